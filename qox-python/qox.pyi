@@ -13,7 +13,13 @@ class DayCountConvention(Enum):
     Actual360 = "Actual360"
     Actual365Fixed = "Actual365Fixed"
     ActActISDA = "ActActISDA"
+    ActActICMA = "ActActICMA"
+    ActActAFB = "ActActAFB"
+    Actual365Leap = "Actual365Leap"
+    NL365 = "NL365"
     Thirty360US = "Thirty360US"
+    Thirty360EPlus = "Thirty360EPlus"
+    OneOne = "OneOne"
 
 class Frequency:
     Annual: Frequency
@@ -61,24 +67,63 @@ class Tenor:
         """Creates a tenor of n years."""
         ...
 
+    @staticmethod
+    def parse(text: str) -> 'Tenor':
+        """Parses "3M", "1Y6M", "2W", "ON", "TN" or "SN"."""
+        ...
+
     def advance(self, from_date: date) -> date:
         """Returns the date advanced by the tenor from the provided date."""
         ...
 
+    def __str__(self) -> str: ...
+
+    def __eq__(self, other: object) -> bool: ...
+
 class InterestRate:
     def __init__(
         self, 
         rate: float, 
-        dcc: DayCountConvention, 
         compounding: Compounding, 
-        frequency: Frequency
+        frequency: Frequency,
+        dcc: DayCountConvention
     ) -> None: ...
 
-    # @property
-    # def rate(self) -> float: ...
-    
+    @property
+    def value(self) -> float: ...
+
     def discount_factor(self, t: float) -> float: ...
 
+    def compound_factor(self, t: float) -> float: ...
+
+    def discount_factor_between(self, start: date, end: date) -> float:
+        """Discount factor over [start, end], accruing under the rate's day count."""
+        ...
+
+    def compound_factor_between(self, start: date, end: date) -> float:
+        """Compound factor over [start, end], accruing under the rate's day count."""
+        ...
+
+    def equivalent_rate(
+        self,
+        t: float,
+        compounding: Compounding,
+        frequency: Frequency
+    ) -> 'InterestRate':
+        """Rate with the same day count compounding to the same amount over t years."""
+        ...
+
+    def equivalent_rate_between(
+        self,
+        start: date,
+        end: date,
+        compounding: Compounding,
+        frequency: Frequency,
+        dcc: DayCountConvention
+    ) -> 'InterestRate':
+        """Rate under another convention compounding to the same amount over [start, end]."""
+        ...
+
 
 
 class RateCurve:
@@ -146,11 +191,17 @@ class OptionMarketFrame:
 
     def __init__(
         self, 
+        evaluation_date: date,
         spot_price: float, 
         rate_curve: RateCurve, 
         vol_surface: VolSurface
     ) -> None:
-        """Initializes the market frame."""
+        """Initializes the market frame as of the start of `evaluation_date` (UTC)."""
+        ...
+
+    @property
+    def evaluation_date(self) -> date:
+        """Returns the date the market is observed on."""
         ...
 
     @property
@@ -169,9 +220,19 @@ class StockOption:
     def strike(self) -> float:
         ...
 
-    @property
-    def years_to_expiry(self) -> float:
+    def years_to_expiry(self, evaluation_time: datetime) -> float:
+        """Returns the Act/365F year fraction from `evaluation_time` to expiry."""
         ...
 
     def evaluate(self, market_view: OptionMarketFrame) -> float:
+        ...
+
+    def evaluate_with_exercise_boundary(
+        self, market_view: OptionMarketFrame
+    ) -> tuple[float, Optional[list[tuple[float, Optional[float]]]]]:
+        """
+        Returns the price and, for American options, the early-exercise boundary
+        as (years_to_expiry, critical_spot) pairs. The spot is None on steps
+        where no node is exercised.
+        """
         ...
\ No newline at end of file
@@ -8,7 +8,13 @@ pub enum PyDayCountConvention {
     Actual360,
     Actual365Fixed,
     ActActISDA,
+    ActActICMA,
+    ActActAFB,
+    Actual365Leap,
+    NL365,
     Thirty360US,
+    Thirty360EPlus,
+    OneOne,
 }
 
 impl From<PyDayCountConvention> for DayCountConvention<'static> {
@@ -17,7 +23,13 @@ impl From<PyDayCountConvention> for DayCountConvention<'static> {
             PyDayCountConvention::Actual360 => DayCountConvention::Actual360,
             PyDayCountConvention::Actual365Fixed => DayCountConvention::Actual365Fixed,
             PyDayCountConvention::ActActISDA => DayCountConvention::ActActISDA,
+            PyDayCountConvention::ActActICMA => DayCountConvention::ActActICMA,
+            PyDayCountConvention::ActActAFB => DayCountConvention::ActActAFB,
+            PyDayCountConvention::Actual365Leap => DayCountConvention::Actual365Leap,
+            PyDayCountConvention::NL365 => DayCountConvention::NL365,
             PyDayCountConvention::Thirty360US => DayCountConvention::Thirty360(Thirty360Subtype::US),
+            PyDayCountConvention::Thirty360EPlus => DayCountConvention::Thirty360(Thirty360Subtype::EuropeanPlus),
+            PyDayCountConvention::OneOne => DayCountConvention::OneOne,
         }
     }
 }
@@ -1,5 +1,6 @@
+use chrono::NaiveDate;
 use pyo3::prelude::*;
-use qox::core::{period::DayCountConvention, rate::{Compounding, Frequency, InterestRate}};
+use qox::core::{period::{DayCountConvention, DefaultPeriodCalculator}, rate::{Compounding, Frequency, InterestRate}};
 use qox::core::rate::Discountable;
 use crate::core::{compounding::PyCompounding, day_count::PyDayCountConvention, frequency::PyFrequency};
 
@@ -26,13 +27,50 @@ impl PyInterestRate {
         }
     }
 
+    #[getter]
+    fn value(&self) -> f64 {
+        self.inner.value
+    }
+
     fn discount_factor(&self, t: f64) -> f64 {
         self.inner.discount_factor(t)
     }
 
-    // Property to inspect the rate
-    // #[getter]
-    // fn rate(&self) -> f64 {
-    //     self.inner.implied_rate()
-    // }
+    fn compound_factor(&self, t: f64) -> f64 {
+        self.inner.compound_factor(t)
+    }
+
+    fn discount_factor_between(&self, start: NaiveDate, end: NaiveDate) -> f64 {
+        self.inner.discount_factor_between(start, end, &DefaultPeriodCalculator)
+    }
+
+    fn compound_factor_between(&self, start: NaiveDate, end: NaiveDate) -> f64 {
+        self.inner.compound_factor_between(start, end, &DefaultPeriodCalculator)
+    }
+
+    fn equivalent_rate(&self, t: f64, compounding: PyCompounding, frequency: PyFrequency) -> Self {
+        Self {
+            inner: self.inner.equivalent_rate(t, compounding.into(), frequency.into()),
+        }
+    }
+
+    fn equivalent_rate_between(
+        &self,
+        start: NaiveDate,
+        end: NaiveDate,
+        compounding: PyCompounding,
+        frequency: PyFrequency,
+        dcc: PyDayCountConvention,
+    ) -> Self {
+        Self {
+            inner: self.inner.equivalent_rate_between(
+                start,
+                end,
+                dcc.into(),
+                compounding.into(),
+                frequency.into(),
+                &DefaultPeriodCalculator,
+            ),
+        }
+    }
 }
\ No newline at end of file
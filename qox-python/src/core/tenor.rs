@@ -1,3 +1,4 @@
+use pyo3::exceptions::PyValueError;
 use pyo3::prelude::*;
 use chrono::NaiveDate;
 use qox::core::tenor::Tenor;
@@ -22,9 +23,28 @@ impl PyTenor {
     #[staticmethod]
     fn years(n: i32) -> Self { Self { inner: Tenor::Years(n) } }
 
+    /// Parses "3M", "1Y6M", "2W", "ON", "TN" or "SN".
+    #[staticmethod]
+    fn parse(text: &str) -> PyResult<Self> {
+        let inner = text.parse().map_err(|e| PyValueError::new_err(format!("{}", e)))?;
+        Ok(Self { inner })
+    }
+
     fn advance(&self, from: NaiveDate) -> NaiveDate {
         self.inner.advance(from)
     }
+
+    fn __str__(&self) -> String {
+        self.inner.to_string()
+    }
+
+    fn __repr__(&self) -> String {
+        format!("Tenor('{}')", self.inner)
+    }
+
+    fn __eq__(&self, other: &Self) -> bool {
+        self.inner == other.inner
+    }
 }
 
 
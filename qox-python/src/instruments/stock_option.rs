@@ -5,6 +5,9 @@ use qox::evaluators::black_scholes::finite_difference::VanillaPayoff;
 use qox::instruments::stock_option::{ExerciseStyle, StockOption};
 use qox::instruments::{OptionInstrument, OptionType};
 
+/// Exercise boundary as `(years_to_expiry, critical_spot)` pairs.
+type BoundaryPoints = Vec<(f64, Option<f64>)>;
+
 #[pyclass(name = "StockOption")]
 #[derive(Clone)]
 pub struct PyStockOption {
@@ -50,12 +53,28 @@ impl PyStockOption {
         self.inner.strike
     }
 
-    #[getter]
-    pub fn years_to_expiry(&self) -> f64 {
-        <StockOption as OptionInstrument<f64, VanillaPayoff>>::years_to_expiry(self.inner)
+    pub fn years_to_expiry(&self, evaluation_time: DateTime<Utc>) -> f64 {
+        <StockOption as OptionInstrument<f64, VanillaPayoff>>::years_to_expiry(
+            self.inner,
+            evaluation_time,
+        )
     }
 
     pub fn evaluate(&self, market_frame: &PyOptionMarketFrame) -> f64 {
         self.inner.evaluate(&market_frame.inner)
     }
+
+    /// Returns the price and, for American options, the exercise boundary as
+    /// `(years_to_expiry, critical_spot)` pairs.
+    pub fn evaluate_with_exercise_boundary(
+        &self,
+        market_frame: &PyOptionMarketFrame,
+    ) -> (f64, Option<BoundaryPoints>) {
+        let (price, boundary) = self
+            .inner
+            .evaluate_with_exercise_boundary(&market_frame.inner);
+
+        let boundary = boundary.map(|b| b.times.into_iter().zip(b.spots).collect());
+        (price, boundary)
+    }
 }
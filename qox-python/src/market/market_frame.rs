@@ -1,9 +1,11 @@
+use chrono::NaiveDate;
 use pyo3::prelude::*;
 use qox::market::market_frame::OptionMarketFrame;
 
 use crate::market::{rate_curve::{PyRateCurve, RateCurveEnum}, vol_surface::{PyVolSurface, VolSurfaceEnum}};
 
-#[pyclass(name = "OptionMarketFrame")]
+// Interpolated curves borrow a `dyn PeriodCalculator`, which need not be `Sync`
+#[pyclass(name = "OptionMarketFrame", unsendable)]
 pub struct PyOptionMarketFrame {
     pub inner: OptionMarketFrame<f64, RateCurveEnum, VolSurfaceEnum>,
 }
@@ -12,12 +14,14 @@ pub struct PyOptionMarketFrame {
 impl PyOptionMarketFrame {
     #[new]
     pub fn new(
+        evaluation_date: NaiveDate,
         spot_price: f64, 
         rate_curve: Bound<'_, PyRateCurve>,
         vol_surface: Bound<'_, PyVolSurface>
     ) -> Self {
         Self {
             inner: OptionMarketFrame::new(
+                evaluation_date,
                 spot_price,
                 rate_curve.borrow().inner.clone(),
                 vol_surface.borrow().inner.clone(),
@@ -25,6 +29,11 @@ impl PyOptionMarketFrame {
         }
     }
 
+    #[getter]
+    pub fn evaluation_date(&self) -> NaiveDate {
+        self.inner.evaluation_time.date_naive()
+    }
+
     #[getter]
     pub fn spot_price(&self) -> f64 {
         self.inner.spot_price
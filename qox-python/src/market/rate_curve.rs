@@ -11,7 +11,7 @@ use crate::core::{rate::PyInterestRate, tenor::PyTenor};
 #[derive(Clone)]
 pub enum RateCurveEnum {
     Flat(FlatRateCurve<'static, f64>),
-    Interpolated(InterpolatedRateCurve<'static, f64>),
+    Interpolated(Box<InterpolatedRateCurve<'static, f64>>),
     Continuous(ContinuousRateCurve<'static, f64>),
 }
 
@@ -31,9 +31,24 @@ impl RateCurve<f64> for RateCurveEnum {
             RateCurveEnum::Continuous(c) => c.discount_factor(t),
         }
     }
+
+    fn reference_date(&self) -> Option<NaiveDate> {
+        match self {
+            RateCurveEnum::Interpolated(c) => RateCurve::reference_date(c.as_ref()),
+            RateCurveEnum::Flat(_) | RateCurveEnum::Continuous(_) => None,
+        }
+    }
+
+    fn curve_time(&self, date: NaiveDate) -> Option<f64> {
+        match self {
+            RateCurveEnum::Interpolated(c) => RateCurve::curve_time(c.as_ref(), date),
+            RateCurveEnum::Flat(_) | RateCurveEnum::Continuous(_) => None,
+        }
+    }
 }
 
-#[pyclass(name = "RateCurve")]
+// Interpolated curves borrow a `dyn PeriodCalculator`, which need not be `Sync`
+#[pyclass(name = "RateCurve", unsendable)]
 #[derive(Clone)]
 pub struct PyRateCurve {
     pub inner: RateCurveEnum,
@@ -71,14 +86,16 @@ impl PyRateCurve {
         let rust_rates: Vec<InterestRate<'static, f64>> =
             rates.into_iter().map(|r| r.inner.clone()).collect();
 
-        let calculator = DefaultPeriodCalculator;
-
-        let curve =
-            InterpolatedRateCurve::new(reference_date, rust_tenors, rust_rates, &calculator)
-                .map_err(|e| PyValueError::new_err(format!("Curve Error: {:?}", e)))?;
+        let curve = InterpolatedRateCurve::new(
+            reference_date,
+            rust_tenors,
+            rust_rates,
+            &DefaultPeriodCalculator,
+        )
+        .map_err(|e| PyValueError::new_err(format!("Curve Error: {:?}", e)))?;
 
         Ok(Self {
-            inner: RateCurveEnum::Interpolated(curve),
+            inner: RateCurveEnum::Interpolated(Box::new(curve)),
         })
     }
 
@@ -23,6 +23,7 @@ pub fn main() {
     let vol = 0.2;
 
     let market_frame = OptionMarketFrame::new(
+        Utc::now().date_naive(),
         spot,
         ContinuousRateCurve::new(rate, DayCountConvention::Actual365Fixed),
         FlatVolSurface::new(vol),
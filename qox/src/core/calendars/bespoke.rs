@@ -0,0 +1,85 @@
+use std::collections::BTreeSet;
+use std::path::Path;
+
+use chrono::{Datelike, NaiveDate, Weekday};
+
+use crate::core::error::CalendarError;
+use crate::traits::calendar::Calendar;
+
+/// Calendar built from an explicit list of holidays, for venues without a rule-based
+/// implementation.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BespokeCalendar {
+    name: String,
+    holidays: BTreeSet<NaiveDate>,
+    weekend: Vec<Weekday>,
+}
+
+impl BespokeCalendar {
+    /// Saturday and Sunday are the weekend; see [`with_weekend`](Self::with_weekend).
+    pub fn new(name: impl Into<String>, holidays: impl IntoIterator<Item = NaiveDate>) -> Self {
+        Self {
+            name: name.into(),
+            holidays: holidays.into_iter().collect(),
+            weekend: vec![Weekday::Sat, Weekday::Sun],
+        }
+    }
+
+    /// Reads one ISO 8601 date per line. Blank lines and lines starting with `#`
+    /// are skipped.
+    pub fn from_file(
+        name: impl Into<String>,
+        path: impl AsRef<Path>,
+    ) -> Result<Self, CalendarError> {
+        let text = std::fs::read_to_string(path)?;
+        let holidays = parse_holidays(&text)?;
+        Ok(Self::new(name, holidays))
+    }
+
+    pub fn with_weekend(mut self, weekend: impl IntoIterator<Item = Weekday>) -> Self {
+        self.weekend = weekend.into_iter().collect();
+        self
+    }
+
+    pub fn holidays(&self) -> impl Iterator<Item = NaiveDate> + '_ {
+        self.holidays.iter().copied()
+    }
+
+    pub fn add_holiday(&mut self, date: NaiveDate) {
+        self.holidays.insert(date);
+    }
+
+    pub fn remove_holiday(&mut self, date: NaiveDate) {
+        self.holidays.remove(&date);
+    }
+}
+
+impl Calendar for BespokeCalendar {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn is_holiday(&self, date: NaiveDate) -> bool {
+        self.holidays.contains(&date)
+    }
+
+    fn is_weekend(&self, date: NaiveDate) -> bool {
+        self.weekend.contains(&date.weekday())
+    }
+}
+
+fn parse_holidays(text: &str) -> Result<Vec<NaiveDate>, CalendarError> {
+    text.lines()
+        .enumerate()
+        .map(|(i, line)| (i + 1, line.trim()))
+        .filter(|(_, line)| !line.is_empty() && !line.starts_with('#'))
+        .map(|(line, value)| {
+            value
+                .parse::<NaiveDate>()
+                .map_err(|_| CalendarError::InvalidDate {
+                    line,
+                    value: value.to_string(),
+                })
+        })
+        .collect()
+}
@@ -0,0 +1,155 @@
+use std::collections::HashMap;
+use std::sync::RwLock;
+
+use chrono::{Datelike, NaiveDate};
+
+use crate::traits::calendar::Calendar;
+
+/// Business days of one year as a bitset indexed by day of the year.
+#[derive(Debug, Clone, Copy)]
+struct YearTable {
+    bits: [u64; 6],
+    count: u32,
+}
+
+impl YearTable {
+    fn build(calendar: &impl Calendar, year: i32) -> Self {
+        let mut bits = [0u64; 6];
+        let first = NaiveDate::from_ymd_opt(year, 1, 1).expect("Date out of range");
+        for date in first.iter_days().take_while(|d| d.year() == year) {
+            if calendar.is_business_day(date) {
+                let day = date.ordinal0() as usize;
+                bits[day / 64] |= 1 << (day % 64);
+            }
+        }
+        let count = bits.iter().map(|w| w.count_ones()).sum();
+        Self { bits, count }
+    }
+
+    fn contains(&self, day: u32) -> bool {
+        self.bits[day as usize / 64] & (1 << (day % 64)) != 0
+    }
+
+    /// Business days before day `day` of the year.
+    fn count_before(&self, day: u32) -> u32 {
+        let (word, bit) = (day as usize / 64, day % 64);
+        let whole: u32 = self.bits[..word].iter().map(|w| w.count_ones()).sum();
+        let partial = match self.bits.get(word) {
+            Some(w) => (w & ((1u64 << bit) - 1)).count_ones(),
+            None => 0,
+        };
+        whole + partial
+    }
+
+    /// Day of the year of the business day with index `k`, counting from zero.
+    fn nth(&self, mut k: u32) -> u32 {
+        for (i, &word) in self.bits.iter().enumerate() {
+            let ones = word.count_ones();
+            if k < ones {
+                let mut word = word;
+                for _ in 0..k {
+                    word &= word - 1;
+                }
+                return i as u32 * 64 + word.trailing_zeros();
+            }
+            k -= ones;
+        }
+        unreachable!("business day index past the end of the year")
+    }
+}
+
+/// Any calendar with its business days tabulated per year on first use, so counting
+/// and advancing over long periods costs a few bit operations per year rather than a
+/// holiday check per day.
+#[derive(Debug)]
+pub struct CachedCalendar<C: Calendar> {
+    calendar: C,
+    years: RwLock<HashMap<i32, YearTable>>,
+}
+
+impl<C: Calendar> CachedCalendar<C> {
+    pub fn new(calendar: C) -> Self {
+        Self {
+            calendar,
+            years: RwLock::new(HashMap::new()),
+        }
+    }
+
+    pub fn calendar(&self) -> &C {
+        &self.calendar
+    }
+
+    fn table(&self, year: i32) -> YearTable {
+        if let Some(table) = self.years.read().unwrap().get(&year) {
+            return *table;
+        }
+        let table = YearTable::build(&self.calendar, year);
+        self.years.write().unwrap().insert(year, table);
+        table
+    }
+
+    fn date(year: i32, day: u32) -> NaiveDate {
+        NaiveDate::from_yo_opt(year, day + 1).expect("Date out of range")
+    }
+}
+
+impl<C: Calendar> Calendar for CachedCalendar<C> {
+    fn name(&self) -> &str {
+        self.calendar.name()
+    }
+
+    fn is_holiday(&self, date: NaiveDate) -> bool {
+        self.calendar.is_holiday(date)
+    }
+
+    fn is_weekend(&self, date: NaiveDate) -> bool {
+        self.calendar.is_weekend(date)
+    }
+
+    fn is_business_day(&self, date: NaiveDate) -> bool {
+        self.table(date.year()).contains(date.ordinal0())
+    }
+
+    fn business_days_between(&self, start: NaiveDate, end: NaiveDate) -> i64 {
+        if end <= start {
+            return 0;
+        }
+        let first = self.table(start.year());
+        if start.year() == end.year() {
+            return (first.count_before(end.ordinal0()) - first.count_before(start.ordinal0()))
+                as i64;
+        }
+        let head = first.count - first.count_before(start.ordinal0());
+        let middle: u32 = (start.year() + 1..end.year())
+            .map(|year| self.table(year).count)
+            .sum();
+        let tail = self.table(end.year()).count_before(end.ordinal0());
+        (head + middle + tail) as i64
+    }
+
+    fn advance_business_days(&self, date: NaiveDate, n: i32) -> NaiveDate {
+        if n == 0 {
+            return date;
+        }
+        let mut year = date.year();
+        let mut table = self.table(year);
+        if n > 0 {
+            // Index of the target among the business days of `year`
+            let mut k = table.count_before(date.ordinal0() + 1) + n as u32 - 1;
+            while k >= table.count {
+                k -= table.count;
+                year += 1;
+                table = self.table(year);
+            }
+            Self::date(year, table.nth(k))
+        } else {
+            let mut k = table.count_before(date.ordinal0()) as i64 + n as i64;
+            while k < 0 {
+                year -= 1;
+                table = self.table(year);
+                k += table.count as i64;
+            }
+            Self::date(year, table.nth(k as u32))
+        }
+    }
+}
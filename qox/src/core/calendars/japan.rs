@@ -0,0 +1,140 @@
+use chrono::{Datelike, NaiveDate, Weekday};
+
+use crate::core::calendars::{date, nth_weekday};
+use crate::traits::calendar::Calendar;
+
+/// Japanese banks and the Tokyo Stock Exchange: national holidays with their
+/// substitutes, plus the year-end closure from December 31 to January 3.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Japan;
+
+impl Calendar for Japan {
+    fn name(&self) -> &str {
+        "Japan"
+    }
+
+    fn is_holiday(&self, d: NaiveDate) -> bool {
+        let (m, day) = (d.month(), d.day());
+
+        (m == 1 && day <= 3)
+            || (m == 12 && day == 31)
+            || is_national_holiday(d)
+            || is_substitute_holiday(d)
+            || is_citizens_holiday(d)
+    }
+}
+
+/// A national holiday falling on a Sunday moves to the next day that is not itself a
+/// national holiday.
+fn is_substitute_holiday(d: NaiveDate) -> bool {
+    if d.year() < 1973 || d.weekday() == Weekday::Sun || is_national_holiday(d) {
+        return false;
+    }
+
+    let mut previous = d.pred_opt().unwrap();
+    while is_national_holiday(previous) {
+        if previous.weekday() == Weekday::Sun {
+            return true;
+        }
+        previous = previous.pred_opt().unwrap();
+    }
+    false
+}
+
+/// A day between two national holidays is a holiday too.
+fn is_citizens_holiday(d: NaiveDate) -> bool {
+    d.year() >= 1986
+        && !is_national_holiday(d)
+        && is_national_holiday(d.pred_opt().unwrap())
+        && is_national_holiday(d.succ_opt().unwrap())
+}
+
+fn is_national_holiday(d: NaiveDate) -> bool {
+    let y = d.year();
+    let (m, day) = (d.month(), d.day());
+
+    let coming_of_age = if y >= 2000 {
+        d == nth_weekday(y, 1, Weekday::Mon, 2)
+    } else {
+        m == 1 && day == 15
+    };
+    let emperors_birthday = match y {
+        ..1989 => m == 4 && day == 29,
+        1989..=2018 => m == 12 && day == 23,
+        2019 => false,
+        _ => m == 2 && day == 23,
+    };
+    let marine_day = match y {
+        2020 => d == date(y, 7, 23),
+        2021 => d == date(y, 7, 22),
+        2003.. => d == nth_weekday(y, 7, Weekday::Mon, 3),
+        1996.. => m == 7 && day == 20,
+        _ => false,
+    };
+    let mountain_day = match y {
+        2020 => d == date(y, 8, 10),
+        2021 => d == date(y, 8, 8),
+        2016.. => m == 8 && day == 11,
+        _ => false,
+    };
+    let respect_for_the_aged = if y >= 2003 {
+        d == nth_weekday(y, 9, Weekday::Mon, 3)
+    } else {
+        y >= 1966 && m == 9 && day == 15
+    };
+    let sports_day = match y {
+        2020 => d == date(y, 7, 24),
+        2021 => d == date(y, 7, 23),
+        2000.. => d == nth_weekday(y, 10, Weekday::Mon, 2),
+        1966.. => m == 10 && day == 10,
+        _ => false,
+    };
+
+    (m == 1 && day == 1)
+        || coming_of_age
+        || (y >= 1967 && m == 2 && day == 11)
+        || emperors_birthday
+        || d == vernal_equinox(y)
+        // Showa Day, Greenery Day until 2006
+        || (y >= 1989 && m == 4 && day == 29)
+        || (m == 5 && day == 3)
+        || (y >= 2007 && m == 5 && day == 4)
+        || (m == 5 && day == 5)
+        || marine_day
+        || mountain_day
+        || respect_for_the_aged
+        || d == autumnal_equinox(y)
+        || sports_day
+        || (m == 11 && day == 3)
+        || (m == 11 && day == 23)
+        || JAPAN_CLOSINGS.contains(&(y, m, day))
+}
+
+/// One-off national holidays.
+const JAPAN_CLOSINGS: [(i32, u32, u32); 8] = [
+    (1959, 4, 10),  // Wedding of Crown Prince Akihito
+    (1989, 2, 24),  // Funeral of Emperor Showa
+    (1990, 11, 12), // Enthronement of Emperor Akihito
+    (1993, 6, 9),   // Wedding of Crown Prince Naruhito
+    (2019, 4, 30),  // Abdication of Emperor Akihito
+    (2019, 5, 1),   // Accession of Emperor Naruhito
+    (2019, 5, 2),
+    (2019, 10, 22), // Enthronement of Emperor Naruhito
+];
+
+fn vernal_equinox(year: i32) -> NaiveDate {
+    let base = if year < 1980 { 20.8357 } else { 20.8431 };
+    date(year, 3, equinox_day(year, base))
+}
+
+fn autumnal_equinox(year: i32) -> NaiveDate {
+    let base = if year < 1980 { 23.2588 } else { 23.2488 };
+    date(year, 9, equinox_day(year, base))
+}
+
+/// Astronomical approximation valid for 1900 to 2099.
+fn equinox_day(year: i32, base: f64) -> u32 {
+    let leap_offset = if year < 1980 { 1983 } else { 1980 };
+    let leap_days = ((year - leap_offset) / 4) as f64;
+    (base + 0.242194 * (year - 1980) as f64 - leap_days) as u32
+}
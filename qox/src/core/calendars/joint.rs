@@ -0,0 +1,65 @@
+use chrono::NaiveDate;
+
+use crate::traits::calendar::Calendar;
+
+/// How the member calendars of a [`JointCalendar`] are combined.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum JointCalendarRule {
+    /// A day is a holiday if it is a holiday for any member.
+    JoinHolidays,
+    /// A day is a holiday only if it is a holiday for every member.
+    JoinBusinessDays,
+}
+
+/// Combination of several calendars, e.g. both currencies of an FX trade.
+#[derive(Debug)]
+pub struct JointCalendar {
+    calendars: Vec<Box<dyn Calendar>>,
+    rule: JointCalendarRule,
+    name: String,
+}
+
+impl JointCalendar {
+    pub fn new(calendars: Vec<Box<dyn Calendar>>, rule: JointCalendarRule) -> Self {
+        let names: Vec<&str> = calendars.iter().map(|c| c.name()).collect();
+        let name = format!("{:?}({})", rule, names.join(", "));
+        Self {
+            calendars,
+            rule,
+            name,
+        }
+    }
+
+    pub fn calendars(&self) -> &[Box<dyn Calendar>] {
+        &self.calendars
+    }
+
+    pub fn rule(&self) -> JointCalendarRule {
+        self.rule
+    }
+
+    fn combine(&self, closed: impl Fn(&dyn Calendar) -> bool) -> bool {
+        match self.rule {
+            JointCalendarRule::JoinHolidays => self.calendars.iter().any(|c| closed(c.as_ref())),
+            JointCalendarRule::JoinBusinessDays => {
+                self.calendars.iter().all(|c| closed(c.as_ref()))
+            }
+        }
+    }
+}
+
+impl Calendar for JointCalendar {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Members may disagree on the weekend, so a weekend day of one member counts
+    /// as a holiday of the joint calendar.
+    fn is_holiday(&self, date: NaiveDate) -> bool {
+        self.combine(|c| !c.is_business_day(date))
+    }
+
+    fn is_weekend(&self, date: NaiveDate) -> bool {
+        self.combine(|c| c.is_weekend(date))
+    }
+}
@@ -0,0 +1,71 @@
+use chrono::{Datelike, Days, NaiveDate, Weekday};
+
+pub mod bespoke;
+pub mod cached;
+pub mod japan;
+pub mod joint;
+pub mod modified;
+pub mod target;
+pub mod united_kingdom;
+pub mod united_states;
+pub mod weekends_only;
+
+/// Western (Gregorian) Easter Sunday, by the anonymous Gregorian algorithm.
+pub fn easter_sunday(year: i32) -> NaiveDate {
+    let a = year % 19;
+    let b = year / 100;
+    let c = year % 100;
+    let d = b / 4;
+    let e = b % 4;
+    let f = (b + 8) / 25;
+    let g = (b - f + 1) / 3;
+    let h = (19 * a + b - d - g + 15) % 30;
+    let i = c / 4;
+    let k = c % 4;
+    let l = (32 + 2 * e + 2 * i - h - k) % 7;
+    let m = (a + 11 * h + 22 * l) / 451;
+    let n = h + l - 7 * m + 114;
+
+    date(year, (n / 31) as u32, (n % 31 + 1) as u32)
+}
+
+pub(crate) fn is_good_friday(date: NaiveDate) -> bool {
+    date == easter_sunday(date.year()) - Days::new(2)
+}
+
+pub(crate) fn is_easter_monday(date: NaiveDate) -> bool {
+    date == easter_sunday(date.year()) + Days::new(1)
+}
+
+pub(crate) fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(year, month, day).expect("valid calendar date")
+}
+
+/// `n`-th `weekday` of the month, counting from one.
+pub(crate) fn nth_weekday(year: i32, month: u32, weekday: Weekday, n: u8) -> NaiveDate {
+    NaiveDate::from_weekday_of_month_opt(year, month, weekday, n).expect("valid weekday of month")
+}
+
+pub(crate) fn last_weekday(year: i32, month: u32, weekday: Weekday) -> NaiveDate {
+    let (next_year, next_month) = if month == 12 {
+        (year + 1, 1)
+    } else {
+        (year, month + 1)
+    };
+    let mut d = date(next_year, next_month, 1).pred_opt().unwrap();
+    while d.weekday() != weekday {
+        d = d.pred_opt().unwrap();
+    }
+    d
+}
+
+/// Fixed-date holiday observed on the Friday before when it falls on a Saturday and
+/// on the Monday after when it falls on a Sunday.
+pub(crate) fn observed(year: i32, month: u32, day: u32) -> NaiveDate {
+    let d = date(year, month, day);
+    match d.weekday() {
+        Weekday::Sat => d.pred_opt().unwrap(),
+        Weekday::Sun => d.succ_opt().unwrap(),
+        _ => d,
+    }
+}
@@ -0,0 +1,68 @@
+use std::collections::BTreeSet;
+
+use chrono::NaiveDate;
+
+use crate::traits::calendar::Calendar;
+
+/// Any calendar with individual days closed or reopened at runtime, e.g. ad-hoc
+/// exchange closures published after the rules were written.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ModifiedCalendar<C: Calendar> {
+    calendar: C,
+    added: BTreeSet<NaiveDate>,
+    removed: BTreeSet<NaiveDate>,
+}
+
+impl<C: Calendar> ModifiedCalendar<C> {
+    pub fn new(calendar: C) -> Self {
+        Self {
+            calendar,
+            added: BTreeSet::new(),
+            removed: BTreeSet::new(),
+        }
+    }
+
+    pub fn calendar(&self) -> &C {
+        &self.calendar
+    }
+
+    /// Closes `date`, overriding an earlier [`remove_holiday`](Self::remove_holiday).
+    pub fn add_holiday(&mut self, date: NaiveDate) {
+        self.removed.remove(&date);
+        self.added.insert(date);
+    }
+
+    /// Opens `date` for business, even if it falls on a weekend.
+    pub fn remove_holiday(&mut self, date: NaiveDate) {
+        self.added.remove(&date);
+        self.removed.insert(date);
+    }
+
+    pub fn added_holidays(&self) -> impl Iterator<Item = NaiveDate> + '_ {
+        self.added.iter().copied()
+    }
+
+    pub fn removed_holidays(&self) -> impl Iterator<Item = NaiveDate> + '_ {
+        self.removed.iter().copied()
+    }
+}
+
+impl<C: Calendar> Calendar for ModifiedCalendar<C> {
+    fn name(&self) -> &str {
+        self.calendar.name()
+    }
+
+    fn is_holiday(&self, date: NaiveDate) -> bool {
+        self.added.contains(&date)
+            || (!self.removed.contains(&date) && self.calendar.is_holiday(date))
+    }
+
+    fn is_weekend(&self, date: NaiveDate) -> bool {
+        !self.removed.contains(&date) && self.calendar.is_weekend(date)
+    }
+
+    fn is_business_day(&self, date: NaiveDate) -> bool {
+        !self.added.contains(&date)
+            && (self.removed.contains(&date) || self.calendar.is_business_day(date))
+    }
+}
@@ -0,0 +1,27 @@
+use chrono::{Datelike, NaiveDate};
+
+use crate::core::calendars::{is_easter_monday, is_good_friday};
+use crate::traits::calendar::Calendar;
+
+/// TARGET2, the euro interbank settlement calendar.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Target;
+
+impl Calendar for Target {
+    fn name(&self) -> &str {
+        "TARGET"
+    }
+
+    fn is_holiday(&self, d: NaiveDate) -> bool {
+        let y = d.year();
+        let (m, day) = (d.month(), d.day());
+
+        (m == 1 && day == 1)
+            || (y >= 2000 && (is_good_friday(d) || is_easter_monday(d)))
+            || (y >= 2000 && m == 5 && day == 1)
+            || (m == 12 && day == 25)
+            || (y >= 2000 && m == 12 && day == 26)
+            // Year-end closures around the launch of the euro
+            || (m == 12 && day == 31 && (y == 1998 || y == 1999 || y == 2001))
+    }
+}
@@ -0,0 +1,61 @@
+use chrono::{Datelike, NaiveDate, Weekday};
+
+use crate::core::calendars::{date, is_easter_monday, is_good_friday, last_weekday, nth_weekday};
+use crate::traits::calendar::Calendar;
+
+/// England and Wales bank holidays, as used for sterling settlement.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct UnitedKingdom;
+
+impl Calendar for UnitedKingdom {
+    fn name(&self) -> &str {
+        "UK settlement"
+    }
+
+    fn is_holiday(&self, d: NaiveDate) -> bool {
+        let y = d.year();
+        let (m, day, w) = (d.month(), d.day(), d.weekday());
+        let monday_or_tuesday = w == Weekday::Mon || w == Weekday::Tue;
+
+        // Weekend New Year's Day, Christmas and Boxing Day roll to the next weekdays
+        (m == 1 && (day == 1 || ((day == 2 || day == 3) && w == Weekday::Mon)))
+            || is_good_friday(d)
+            || is_easter_monday(d)
+            || is_early_may_bank_holiday(d)
+            || is_spring_bank_holiday(d)
+            || d == last_weekday(y, 8, Weekday::Mon)
+            || (m == 12 && (day == 25 || (day == 27 && monday_or_tuesday)))
+            || (m == 12 && (day == 26 || (day == 28 && monday_or_tuesday)))
+            || UK_CLOSINGS.contains(&(y, m, day))
+    }
+}
+
+/// One-off bank holidays.
+const UK_CLOSINGS: [(i32, u32, u32); 7] = [
+    (1999, 12, 31), // Millennium
+    (2002, 6, 3),   // Golden Jubilee
+    (2011, 4, 29),  // Royal wedding
+    (2012, 6, 5),   // Diamond Jubilee
+    (2022, 6, 3),   // Platinum Jubilee
+    (2022, 9, 19),  // State funeral of Queen Elizabeth II
+    (2023, 5, 8),   // Coronation of King Charles III
+];
+
+fn is_early_may_bank_holiday(d: NaiveDate) -> bool {
+    let y = d.year();
+    match y {
+        // Moved to VE Day anniversaries
+        1995 | 2020 => d == date(y, 5, 8),
+        _ => y >= 1978 && d == nth_weekday(y, 5, Weekday::Mon, 1),
+    }
+}
+
+fn is_spring_bank_holiday(d: NaiveDate) -> bool {
+    let y = d.year();
+    match y {
+        // Moved for the Golden, Diamond and Platinum Jubilees
+        2002 | 2012 => d == date(y, 6, 4),
+        2022 => d == date(y, 6, 2),
+        _ => d == last_weekday(y, 5, Weekday::Mon),
+    }
+}
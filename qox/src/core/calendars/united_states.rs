@@ -0,0 +1,144 @@
+use chrono::{Datelike, NaiveDate, Weekday};
+
+use crate::core::calendars::{date, is_good_friday, last_weekday, nth_weekday, observed};
+use crate::traits::calendar::Calendar;
+
+/// United States calendars.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UnitedStates {
+    /// Federal Reserve bankwire settlement.
+    Settlement,
+    /// New York Stock Exchange.
+    Nyse,
+}
+
+impl Calendar for UnitedStates {
+    fn name(&self) -> &str {
+        match self {
+            Self::Settlement => "US settlement",
+            Self::Nyse => "New York stock exchange",
+        }
+    }
+
+    fn is_holiday(&self, date: NaiveDate) -> bool {
+        match self {
+            Self::Settlement => is_settlement_holiday(date),
+            Self::Nyse => is_nyse_holiday(date),
+        }
+    }
+}
+
+fn is_settlement_holiday(d: NaiveDate) -> bool {
+    let y = d.year();
+
+    // New Year's Day on a Saturday is observed on the last day of the previous year
+    d == observed(y, 1, 1)
+        || d == observed(y + 1, 1, 1)
+        || (y >= 1983 && d == nth_weekday(y, 1, Weekday::Mon, 3))
+        || is_washington_birthday(d)
+        || is_memorial_day(d)
+        || (y >= 2022 && d == observed(y, 6, 19))
+        || d == observed(y, 7, 4)
+        || is_labor_day(d)
+        || is_columbus_day(d)
+        || is_veterans_day(d)
+        || is_thanksgiving(d)
+        || d == observed(y, 12, 25)
+}
+
+fn is_nyse_holiday(d: NaiveDate) -> bool {
+    let y = d.year();
+
+    // New Year's Day moves to Monday but never back to the previous Friday
+    d == date(y, 1, 1)
+        || (d == date(y, 1, 2) && d.weekday() == Weekday::Mon)
+        || (y >= 1998 && d == nth_weekday(y, 1, Weekday::Mon, 3))
+        || is_washington_birthday(d)
+        || is_good_friday(d)
+        || is_memorial_day(d)
+        || (y >= 2022 && d == observed(y, 6, 19))
+        || d == observed(y, 7, 4)
+        || is_labor_day(d)
+        || is_thanksgiving(d)
+        || d == observed(y, 12, 25)
+        || is_presidential_election_day(d)
+        || NYSE_CLOSINGS.contains(&(y, d.month(), d.day()))
+}
+
+/// Unscheduled closures.
+const NYSE_CLOSINGS: [(i32, u32, u32); 20] = [
+    (1963, 11, 25), // President Kennedy's funeral
+    (1968, 4, 9),   // Martin Luther King's funeral
+    (1969, 2, 10),  // Snowstorm
+    (1969, 3, 31),  // President Eisenhower's funeral
+    (1969, 7, 21),  // First lunar landing
+    (1972, 12, 28), // President Truman's funeral
+    (1973, 1, 25),  // President Johnson's funeral
+    (1977, 7, 14),  // New York City blackout
+    (1985, 9, 27),  // Hurricane Gloria
+    (1994, 4, 27),  // President Nixon's funeral
+    (2001, 9, 11),  // September 11
+    (2001, 9, 12),
+    (2001, 9, 13),
+    (2001, 9, 14),
+    (2004, 6, 11),  // President Reagan's funeral
+    (2007, 1, 2),   // President Ford's funeral
+    (2012, 10, 29), // Hurricane Sandy
+    (2012, 10, 30),
+    (2018, 12, 5), // President George H. W. Bush's funeral
+    (2025, 1, 9),  // President Carter's funeral
+];
+
+fn is_washington_birthday(d: NaiveDate) -> bool {
+    let y = d.year();
+    if y >= 1971 {
+        d == nth_weekday(y, 2, Weekday::Mon, 3)
+    } else {
+        d == observed(y, 2, 22)
+    }
+}
+
+fn is_memorial_day(d: NaiveDate) -> bool {
+    let y = d.year();
+    if y >= 1971 {
+        d == last_weekday(y, 5, Weekday::Mon)
+    } else {
+        d == observed(y, 5, 30)
+    }
+}
+
+fn is_labor_day(d: NaiveDate) -> bool {
+    d == nth_weekday(d.year(), 9, Weekday::Mon, 1)
+}
+
+fn is_columbus_day(d: NaiveDate) -> bool {
+    let y = d.year();
+    if y >= 1971 {
+        d == nth_weekday(y, 10, Weekday::Mon, 2)
+    } else {
+        y >= 1937 && d == observed(y, 10, 12)
+    }
+}
+
+fn is_veterans_day(d: NaiveDate) -> bool {
+    let y = d.year();
+    if (1971..=1977).contains(&y) {
+        d == nth_weekday(y, 10, Weekday::Mon, 4)
+    } else {
+        d == observed(y, 11, 11)
+    }
+}
+
+fn is_thanksgiving(d: NaiveDate) -> bool {
+    d == nth_weekday(d.year(), 11, Weekday::Thu, 4)
+}
+
+/// The exchange closed for presidential elections until 1980.
+fn is_presidential_election_day(d: NaiveDate) -> bool {
+    let y = d.year();
+    y <= 1980
+        && y % 4 == 0
+        && d.month() == 11
+        && d.weekday() == Weekday::Tue
+        && (2..=8).contains(&d.day())
+}
@@ -0,0 +1,11 @@
+use crate::traits::calendar::Calendar;
+
+/// Saturdays and Sundays are the only non-business days.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct WeekendsOnly;
+
+impl Calendar for WeekendsOnly {
+    fn name(&self) -> &str {
+        "Weekends only"
+    }
+}
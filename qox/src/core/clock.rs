@@ -0,0 +1,236 @@
+//! Clocks for intraday time to expiry: calendar time to the second, time spent in
+//! trading sessions, and variance time weighting sessions, nights, closed days and
+//! event days differently.
+
+use std::collections::BTreeMap;
+
+use chrono::{DateTime, Days, LocalResult, NaiveDate, NaiveTime, Offset, TimeZone, Utc};
+
+use crate::traits::calendar::Calendar;
+use crate::traits::clock::Clock;
+
+const SECONDS_PER_DAY: f64 = 86_400.0;
+
+/// Actual/365 Fixed, measured to the millisecond.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ActualClock;
+
+impl Clock for ActualClock {
+    fn year_fraction(&self, start: DateTime<Utc>, end: DateTime<Utc>) -> f64 {
+        (end - start).num_milliseconds() as f64 / 1000.0 / (365.0 * SECONDS_PER_DAY)
+    }
+}
+
+/// Daily trading session of an exchange, in the local time of `timezone`. Open and
+/// close are resolved on each local date, so sessions follow daylight saving time in
+/// zones that observe it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TradingHours<Tz: TimeZone> {
+    pub open: NaiveTime,
+    pub close: NaiveTime,
+    pub timezone: Tz,
+}
+
+impl<Tz: TimeZone> TradingHours<Tz> {
+    /// # Panics
+    ///
+    /// If `close` is not after `open`.
+    pub fn new(open: NaiveTime, close: NaiveTime, timezone: Tz) -> Self {
+        assert!(close > open, "session must close after it opens");
+        Self {
+            open,
+            close,
+            timezone,
+        }
+    }
+
+    /// Instant of the local `time` on `date`. A time repeated when clocks go back is
+    /// the earlier instant, and a time skipped when they go forward is read at the
+    /// offset before the change.
+    fn instant(&self, date: NaiveDate, time: NaiveTime) -> DateTime<Utc> {
+        let local = date.and_time(time);
+        let offset = match self.timezone.offset_from_local_datetime(&local) {
+            LocalResult::Single(offset) | LocalResult::Ambiguous(offset, _) => offset.fix(),
+            LocalResult::None => self
+                .timezone
+                .offset_from_local_datetime(&(local - Days::new(1)))
+                .earliest()
+                .expect("clock changes are a day apart")
+                .fix(),
+        };
+        (local - offset).and_utc()
+    }
+}
+
+/// Part of a local calendar day, as seen by [`TradingClock`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum DayPart {
+    Session,
+    /// Outside the session on a business day.
+    OffSession,
+    /// A whole weekend day or holiday.
+    Closed,
+}
+
+/// Counts only time inside the trading session of business days. A full session is
+/// `1 / days_per_year` years, 252 sessions a year by default.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TradingClock<C, Tz: TimeZone> {
+    calendar: C,
+    hours: TradingHours<Tz>,
+    days_per_year: f64,
+}
+
+impl<C: Calendar, Tz: TimeZone> TradingClock<C, Tz> {
+    pub fn new(calendar: C, hours: TradingHours<Tz>) -> Self {
+        Self {
+            calendar,
+            hours,
+            days_per_year: 252.0,
+        }
+    }
+
+    pub fn with_days_per_year(mut self, days_per_year: f64) -> Self {
+        self.days_per_year = days_per_year;
+        self
+    }
+
+    pub fn calendar(&self) -> &C {
+        &self.calendar
+    }
+
+    pub fn hours(&self) -> &TradingHours<Tz> {
+        &self.hours
+    }
+
+    pub fn days_per_year(&self) -> f64 {
+        self.days_per_year
+    }
+
+    /// Sum over the local days touched by `[start, end)` of the elapsed share of each
+    /// day part times its `weight`. Shares are of the day's own length, which is not
+    /// 24 hours when the clocks change.
+    fn weighted_days(
+        &self,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+        weight: impl Fn(NaiveDate, DayPart) -> f64,
+    ) -> f64 {
+        let seconds =
+            |from: DateTime<Utc>, to: DateTime<Utc>| (to - from).num_milliseconds() as f64 / 1000.0;
+        let elapsed =
+            |from: DateTime<Utc>, to: DateTime<Utc>| seconds(from.max(start), to.min(end)).max(0.0);
+        let local_date = |t: DateTime<Utc>| t.with_timezone(&self.hours.timezone).date_naive();
+
+        let mut total = 0.0;
+        for date in local_date(start)
+            .iter_days()
+            .take_while(|&d| d <= local_date(end))
+        {
+            let midnight = self.hours.instant(date, NaiveTime::MIN);
+            let next_midnight = self.hours.instant(date + Days::new(1), NaiveTime::MIN);
+            let day = seconds(midnight, next_midnight);
+            let passed = elapsed(midnight, next_midnight);
+            if self.calendar.is_business_day(date) {
+                let open = self.hours.instant(date, self.hours.open);
+                let close = self.hours.instant(date, self.hours.close);
+                let session = seconds(open, close);
+                let traded = elapsed(open, close);
+                total += weight(date, DayPart::Session) * traded / session
+                    + weight(date, DayPart::OffSession) * (passed - traded) / (day - session);
+            } else {
+                total += weight(date, DayPart::Closed) * passed / day;
+            }
+        }
+        total
+    }
+}
+
+impl<C: Calendar, Tz: TimeZone> Clock for TradingClock<C, Tz> {
+    fn year_fraction(&self, start: DateTime<Utc>, end: DateTime<Utc>) -> f64 {
+        if end < start {
+            return -self.year_fraction(end, start);
+        }
+        let sessions = self.weighted_days(start, end, |_, part| match part {
+            DayPart::Session => 1.0,
+            DayPart::OffSession | DayPart::Closed => 0.0,
+        });
+        sessions / self.days_per_year
+    }
+}
+
+/// Variance time for the volatility of a [`TradingClock`]'s market.
+///
+/// A full trading session carries one day of variance, the hours outside the session
+/// of a business day carry `overnight_weight` days, and a weekend day or holiday
+/// carries `weekend_weight` days. An event day, such as an earnings release, carries
+/// its own weight in place of the session or closed-day weight. The total is
+/// annualized so that `days_per_year` ordinary business days and the remaining days
+/// of a 365-day year closed make one year.
+#[derive(Debug, Clone, PartialEq)]
+pub struct VarianceClock<C, Tz: TimeZone> {
+    trading: TradingClock<C, Tz>,
+    overnight_weight: f64,
+    weekend_weight: f64,
+    events: BTreeMap<NaiveDate, f64>,
+}
+
+impl<C: Calendar, Tz: TimeZone> VarianceClock<C, Tz> {
+    /// No variance outside trading sessions until weights are set, which makes this
+    /// trading time.
+    pub fn new(trading: TradingClock<C, Tz>) -> Self {
+        Self {
+            trading,
+            overnight_weight: 0.0,
+            weekend_weight: 0.0,
+            events: BTreeMap::new(),
+        }
+    }
+
+    pub fn with_overnight_weight(mut self, weight: f64) -> Self {
+        self.overnight_weight = weight;
+        self
+    }
+
+    pub fn with_weekend_weight(mut self, weight: f64) -> Self {
+        self.weekend_weight = weight;
+        self
+    }
+
+    pub fn with_event(mut self, date: NaiveDate, weight: f64) -> Self {
+        self.events.insert(date, weight);
+        self
+    }
+
+    pub fn trading_clock(&self) -> &TradingClock<C, Tz> {
+        &self.trading
+    }
+
+    pub fn events(&self) -> impl Iterator<Item = (NaiveDate, f64)> + '_ {
+        self.events.iter().map(|(&date, &weight)| (date, weight))
+    }
+
+    /// Days of variance in an ordinary year.
+    pub fn annual_weight(&self) -> f64 {
+        let business_days = self.trading.days_per_year;
+        business_days * (1.0 + self.overnight_weight)
+            + (365.0 - business_days) * self.weekend_weight
+    }
+}
+
+impl<C: Calendar, Tz: TimeZone> Clock for VarianceClock<C, Tz> {
+    fn year_fraction(&self, start: DateTime<Utc>, end: DateTime<Utc>) -> f64 {
+        if end < start {
+            return -self.year_fraction(end, start);
+        }
+        let days = self.trading.weighted_days(start, end, |date, part| {
+            let event = self.events.get(&date).copied();
+            match part {
+                DayPart::Session => event.unwrap_or(1.0),
+                DayPart::OffSession => self.overnight_weight,
+                DayPart::Closed => event.unwrap_or(self.weekend_weight),
+            }
+        });
+        days / self.annual_weight()
+    }
+}
@@ -16,8 +16,21 @@ pub enum DayCountConvention<'a> {
     Actual360,
     Actual365Fixed,
     ActActISDA,
+    /// Act/Act ICMA: the year fraction of a coupon period is measured against its
+    /// reference period, see [`PeriodCalculator::year_fraction_in_period`].
+    ///
+    /// [`PeriodCalculator::year_fraction_in_period`]: crate::core::period::PeriodCalculator::year_fraction_in_period
+    ActActICMA,
+    ActActAFB,
+    /// Act/365L: 366 days in the year if the period end is in a leap year, or for
+    /// annual periods if February 29 is in the period.
+    Actual365Leap,
+    /// Actual days less any February 29, over 365.
+    NL365,
     Thirty360(Thirty360Subtype),
     Business252(&'a dyn Calendar),
+    /// A whole year for any period.
+    OneOne,
 }
 
 impl<'a> DayCountConvention<'a> {}
@@ -50,6 +63,8 @@ impl<'a> DayCountConvention<'a> {}
 pub enum Thirty360Subtype {
     US,
     European,
+    /// 30E+/360: an end on the 31st rolls to the 1st of the next month.
+    EuropeanPlus,
     German,
 }
 
@@ -0,0 +1,233 @@
+use crate::core::error::InterpolationError;
+use crate::math::interpolate::{AnyInterpolator, InterpolationMethod, Interpolator1D};
+use crate::types::Real;
+
+/// Quantity a rate curve stores at its nodes and interpolates between them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InterpolationVariable {
+    ZeroRate,
+    DiscountFactor,
+    /// Natural log of the discount factor.
+    LogDiscount,
+    /// Instantaneous forward rate, integrated to get discount factors.
+    InstantaneousForward,
+}
+
+/// What a rate curve interpolates, and how.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CurveInterpolation {
+    pub variable: InterpolationVariable,
+    pub method: InterpolationMethod,
+}
+
+impl CurveInterpolation {
+    pub const LINEAR_ZERO: Self = Self {
+        variable: InterpolationVariable::ZeroRate,
+        method: InterpolationMethod::Linear,
+    };
+    /// Piecewise flat forwards.
+    pub const LOG_LINEAR_DISCOUNT: Self = Self {
+        variable: InterpolationVariable::LogDiscount,
+        method: InterpolationMethod::Linear,
+    };
+    pub const LINEAR_DISCOUNT: Self = Self {
+        variable: InterpolationVariable::DiscountFactor,
+        method: InterpolationMethod::Linear,
+    };
+
+    /// Monotone convex and flat forward interpolation are defined on zero rates only.
+    pub fn new(
+        variable: InterpolationVariable,
+        method: InterpolationMethod,
+    ) -> Result<Self, InterpolationError> {
+        let interpolation = Self { variable, method };
+        interpolation.validate()?;
+        Ok(interpolation)
+    }
+
+    pub fn validate(&self) -> Result<(), InterpolationError> {
+        match self.method {
+            InterpolationMethod::MonotoneConvex | InterpolationMethod::FlatForward
+                if self.variable != InterpolationVariable::ZeroRate =>
+            {
+                Err(InterpolationError::RequiresZeroRates(self.method))
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+impl Default for CurveInterpolation {
+    fn default() -> Self {
+        Self::LINEAR_ZERO
+    }
+}
+
+// 5-point Gauss-Legendre rule on [-1, 1]; exact up to degree 9, so for the linear and
+// cubic forward interpolants, the only ones allowed on forwards
+const GAUSS_NODES: [f64; 5] = [
+    -0.906_179_845_938_664,
+    -0.538_469_310_105_683,
+    0.0,
+    0.538_469_310_105_683,
+    0.906_179_845_938_664,
+];
+const GAUSS_WEIGHTS: [f64; 5] = [
+    0.236_926_885_056_189,
+    0.478_628_670_499_366,
+    0.568_888_888_888_889,
+    0.478_628_670_499_366,
+    0.236_926_885_056_189,
+];
+
+/// Interpolated node values of a rate curve, in curve time.
+#[derive(Debug, Clone)]
+pub(crate) struct CurveNodes<T: Real> {
+    variable: InterpolationVariable,
+    times: Vec<T>,
+    interpolator: AnyInterpolator<T>,
+    /// Integrated forward from zero to each node; only used for forward interpolation.
+    cumulative: Vec<T>,
+}
+
+impl<T: Real> CurveNodes<T> {
+    /// A node at `t = 0` carries no information for zero rates or forwards, so it is
+    /// held equal to the next node and the curve starts flat.
+    pub(crate) fn new(
+        interpolation: CurveInterpolation,
+        times: Vec<T>,
+        mut values: Vec<T>,
+    ) -> Result<Self, InterpolationError> {
+        interpolation.validate()?;
+        let flat_start = matches!(
+            interpolation.variable,
+            InterpolationVariable::ZeroRate | InterpolationVariable::InstantaneousForward
+        );
+        if flat_start && times.len() > 1 && values.len() > 1 && times[0] == T::zero() {
+            values[0] = values[1];
+        }
+
+        let interpolator = interpolation.method.build(times.clone(), values)?;
+        let mut nodes = Self {
+            variable: interpolation.variable,
+            times,
+            interpolator,
+            cumulative: Vec::new(),
+        };
+
+        if nodes.variable == InterpolationVariable::InstantaneousForward {
+            let mut total = T::zero();
+            let mut previous = T::zero();
+            for i in 0..nodes.times.len() {
+                let t = nodes.times[i];
+                if t > previous {
+                    total += nodes.integrate(previous, t);
+                    previous = t;
+                }
+                nodes.cumulative.push(total);
+            }
+        }
+
+        Ok(nodes)
+    }
+
+    /// Interpolated node variable at `t`.
+    pub(crate) fn value(&self, t: T) -> T {
+        self.interpolator.interpolate(t)
+    }
+
+    pub(crate) fn discount_factor(&self, t: T) -> T {
+        match self.variable {
+            InterpolationVariable::ZeroRate => (-self.value(t) * t).exp(),
+            InterpolationVariable::DiscountFactor => self.value(t),
+            InterpolationVariable::LogDiscount => self.value(t).exp(),
+            InterpolationVariable::InstantaneousForward => (-self.integrated_forward(t)).exp(),
+        }
+    }
+
+    /// Continuously compounded zero rate.
+    pub(crate) fn zero_rate(&self, t: T) -> T {
+        if self.variable == InterpolationVariable::ZeroRate {
+            return self.value(t);
+        }
+
+        let t = t.max(T::from_f64(1e-8));
+        -self.discount_factor(t).ln() / t
+    }
+
+    pub(crate) fn instantaneous_forward(&self, t: T) -> T {
+        if self.variable == InterpolationVariable::InstantaneousForward {
+            return self.value(t);
+        }
+
+        let h = T::from_f64(1e-5);
+        let lo = (t - h).max(T::zero());
+        let hi = lo + h + h;
+        (self.discount_factor(lo).ln() - self.discount_factor(hi).ln()) / (hi - lo)
+    }
+
+    fn integrated_forward(&self, t: T) -> T {
+        if t <= T::zero() {
+            return T::zero();
+        }
+
+        // Last node at or before t, then the remaining piece within one interval
+        let mut base = T::zero();
+        let mut from = T::zero();
+        for (i, &node) in self.times.iter().enumerate() {
+            if node > t {
+                break;
+            }
+            if node > T::zero() {
+                base = self.cumulative[i];
+                from = node;
+            }
+        }
+
+        base + self.integrate(from, t)
+    }
+
+    fn integrate(&self, a: T, b: T) -> T {
+        let half = (b - a) * T::from_f64(0.5);
+        let mid = (a + b) * T::from_f64(0.5);
+        let mut sum = T::zero();
+        for (&node, &weight) in GAUSS_NODES.iter().zip(&GAUSS_WEIGHTS) {
+            sum += T::from_f64(weight) * self.value(mid + half * T::from_f64(node));
+        }
+        sum * half
+    }
+}
+
+/// Secant iteration for a single curve node, from `guess` until `|residual| <= tolerance`.
+/// Returns `Ok(None)` if it fails to converge.
+pub(crate) fn solve_node<T, E, F>(guess: T, tolerance: T, mut residual: F) -> Result<Option<T>, E>
+where
+    T: Real,
+    F: FnMut(T) -> Result<T, E>,
+{
+    const MAX_ITERATIONS: usize = 100;
+
+    let mut x0 = guess;
+    let mut f0 = residual(x0)?;
+    if f0.abs() <= tolerance {
+        return Ok(Some(x0));
+    }
+
+    let mut x1 = x0 + T::from_f64(1e-4);
+    for _ in 0..MAX_ITERATIONS {
+        let f1 = residual(x1)?;
+        if f1.abs() <= tolerance {
+            return Ok(Some(x1));
+        }
+        if f1 == f0 {
+            break;
+        }
+
+        let x2 = x1 - f1 * (x1 - x0) / (f1 - f0);
+        x0 = x1;
+        f0 = f1;
+        x1 = x2;
+    }
+
+    Ok(None)
+}
@@ -0,0 +1,179 @@
+//! Exchange and market date rules: IMM and ASX futures dates, listed option expiries
+//! and CDS roll dates.
+//!
+//! `next_*` and `previous_*` functions return dates strictly after or before `date`.
+
+use chrono::{Datelike, Days, Months, NaiveDate, Weekday};
+
+use crate::core::calendars::nth_weekday;
+use crate::core::conventions::BusinessDayConvention;
+use crate::core::error::DateRuleError;
+use crate::core::tenor::Tenor;
+use crate::traits::calendar::Calendar;
+
+/// Futures month codes, January to December.
+const MONTH_CODES: [char; 12] = ['F', 'G', 'H', 'J', 'K', 'M', 'N', 'Q', 'U', 'V', 'X', 'Z'];
+
+fn is_quarter_month(month: u32) -> bool {
+    month.is_multiple_of(3)
+}
+
+pub fn third_wednesday(year: i32, month: u32) -> NaiveDate {
+    nth_weekday(year, month, Weekday::Wed, 3)
+}
+
+/// Third Wednesday of any month, or only of March, June, September and December for
+/// the `main_cycle`.
+pub fn is_imm_date(date: NaiveDate, main_cycle: bool) -> bool {
+    (!main_cycle || is_quarter_month(date.month()))
+        && date == third_wednesday(date.year(), date.month())
+}
+
+pub fn next_imm_date(date: NaiveDate, main_cycle: bool) -> NaiveDate {
+    next_monthly(date, main_cycle, third_wednesday)
+}
+
+pub fn previous_imm_date(date: NaiveDate, main_cycle: bool) -> NaiveDate {
+    previous_monthly(date, main_cycle, third_wednesday)
+}
+
+/// Code of an IMM date, e.g. `H5` for the March 2025 contract.
+pub fn imm_code(date: NaiveDate) -> Option<String> {
+    if !is_imm_date(date, false) {
+        return None;
+    }
+    let letter = MONTH_CODES[date.month0() as usize];
+    Some(format!("{}{}", letter, date.year().rem_euclid(10)))
+}
+
+/// First IMM date on or after `reference` matching `code`.
+pub fn imm_date_from_code(code: &str, reference: NaiveDate) -> Result<NaiveDate, DateRuleError> {
+    let invalid = || DateRuleError::InvalidImmCode(code.to_string());
+    let mut chars = code.trim().chars();
+    let (Some(letter), Some(digit), None) = (chars.next(), chars.next(), chars.next()) else {
+        return Err(invalid());
+    };
+    let month0 = MONTH_CODES
+        .iter()
+        .position(|&c| c == letter.to_ascii_uppercase())
+        .ok_or_else(invalid)?;
+    let digit = digit.to_digit(10).ok_or_else(invalid)? as i32;
+
+    let decade = reference.year() - reference.year().rem_euclid(10);
+    let date = third_wednesday(decade + digit, month0 as u32 + 1);
+    if date >= reference {
+        Ok(date)
+    } else {
+        Ok(third_wednesday(decade + digit + 10, month0 as u32 + 1))
+    }
+}
+
+/// Second Friday of any month, or only of March, June, September and December for the
+/// `main_cycle`.
+pub fn is_asx_date(date: NaiveDate, main_cycle: bool) -> bool {
+    (!main_cycle || is_quarter_month(date.month()))
+        && date == second_friday(date.year(), date.month())
+}
+
+pub fn next_asx_date(date: NaiveDate, main_cycle: bool) -> NaiveDate {
+    next_monthly(date, main_cycle, second_friday)
+}
+
+pub fn previous_asx_date(date: NaiveDate, main_cycle: bool) -> NaiveDate {
+    previous_monthly(date, main_cycle, second_friday)
+}
+
+fn second_friday(year: i32, month: u32) -> NaiveDate {
+    nth_weekday(year, month, Weekday::Fri, 2)
+}
+
+/// Standard monthly equity option expiry: the third Friday, or the business day
+/// before it if the exchange is closed.
+pub fn monthly_expiry(year: i32, month: u32, calendar: &dyn Calendar) -> NaiveDate {
+    let third_friday = nth_weekday(year, month, Weekday::Fri, 3);
+    BusinessDayConvention::Preceding.adjust(third_friday, calendar)
+}
+
+/// First monthly expiry on or after `date`.
+pub fn next_monthly_expiry(date: NaiveDate, calendar: &dyn Calendar) -> NaiveDate {
+    let expiry = monthly_expiry(date.year(), date.month(), calendar);
+    if expiry >= date {
+        return expiry;
+    }
+    let next = date.with_day(1).unwrap() + Months::new(1);
+    monthly_expiry(next.year(), next.month(), calendar)
+}
+
+/// Weekly option expiry for the week of `date`: the Friday on or after it, or the
+/// business day before that Friday if the exchange is closed.
+pub fn weekly_expiry(date: NaiveDate, calendar: &dyn Calendar) -> NaiveDate {
+    let days_to_friday =
+        (Weekday::Fri.num_days_from_monday() + 7 - date.weekday().num_days_from_monday()) % 7;
+    let friday = date + Days::new(days_to_friday as u64);
+    BusinessDayConvention::Preceding.adjust(friday, calendar)
+}
+
+/// The 20th of March, June, September or December.
+pub fn is_cds_date(date: NaiveDate) -> bool {
+    is_quarter_month(date.month()) && date.day() == 20
+}
+
+pub fn next_cds_date(date: NaiveDate) -> NaiveDate {
+    next_monthly(date, true, cds_roll)
+}
+
+pub fn previous_cds_date(date: NaiveDate) -> NaiveDate {
+    previous_monthly(date, true, cds_roll)
+}
+
+fn cds_roll(year: i32, month: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(year, month, 20).unwrap()
+}
+
+/// Scheduled maturity of a standard CDS traded on `trade_date`, under the 2015 rule
+/// that rolls the maturity semi-annually on March 20 and September 20.
+pub fn cds_maturity(trade_date: NaiveDate, tenor: Tenor) -> NaiveDate {
+    let mut anchor = if is_cds_date(trade_date) {
+        trade_date
+    } else {
+        previous_cds_date(trade_date)
+    };
+    if anchor.month() == 6 || anchor.month() == 12 {
+        anchor = Tenor::Months(-3).advance(anchor);
+    }
+    Tenor::Months(3).advance(tenor.advance(anchor))
+}
+
+fn next_monthly(
+    date: NaiveDate,
+    main_cycle: bool,
+    rule: impl Fn(i32, u32) -> NaiveDate,
+) -> NaiveDate {
+    let mut month = date.with_day(1).unwrap();
+    loop {
+        if !main_cycle || is_quarter_month(month.month()) {
+            let candidate = rule(month.year(), month.month());
+            if candidate > date {
+                return candidate;
+            }
+        }
+        month = month + Months::new(1);
+    }
+}
+
+fn previous_monthly(
+    date: NaiveDate,
+    main_cycle: bool,
+    rule: impl Fn(i32, u32) -> NaiveDate,
+) -> NaiveDate {
+    let mut month = date.with_day(1).unwrap();
+    loop {
+        if !main_cycle || is_quarter_month(month.month()) {
+            let candidate = rule(month.year(), month.month());
+            if candidate < date {
+                return candidate;
+            }
+        }
+        month = month - Months::new(1);
+    }
+}
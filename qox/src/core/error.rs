@@ -1,11 +1,24 @@
+use chrono::NaiveDate;
 use thiserror::Error;
 
+use crate::core::schedule::DateGeneration;
+use crate::core::tenor::Tenor;
+use crate::math::interpolate::InterpolationMethod;
+
 #[derive(Debug, thiserror::Error)]
 pub enum QoxError {
     #[error(transparent)]
     Curve(#[from] CurveError),
     #[error(transparent)]
     Interpolation(#[from] InterpolationError),
+    #[error(transparent)]
+    Calendar(#[from] CalendarError),
+    #[error(transparent)]
+    Schedule(#[from] ScheduleError),
+    #[error(transparent)]
+    Tenor(#[from] TenorError),
+    #[error(transparent)]
+    DateRule(#[from] DateRuleError),
 }
 
 #[derive(Debug, Error)]
@@ -16,12 +29,68 @@ pub enum InterpolationError {
     InsufficientPoints,
     #[error("values must be strictly increasing")]
     NotMonotonic,
+    #[error("{0:?} interpolation needs zero-rate nodes")]
+    RequiresZeroRates(InterpolationMethod),
 }
 
 #[derive(Debug, Error)]
 pub enum CurveError {
     #[error("tenors and rates must have same length")]
     LengthMismatch,
+    #[error("no instruments to bootstrap from")]
+    NoInstruments,
+    #[error("more than one instrument matures on {0}")]
+    DuplicatePillar(NaiveDate),
+    #[error("instrument maturity {0} is not after the reference date")]
+    InvalidPillar(NaiveDate),
+    #[error("bootstrap did not converge for the pillar at {0}")]
+    BootstrapFailed(NaiveDate),
+    #[error("no projection curve for index {0}")]
+    MissingProjection(String),
+    #[error("no fixing for the coupon accruing from {0}")]
+    MissingFixing(NaiveDate),
+    #[error("curve reference date {curve} is not the market date {market}")]
+    ReferenceDateMismatch { curve: NaiveDate, market: NaiveDate },
+    #[error("discount curve time at {0} is not on the projection curve's day count")]
+    DayCountMismatch(NaiveDate),
+    #[error("curve has {nodes} nodes but {variables} AD variables were seeded")]
+    SeedMismatch { nodes: usize, variables: usize },
     #[error(transparent)]
     Interpolation(#[from] InterpolationError),
-}
\ No newline at end of file
+}
+
+#[derive(Debug, Error)]
+pub enum CalendarError {
+    #[error("could not read holiday file")]
+    Io(#[from] std::io::Error),
+    #[error("line {line}: '{value}' is not an ISO 8601 date")]
+    InvalidDate { line: usize, value: String },
+}
+
+#[derive(Debug, Error)]
+pub enum ScheduleError {
+    #[error("termination date {termination} is not after the effective date {effective}")]
+    InvalidDates {
+        effective: NaiveDate,
+        termination: NaiveDate,
+    },
+    #[error("stub date {0} is outside the schedule")]
+    InvalidStubDate(NaiveDate),
+    /// The tenor is not positive, or too short for the rule's roll dates.
+    #[error("{rule:?} schedules cannot roll by {tenor}")]
+    InvalidTenor { tenor: Tenor, rule: DateGeneration },
+}
+
+#[derive(Debug, Error)]
+pub enum TenorError {
+    #[error("empty tenor")]
+    Empty,
+    #[error("'{0}' is not a tenor")]
+    Invalid(String),
+}
+
+#[derive(Debug, Error)]
+pub enum DateRuleError {
+    #[error("'{0}' is not an IMM code")]
+    InvalidImmCode(String),
+}
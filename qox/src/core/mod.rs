@@ -1,6 +1,11 @@
+pub mod calendars;
+pub mod clock;
 pub mod conventions;
+pub mod curve_interpolation;
+pub mod date_rules;
 pub mod error;
 pub mod period;
 pub mod rate;
+pub mod schedule;
 pub mod tenor;
 pub mod yield_curve;
\ No newline at end of file
@@ -1,11 +1,12 @@
 // src/engine.rs
-use chrono::{NaiveDate, Datelike};
+use chrono::{NaiveDate, Datelike, Months};
 pub use crate::core::conventions::{
     Thirty360Subtype,
     DayCountConvention,
 };
 use crate::{Days, Years};
 
+#[derive(Debug, Clone, Copy, Default)]
 pub struct DefaultPeriodCalculator;
 
 /// Only handles integer day counts
@@ -23,6 +24,22 @@ pub trait PeriodCalculator<'a> {
         end: NaiveDate,
         convention: DayCountConvention<'a>,
     ) -> Years;
+
+    /// Year fraction of `[start, end]` inside the coupon period
+    /// `[reference_start, reference_end]`. Only Act/Act ICMA and Act/365L depend on the
+    /// reference period; `year_fraction` takes `[start, end]` itself as the reference
+    /// for Act/365L, and counts Act/Act ICMA without a coupon period as Act/Act ISDA.
+    fn year_fraction_in_period(
+        &self,
+        start: NaiveDate,
+        end: NaiveDate,
+        reference_start: NaiveDate,
+        reference_end: NaiveDate,
+        convention: DayCountConvention<'a>,
+    ) -> Years {
+        let _ = (reference_start, reference_end);
+        self.year_fraction(start, end, convention)
+    }
 }
 
 impl<'a> PeriodCalculator<'a> for DefaultPeriodCalculator {
@@ -69,6 +86,22 @@ impl<'a> PeriodCalculator<'a> for DefaultPeriodCalculator {
                 Years(days / 252.0)
             }
 
+            DayCountConvention::ActActICMA => {
+                self.year_fraction(start, end, DayCountConvention::ActActISDA)
+            }
+
+            DayCountConvention::Actual365Leap => {
+                self.year_fraction_in_period(start, end, start, end, convention)
+            }
+
+            DayCountConvention::ActActAFB => Years(act_act_afb(start, end)),
+
+            DayCountConvention::NL365 => {
+                Years(calculate_days(start, end, convention).0 as f64 / 365.0)
+            }
+
+            DayCountConvention::OneOne => Years(1.0),
+
             DayCountConvention::ActActISDA => {
                 let mut current = start;
                 let mut acc = 0.0;
@@ -99,6 +132,127 @@ impl<'a> PeriodCalculator<'a> for DefaultPeriodCalculator {
             }
         }
     }
+
+    fn year_fraction_in_period(
+        &self,
+        start: NaiveDate,
+        end: NaiveDate,
+        reference_start: NaiveDate,
+        reference_end: NaiveDate,
+        convention: DayCountConvention<'a>,
+    ) -> Years {
+        if start == end {
+            return Years(0.0);
+        }
+
+        if end < start {
+            let (rs, re) = (reference_start, reference_end);
+            return Years(-self.year_fraction_in_period(end, start, rs, re, convention).0);
+        }
+
+        match convention {
+            // A reference period under half a month has no coupon frequency
+            DayCountConvention::ActActICMA
+                if reference_months(reference_start, reference_end) == 0 =>
+            {
+                self.year_fraction(start, end, DayCountConvention::ActActISDA)
+            }
+
+            DayCountConvention::ActActICMA => {
+                Years(act_act_icma(start, end, reference_start, reference_end))
+            }
+
+            DayCountConvention::Actual365Leap => {
+                let days = (end - start).num_days() as f64;
+                let leap = if reference_months(reference_start, reference_end) >= 12 {
+                    (start.year()..=end.year())
+                        .filter_map(|y| NaiveDate::from_ymd_opt(y, 2, 29))
+                        .any(|d| start < d && d <= end)
+                } else {
+                    is_leap_year(end.year())
+                };
+                Years(days / if leap { 366.0 } else { 365.0 })
+            }
+
+            _ => self.year_fraction(start, end, convention),
+        }
+    }
+}
+
+/// Whole months in a reference period, rounded to the nearest month.
+fn reference_months(start: NaiveDate, end: NaiveDate) -> u32 {
+    (12.0 * (end - start).num_days() as f64 / 365.0).round() as u32
+}
+
+/// Act/Act ICMA. Regular periods accrue `1 / frequency` pro rata to the days of their
+/// reference period; long stubs are split into notional reference periods. The
+/// reference period must round to at least one month.
+fn act_act_icma(
+    start: NaiveDate,
+    end: NaiveDate,
+    reference_start: NaiveDate,
+    reference_end: NaiveDate,
+) -> f64 {
+    let months = reference_months(reference_start, reference_end);
+    let period = months as f64 / 12.0;
+    let step = Months::new(months);
+
+    if end <= reference_end {
+        if start >= reference_start {
+            let reference_days = (reference_end - reference_start).num_days() as f64;
+            return period * (end - start).num_days() as f64 / reference_days;
+        }
+
+        // Long front stub: accrue the part before the reference period in the
+        // notional period preceding it
+        let previous_start = reference_start - step;
+        if end > reference_start {
+            act_act_icma(start, reference_start, previous_start, reference_start)
+                + act_act_icma(reference_start, end, reference_start, reference_end)
+        } else {
+            act_act_icma(start, end, previous_start, reference_start)
+        }
+    } else {
+        // Long back stub: whole notional periods after the reference period, then the rest
+        let mut sum =
+            act_act_icma(start.max(reference_start), reference_end, reference_start, reference_end);
+        if start < reference_start {
+            sum += act_act_icma(start, reference_start, reference_start, reference_end);
+        }
+        let mut notional_start = reference_end;
+        loop {
+            let notional_end = notional_start + step;
+            if end <= notional_end {
+                return sum + act_act_icma(notional_start, end, notional_start, notional_end);
+            }
+            sum += period;
+            notional_start = notional_end;
+        }
+    }
+}
+
+/// Act/Act AFB: whole years counted back from the end, then the remainder over 365 or
+/// 366 if it contains February 29.
+fn act_act_afb(start: NaiveDate, end: NaiveDate) -> f64 {
+    let mut years = 0.0;
+    let mut period_end = end;
+    loop {
+        let mut previous = period_end - Months::new(12);
+        if previous.month() == 2 && previous.day() == 28 && is_leap_year(previous.year()) {
+            previous = previous.succ_opt().unwrap();
+        }
+        if previous < start {
+            break;
+        }
+        years += 1.0;
+        period_end = previous;
+    }
+
+    let contains_leap_day = (start.year()..=period_end.year())
+        .filter_map(|y| NaiveDate::from_ymd_opt(y, 2, 29))
+        .any(|d| start <= d && d < period_end);
+    let denom = if contains_leap_day { 366.0 } else { 365.0 };
+    years + (period_end - start).num_days() as f64 / denom
 }
 
 fn is_leap_year(year: i32) -> bool {
@@ -114,22 +268,28 @@ fn calculate_days<'a>(
     let d = match convention {
         DayCountConvention::Actual360
         | DayCountConvention::Actual365Fixed
-        | DayCountConvention::ActActISDA => {
+        | DayCountConvention::ActActISDA
+        | DayCountConvention::ActActICMA
+        | DayCountConvention::ActActAFB
+        | DayCountConvention::Actual365Leap => {
             (end - start).num_days()
         }
 
-        DayCountConvention::Business252(calendar) => {
-            let mut count = 0;
-            let mut current = start;
+        DayCountConvention::NL365 => {
+            let (from, to) = (start.min(end), start.max(end));
+            let leap_days = (from.year()..=to.year())
+                .filter_map(|y| NaiveDate::from_ymd_opt(y, 2, 29))
+                .filter(|&d| from <= d && d < to)
+                .count() as i64;
+            (end - start).num_days() - leap_days * (end - start).num_days().signum()
+        }
 
-            while current < end {
-                if calendar.is_business_day(current) {
-                    count += 1;
-                }
-                current = current.succ_opt().expect("Date overflow");
-            }
+        DayCountConvention::OneOne => {
+            if end >= start { 1 } else { -1 }
+        }
 
-            count
+        DayCountConvention::Business252(calendar) => {
+            calendar.business_days_between(start, end)
         }
 
         DayCountConvention::Thirty360(subtype) => {
@@ -138,7 +298,7 @@ fn calculate_days<'a>(
             let mut d1 = start.day() as i64;
 
             let y2 = end.year() as i64;
-            let m2 = end.month() as i64;
+            let mut m2 = end.month() as i64;
             let mut d2 = end.day() as i64;
 
             match subtype {
@@ -161,6 +321,16 @@ fn calculate_days<'a>(
                     }
                 }
 
+                Thirty360Subtype::EuropeanPlus => {
+                    if d1 == 31 {
+                        d1 = 30;
+                    }
+                    if d2 == 31 {
+                        d2 = 1;
+                        m2 += 1;
+                    }
+                }
+
                 Thirty360Subtype::German => {
                     let is_last_feb = |date: NaiveDate| -> bool {
                         date.month() == 2
@@ -1,4 +1,12 @@
-use crate::{core::period::DayCountConvention, types::Real};
+use chrono::NaiveDate;
+
+use crate::{
+    core::{
+        period::{DayCountConvention, PeriodCalculator},
+        tenor::Tenor,
+    },
+    types::Real,
+};
 
 #[derive(Debug, Clone, Copy)]
 pub enum Compounding {
@@ -18,6 +26,37 @@ pub enum Frequency {
     Infinite = -1,
 }
 
+impl Frequency {
+    /// Length of one period, or `None` for a single or continuous period.
+    pub fn tenor(&self) -> Option<Tenor> {
+        match self {
+            Frequency::Annual => Some(Tenor::Years(1)),
+            Frequency::SemiAnnual => Some(Tenor::Months(6)),
+            Frequency::Quarterly => Some(Tenor::Months(3)),
+            Frequency::Monthly => Some(Tenor::Months(1)),
+            Frequency::Once | Frequency::Infinite => None,
+        }
+    }
+}
+
+/// Floating-rate index, e.g. a 3M term rate, identified by name in a market.
+#[derive(Debug, Clone, Copy)]
+pub struct RateIndex<'a> {
+    pub name: &'a str,
+    pub tenor: Tenor,
+    pub convention: DayCountConvention<'a>,
+}
+
+impl<'a> RateIndex<'a> {
+    pub fn new(name: &'a str, tenor: Tenor, convention: DayCountConvention<'a>) -> Self {
+        Self {
+            name,
+            tenor,
+            convention,
+        }
+    }
+}
+
 pub trait Discountable<T: Real> {
     fn discount_factor(&self, t: T) -> T;
 }
@@ -48,68 +87,117 @@ impl<'a, T> InterestRate<'a, T> {
 
 impl<'a, T: Real> Discountable<T> for InterestRate<'a, T> {
     fn discount_factor(&self, t: T) -> T {
+        T::one() / self.compound_factor(t)
+    }
+}
+
+impl<'a, T: Real> InterestRate<'a, T> {
+    /// Growth of one unit invested at this rate for `t` years.
+    pub fn compound_factor(&self, t: T) -> T {
         let one = T::one();
         let r = self.value;
+        let compounded = || {
+            let f = T::from_f64(self.frequency as i32 as f64);
+            // (1 + r/f)^(f*t)
+            (one + r / f).powf(f * t)
+        };
 
         match self.compounding {
-            Compounding::Simple => {
-                // df = 1 / (1 + r * t)
-                one / (one + r * t)
-            }
+            Compounding::Simple => one + r * t,
 
-            Compounding::Continuous => {
-                let rt = r * t;
-                (-rt).exp()
-            }
+            Compounding::Continuous => (r * t).exp(),
 
-            Compounding::Compounded => {
-                let f = T::from_f64(self.frequency as i32 as f64);
-                // df = 1 / (1 + r/f)^(f*t)
-                let base = one + (r / f);
-                let exponent = f * t;
-                one / base.powf(exponent)
-            }
+            Compounding::Compounded => compounded(),
 
             Compounding::SimpleThenCompounded => {
                 if t <= one {
-                    one / (one + r * t)
+                    one + r * t
                 } else {
-                    let f = T::from_f64(self.frequency as i32 as f64);
-                    let base = one + (r / f);
-                    let exponent = f * t;
-                    one / base.powf(exponent)
+                    compounded()
                 }
             }
         }
     }
-}
 
-// Small helper to avoid syntax clutter for (r * t)
+    /// Compound factor from `start` to `end`, accruing under this rate's day count.
+    pub fn compound_factor_between(
+        &self,
+        start: NaiveDate,
+        end: NaiveDate,
+        calculator: &dyn PeriodCalculator<'a>,
+    ) -> T {
+        let t = calculator.year_fraction(start, end, self.convention);
+        self.compound_factor(T::from_f64(t.0))
+    }
+
+    /// Discount factor from `end` back to `start`, accruing under this rate's day count.
+    pub fn discount_factor_between(
+        &self,
+        start: NaiveDate,
+        end: NaiveDate,
+        calculator: &dyn PeriodCalculator<'a>,
+    ) -> T {
+        T::one() / self.compound_factor_between(start, end, calculator)
+    }
+
+    /// Rate with the same day count that compounds to the same amount over `t` years.
+    pub fn equivalent_rate(&self, t: T, compounding: Compounding, frequency: Frequency) -> Self {
+        Self::implied_rate(
+            self.discount_factor(t),
+            t,
+            compounding,
+            frequency,
+            self.convention,
+        )
+    }
+
+    /// Rate quoted under `convention` that compounds to the same amount from `start`
+    /// to `end`.
+    pub fn equivalent_rate_between(
+        &self,
+        start: NaiveDate,
+        end: NaiveDate,
+        convention: DayCountConvention<'a>,
+        compounding: Compounding,
+        frequency: Frequency,
+        calculator: &dyn PeriodCalculator<'a>,
+    ) -> Self {
+        let t = calculator.year_fraction(start, end, convention);
+        Self::implied_rate(
+            self.discount_factor_between(start, end, calculator),
+            T::from_f64(t.0),
+            compounding,
+            frequency,
+            convention,
+        )
+    }
 
-impl<'a> InterestRate<'a, f64> {
+    /// Rate with the given conventions that discounts to `df` over `t` years.
     pub fn implied_rate(
-        df: f64,
-        t: f64,
+        df: T,
+        t: T,
         compounding: Compounding,
         frequency: Frequency,
         convention: DayCountConvention<'a>,
     ) -> Self {
+        let one = T::one();
+        let compounded = || {
+            let f = T::from_f64(frequency as i32 as f64);
+            f * ((one / df).powf(one / (f * t)) - one)
+        };
+
         let value = match compounding {
-            Compounding::Simple => (1.0 / df - 1.0) / t,
+            Compounding::Simple => (one / df - one) / t,
 
             Compounding::Continuous => -df.ln() / t,
 
-            Compounding::Compounded => {
-                let f = frequency as i32 as f64;
-                f * ((1.0 / df).powf(1.0 / (f * t)) - 1.0)
-            }
+            Compounding::Compounded => compounded(),
 
             Compounding::SimpleThenCompounded => {
-                if t <= 1.0 {
-                    (1.0 / df - 1.0) / t
+                if t <= one {
+                    (one / df - one) / t
                 } else {
-                    let f = frequency as i32 as f64;
-                    f * ((1.0 / df).powf(1.0 / (f * t)) - 1.0)
+                    compounded()
                 }
             }
         };
@@ -0,0 +1,428 @@
+use chrono::{Datelike, Days, Months, NaiveDate};
+
+use crate::core::conventions::BusinessDayConvention;
+use crate::core::date_rules::{
+    is_cds_date, is_imm_date, next_cds_date, next_imm_date, previous_cds_date, third_wednesday,
+};
+use crate::core::error::ScheduleError;
+use crate::core::rate::Frequency;
+use crate::core::tenor::Tenor;
+use crate::traits::calendar::Calendar;
+
+/// How the dates between effective and termination are generated.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DateGeneration {
+    /// Roll forward from the effective date; any stub is at the back.
+    Forward,
+    /// Roll backward from the termination date; any stub is at the front.
+    Backward,
+    /// Forward, with every date (effective and termination included) moved to the
+    /// IMM date (third Wednesday of March, June, September or December) on or after it.
+    Imm,
+    /// Forward, with intermediate dates moved to the third Wednesday of their month.
+    ThirdWednesday,
+    /// Standard CDS dates on the 20th of March, June, September and December. Accrual
+    /// starts on the roll date on or before the effective date and ends on the roll
+    /// date on or after the termination date.
+    Cds,
+}
+
+/// Length of the stub left over when the tenor does not divide the schedule.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Stub {
+    /// Keep the short period as it is.
+    Short,
+    /// Merge the short period into its regular neighbour.
+    Long,
+}
+
+/// Accrual and payment dates of a cash-flow leg.
+#[derive(Debug, Clone)]
+pub struct Schedule {
+    unadjusted_dates: Vec<NaiveDate>,
+    dates: Vec<NaiveDate>,
+    payment_dates: Vec<NaiveDate>,
+    regular: Vec<bool>,
+    tenor: Option<Tenor>,
+    rule: DateGeneration,
+}
+
+impl Schedule {
+    pub fn builder(
+        effective: NaiveDate,
+        termination: NaiveDate,
+        calendar: &dyn Calendar,
+    ) -> ScheduleBuilder<'_> {
+        ScheduleBuilder::new(effective, termination, calendar)
+    }
+
+    /// Accrual boundaries before business-day adjustment.
+    pub fn unadjusted_dates(&self) -> &[NaiveDate] {
+        &self.unadjusted_dates
+    }
+
+    /// Accrual boundaries, from the effective to the termination date.
+    pub fn dates(&self) -> &[NaiveDate] {
+        &self.dates
+    }
+
+    /// Payment date of each period, one fewer than the accrual dates.
+    pub fn payment_dates(&self) -> &[NaiveDate] {
+        &self.payment_dates
+    }
+
+    /// Adjusted accrual start and end of each period.
+    pub fn periods(&self) -> impl Iterator<Item = (NaiveDate, NaiveDate)> + '_ {
+        self.dates.windows(2).map(|w| (w[0], w[1]))
+    }
+
+    /// Whether period `i` spans a full tenor rather than a stub.
+    pub fn is_regular(&self, i: usize) -> bool {
+        self.regular[i]
+    }
+
+    pub fn tenor(&self) -> Option<Tenor> {
+        self.tenor
+    }
+
+    pub fn rule(&self) -> DateGeneration {
+        self.rule
+    }
+}
+
+/// Builds a [`Schedule`]. Without a tenor the schedule is a single period.
+#[derive(Debug, Clone, Copy)]
+pub struct ScheduleBuilder<'a> {
+    effective: NaiveDate,
+    termination: NaiveDate,
+    calendar: &'a dyn Calendar,
+    tenor: Option<Tenor>,
+    convention: BusinessDayConvention,
+    termination_convention: Option<BusinessDayConvention>,
+    payment_convention: Option<BusinessDayConvention>,
+    rule: DateGeneration,
+    end_of_month: bool,
+    stub: Stub,
+    first_date: Option<NaiveDate>,
+    next_to_last_date: Option<NaiveDate>,
+}
+
+impl<'a> ScheduleBuilder<'a> {
+    /// Defaults to backward generation with short stubs and modified following
+    /// adjustment.
+    pub fn new(effective: NaiveDate, termination: NaiveDate, calendar: &'a dyn Calendar) -> Self {
+        Self {
+            effective,
+            termination,
+            calendar,
+            tenor: None,
+            convention: BusinessDayConvention::ModifiedFollowing,
+            termination_convention: None,
+            payment_convention: None,
+            rule: DateGeneration::Backward,
+            end_of_month: false,
+            stub: Stub::Short,
+            first_date: None,
+            next_to_last_date: None,
+        }
+    }
+
+    pub fn with_tenor(mut self, tenor: Tenor) -> Self {
+        self.tenor = Some(tenor);
+        self
+    }
+
+    pub fn with_frequency(mut self, frequency: Frequency) -> Self {
+        self.tenor = frequency.tenor();
+        self
+    }
+
+    /// Adjustment of the accrual dates, and of the termination and payment dates
+    /// unless they are set separately.
+    pub fn with_convention(mut self, convention: BusinessDayConvention) -> Self {
+        self.convention = convention;
+        self
+    }
+
+    pub fn with_termination_convention(mut self, convention: BusinessDayConvention) -> Self {
+        self.termination_convention = Some(convention);
+        self
+    }
+
+    pub fn with_payment_convention(mut self, convention: BusinessDayConvention) -> Self {
+        self.payment_convention = Some(convention);
+        self
+    }
+
+    pub fn with_rule(mut self, rule: DateGeneration) -> Self {
+        self.rule = rule;
+        self
+    }
+
+    /// Keep month-end anchors on month ends, see [`Calendar::advance_period`].
+    pub fn with_end_of_month(mut self, end_of_month: bool) -> Self {
+        self.end_of_month = end_of_month;
+        self
+    }
+
+    pub fn with_stub(mut self, stub: Stub) -> Self {
+        self.stub = stub;
+        self
+    }
+
+    /// End of an explicit front stub.
+    pub fn with_first_date(mut self, date: NaiveDate) -> Self {
+        self.first_date = Some(date);
+        self
+    }
+
+    /// Start of an explicit back stub.
+    pub fn with_next_to_last_date(mut self, date: NaiveDate) -> Self {
+        self.next_to_last_date = Some(date);
+        self
+    }
+
+    pub fn build(self) -> Result<Schedule, ScheduleError> {
+        let (effective, termination) = match self.rule {
+            DateGeneration::Imm => (
+                imm_on_or_after(self.effective),
+                imm_on_or_after(self.termination),
+            ),
+            DateGeneration::Cds => (
+                cds_on_or_before(self.effective),
+                cds_on_or_after(self.termination),
+            ),
+            _ => (self.effective, self.termination),
+        };
+        if termination <= effective {
+            return Err(ScheduleError::InvalidDates {
+                effective: self.effective,
+                termination: self.termination,
+            });
+        }
+        for date in [self.first_date, self.next_to_last_date]
+            .into_iter()
+            .flatten()
+        {
+            if date <= effective || date >= termination {
+                return Err(ScheduleError::InvalidStubDate(date));
+            }
+        }
+        if let (Some(first), Some(next_to_last)) = (self.first_date, self.next_to_last_date)
+            && first >= next_to_last
+        {
+            return Err(ScheduleError::InvalidStubDate(next_to_last));
+        }
+        if let Some(tenor) = self.tenor
+            && !self.rolls_by(tenor)
+        {
+            return Err(ScheduleError::InvalidTenor {
+                tenor,
+                rule: self.rule,
+            });
+        }
+
+        let (unadjusted_dates, regular) = match self.tenor {
+            None => (vec![effective, termination], vec![true]),
+            Some(tenor) => match self.rule {
+                DateGeneration::Backward => self.backward(effective, termination, tenor),
+                DateGeneration::Forward => self.forward(effective, termination, tenor, |d| d),
+                DateGeneration::Imm => self.forward(effective, termination, tenor, |d| {
+                    imm_on_or_after(d.with_day(1).unwrap())
+                }),
+                DateGeneration::ThirdWednesday => {
+                    self.forward(effective, termination, tenor, |d| {
+                        third_wednesday(d.year(), d.month())
+                    })
+                }
+                DateGeneration::Cds => self.forward(effective, termination, tenor, |d| {
+                    cds_on_or_after(d.with_day(1).unwrap())
+                }),
+            },
+        };
+
+        let termination_convention = self.termination_convention.unwrap_or(self.convention);
+        let payment_convention = self.payment_convention.unwrap_or(self.convention);
+        let last = unadjusted_dates.len() - 1;
+        let dates = unadjusted_dates
+            .iter()
+            .enumerate()
+            .map(|(i, &date)| match i {
+                0 => self.convention.adjust(date, self.calendar),
+                i if i == last => termination_convention.adjust(date, self.calendar),
+                _ if self.rolls_to_month_end(date)
+                    && self.convention != BusinessDayConvention::Unadjusted =>
+                {
+                    self.calendar.end_of_month(date)
+                }
+                _ => self.convention.adjust(date, self.calendar),
+            })
+            .collect();
+        let payment_dates = unadjusted_dates[1..]
+            .iter()
+            .map(|&date| payment_convention.adjust(date, self.calendar))
+            .collect();
+
+        Ok(Schedule {
+            unadjusted_dates,
+            dates,
+            payment_dates,
+            regular,
+            tenor: self.tenor,
+            rule: self.rule,
+        })
+    }
+
+    fn forward(
+        &self,
+        effective: NaiveDate,
+        termination: NaiveDate,
+        tenor: Tenor,
+        map: impl Fn(NaiveDate) -> NaiveDate,
+    ) -> (Vec<NaiveDate>, Vec<bool>) {
+        let mut dates = vec![effective];
+        let mut regular = Vec::new();
+        if let Some(first) = self.first_date {
+            regular.push(map(self.roll(effective, tenor, 1)) == first);
+            dates.push(first);
+        }
+
+        let anchor = dates[dates.len() - 1];
+        let limit = self.next_to_last_date.unwrap_or(termination);
+        let mut k = 1;
+        let last_regular = loop {
+            let date = map(self.roll(anchor, tenor, k));
+            if date >= limit {
+                break date == limit;
+            }
+            dates.push(date);
+            regular.push(true);
+            k += 1;
+        };
+        regular.push(last_regular);
+
+        if let Some(next_to_last) = self.next_to_last_date {
+            dates.push(next_to_last);
+            regular.push(map(self.roll(next_to_last, tenor, 1)) == termination);
+        } else if self.stub == Stub::Long && !last_regular && k > 1 {
+            dates.pop();
+            regular.pop();
+            *regular.last_mut().unwrap() = false;
+        }
+        dates.push(termination);
+        (dates, regular)
+    }
+
+    fn backward(
+        &self,
+        effective: NaiveDate,
+        termination: NaiveDate,
+        tenor: Tenor,
+    ) -> (Vec<NaiveDate>, Vec<bool>) {
+        let mut dates = vec![termination];
+        let mut regular = Vec::new();
+        if let Some(next_to_last) = self.next_to_last_date {
+            regular.push(self.roll(termination, tenor, -1) == next_to_last);
+            dates.push(next_to_last);
+        }
+
+        let anchor = dates[dates.len() - 1];
+        let limit = self.first_date.unwrap_or(effective);
+        let mut k = 1;
+        let first_regular = loop {
+            let date = self.roll(anchor, tenor, -k);
+            if date <= limit {
+                break date == limit;
+            }
+            dates.push(date);
+            regular.push(true);
+            k += 1;
+        };
+        regular.push(first_regular);
+
+        if let Some(first) = self.first_date {
+            dates.push(first);
+            regular.push(self.roll(first, tenor, -1) == effective);
+        } else if self.stub == Stub::Long && !first_regular && k > 1 {
+            dates.pop();
+            regular.pop();
+            *regular.last_mut().unwrap() = false;
+        }
+        dates.push(effective);
+        dates.reverse();
+        regular.reverse();
+        (dates, regular)
+    }
+
+    /// `anchor` moved by `k` tenors, kept on the month end under the end-of-month rule.
+    fn roll(&self, anchor: NaiveDate, tenor: Tenor, k: i32) -> NaiveDate {
+        let date = shift(anchor, tenor, k);
+        if self.rolls_to_month_end(anchor) && matches!(tenor, Tenor::Months(_) | Tenor::Years(_)) {
+            last_day_of_month(date)
+        } else {
+            date
+        }
+    }
+
+    /// Whether `tenor` moves every date, and by at least a month for rules that snap
+    /// dates to a day of the month, or a quarter for IMM and CDS dates, so that no two
+    /// dates snap to the same one.
+    fn rolls_by(&self, tenor: Tenor) -> bool {
+        if tenor <= Tenor::Days(0) {
+            return false;
+        }
+        let months = match tenor {
+            Tenor::Months(n) => n,
+            Tenor::Years(n) => n.saturating_mul(12),
+            Tenor::Days(_) | Tenor::Weeks(_) => 0,
+        };
+        match self.rule {
+            DateGeneration::Forward | DateGeneration::Backward => true,
+            DateGeneration::ThirdWednesday => months >= 1,
+            DateGeneration::Imm | DateGeneration::Cds => months >= 3,
+        }
+    }
+
+    fn rolls_to_month_end(&self, date: NaiveDate) -> bool {
+        if !self.end_of_month {
+            return false;
+        }
+        if self.convention == BusinessDayConvention::Unadjusted {
+            last_day_of_month(date) == date
+        } else {
+            self.calendar.is_end_of_month(date)
+        }
+    }
+}
+
+fn shift(date: NaiveDate, tenor: Tenor, k: i32) -> NaiveDate {
+    tenor.scaled(k).advance(date)
+}
+
+fn last_day_of_month(date: NaiveDate) -> NaiveDate {
+    date.with_day(1).unwrap() + Months::new(1) - Days::new(1)
+}
+
+fn imm_on_or_after(date: NaiveDate) -> NaiveDate {
+    if is_imm_date(date, true) {
+        date
+    } else {
+        next_imm_date(date, true)
+    }
+}
+
+fn cds_on_or_after(date: NaiveDate) -> NaiveDate {
+    if is_cds_date(date) {
+        date
+    } else {
+        next_cds_date(date)
+    }
+}
+
+fn cds_on_or_before(date: NaiveDate) -> NaiveDate {
+    if is_cds_date(date) {
+        date
+    } else {
+        previous_cds_date(date)
+    }
+}
@@ -1,6 +1,19 @@
+use std::cmp::Ordering;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::ops::{Add, Mul, Neg, Sub};
+use std::str::FromStr;
+
 use chrono::NaiveDate;
 
+use crate::core::error::TenorError;
+use crate::core::rate::Frequency;
 
+/// Length of a period.
+///
+/// Tenors compare by length after normalization, so `12M == 1Y` and `14D == 2W`.
+/// Day-based and month-based tenors are ordered by approximate length and are
+/// unordered when that cannot tell them apart.
 #[derive(Debug, Clone, Copy)]
 pub enum Tenor {
     Days(i32),
@@ -10,12 +23,281 @@ pub enum Tenor {
 }
 
 impl Tenor {
+    /// # Panics
+    ///
+    /// If the date is out of range.
     pub fn advance(&self, from: NaiveDate) -> NaiveDate {
+        let date = match (self.days(), self.months()) {
+            (Some(n), _) => from.checked_add_signed(chrono::Duration::days(n)),
+            (_, Some(n)) => match u32::try_from(n.unsigned_abs()) {
+                Ok(m) if n >= 0 => from.checked_add_months(chrono::Months::new(m)),
+                Ok(m) => from.checked_sub_months(chrono::Months::new(m)),
+                Err(_) => None,
+            },
+            _ => unreachable!("every tenor counts days or months"),
+        };
+        date.expect("Date out of range")
+    }
+
+    /// The tenor repeated `k` times.
+    ///
+    /// # Panics
+    ///
+    /// If the count overflows.
+    pub fn scaled(&self, k: i32) -> Tenor {
+        let scale = |n: i32| {
+            n.checked_mul(k)
+                .unwrap_or_else(|| panic!("{} times {} overflows", self, k))
+        };
+        match *self {
+            Tenor::Days(n) => Tenor::Days(scale(n)),
+            Tenor::Weeks(n) => Tenor::Weeks(scale(n)),
+            Tenor::Months(n) => Tenor::Months(scale(n)),
+            Tenor::Years(n) => Tenor::Years(scale(n)),
+        }
+    }
+
+    /// Dates rolled forward from `start` every tenor, ending with `end` (a short stub
+    /// if the tenor does not divide the period). `start` itself is not included.
+    pub fn roll(&self, start: NaiveDate, end: NaiveDate) -> Vec<NaiveDate> {
+        let mut dates = Vec::new();
+        let mut k = 1;
+        loop {
+            let date = self.scaled(k).advance(start);
+            if date >= end || date <= start {
+                break;
+            }
+            dates.push(date);
+            k += 1;
+        }
+        dates.push(end);
+        dates
+    }
+
+    /// Whole weeks for day tenors and whole years for month tenors; zero is `0D`.
+    pub fn normalized(&self) -> Tenor {
         match *self {
-            Tenor::Days(n) => from + chrono::Duration::days(n as i64),
-            Tenor::Weeks(n) => from + chrono::Duration::weeks(n as i64),
-            Tenor::Months(n) => from + chrono::Months::new(n as u32),
-            Tenor::Years(n) => from + chrono::Months::new((n * 12) as u32),
+            Tenor::Days(0) | Tenor::Weeks(0) | Tenor::Months(0) | Tenor::Years(0) => Tenor::Days(0),
+            Tenor::Days(n) if n % 7 == 0 => Tenor::Weeks(n / 7),
+            Tenor::Months(n) if n % 12 == 0 => Tenor::Years(n / 12),
+            tenor => tenor,
+        }
+    }
+
+    /// Length in days, counting a year as 365.25 days.
+    pub fn approx_days(&self) -> f64 {
+        match *self {
+            Tenor::Days(n) => n as f64,
+            Tenor::Weeks(n) => 7.0 * n as f64,
+            Tenor::Months(n) => 365.25 * n as f64 / 12.0,
+            Tenor::Years(n) => 365.25 * n as f64,
+        }
+    }
+
+    /// The coupon frequency with this tenor as its period, if there is one.
+    pub fn frequency(&self) -> Option<Frequency> {
+        match self.months()? {
+            0 => Some(Frequency::Once),
+            1 => Some(Frequency::Monthly),
+            3 => Some(Frequency::Quarterly),
+            6 => Some(Frequency::SemiAnnual),
+            12 => Some(Frequency::Annual),
+            _ => None,
+        }
+    }
+
+    pub fn checked_add(self, other: Tenor) -> Option<Tenor> {
+        match (self.days(), other.days(), self.months(), other.months()) {
+            (Some(0), _, _, _) => Some(other),
+            (_, Some(0), _, _) => Some(self),
+            (Some(a), Some(b), _, _) => {
+                Some(Tenor::Days(i32::try_from(a + b).ok()?).normalized_like(self, other))
+            }
+            (_, _, Some(a), Some(b)) => {
+                Some(Tenor::Months(i32::try_from(a + b).ok()?).normalized_like(self, other))
+            }
+            _ => None,
         }
     }
-}
\ No newline at end of file
+
+    pub fn checked_sub(self, other: Tenor) -> Option<Tenor> {
+        self.checked_add(-other)
+    }
+
+    /// Length in days, for day and week tenors. Wide enough not to overflow.
+    fn days(&self) -> Option<i64> {
+        match *self {
+            Tenor::Days(n) => Some(n as i64),
+            Tenor::Weeks(n) => Some(7 * n as i64),
+            Tenor::Months(0) | Tenor::Years(0) => Some(0),
+            _ => None,
+        }
+    }
+
+    /// Length in months, for month and year tenors. Wide enough not to overflow.
+    fn months(&self) -> Option<i64> {
+        match *self {
+            Tenor::Months(n) => Some(n as i64),
+            Tenor::Years(n) => Some(12 * n as i64),
+            Tenor::Days(0) | Tenor::Weeks(0) => Some(0),
+            _ => None,
+        }
+    }
+
+    /// Keeps the unit of the operands when they share one.
+    fn normalized_like(self, a: Tenor, b: Tenor) -> Tenor {
+        match (a, b, self) {
+            (Tenor::Weeks(_), Tenor::Weeks(_), Tenor::Days(n)) => Tenor::Weeks(n / 7),
+            (Tenor::Years(_), Tenor::Years(_), Tenor::Months(n)) => Tenor::Years(n / 12),
+            _ => self,
+        }
+    }
+}
+
+impl PartialEq for Tenor {
+    fn eq(&self, other: &Self) -> bool {
+        match (self.days(), other.days()) {
+            (Some(a), Some(b)) => a == b,
+            _ => self.months().is_some() && self.months() == other.months(),
+        }
+    }
+}
+
+impl Eq for Tenor {}
+
+impl Hash for Tenor {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        match self.normalized() {
+            Tenor::Days(n) => (0, n as i64).hash(state),
+            Tenor::Weeks(n) => (0, 7 * n as i64).hash(state),
+            Tenor::Months(n) => (1, n as i64).hash(state),
+            Tenor::Years(n) => (1, 12 * n as i64).hash(state),
+        }
+    }
+}
+
+impl PartialOrd for Tenor {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        match (self.days(), other.days(), self.months(), other.months()) {
+            (Some(a), Some(b), _, _) => Some(a.cmp(&b)),
+            (_, _, Some(a), Some(b)) => Some(a.cmp(&b)),
+            _ => match self.approx_days().partial_cmp(&other.approx_days())? {
+                Ordering::Equal => None,
+                ordering => Some(ordering),
+            },
+        }
+    }
+}
+
+impl Neg for Tenor {
+    type Output = Tenor;
+
+    fn neg(self) -> Tenor {
+        self.scaled(-1)
+    }
+}
+
+impl Mul<i32> for Tenor {
+    type Output = Tenor;
+
+    fn mul(self, k: i32) -> Tenor {
+        self.scaled(k)
+    }
+}
+
+impl Add for Tenor {
+    type Output = Tenor;
+
+    /// # Panics
+    ///
+    /// If one tenor is day-based and the other month-based; see
+    /// [`checked_add`](Tenor::checked_add).
+    fn add(self, other: Tenor) -> Tenor {
+        self.checked_add(other)
+            .unwrap_or_else(|| panic!("cannot add {} and {}", self, other))
+    }
+}
+
+impl Sub for Tenor {
+    type Output = Tenor;
+
+    /// # Panics
+    ///
+    /// If one tenor is day-based and the other month-based; see
+    /// [`checked_sub`](Tenor::checked_sub).
+    fn sub(self, other: Tenor) -> Tenor {
+        self.checked_sub(other)
+            .unwrap_or_else(|| panic!("cannot subtract {} from {}", other, self))
+    }
+}
+
+/// Normalized form: `2W`, `1Y`, `1Y6M`, `-3M`.
+impl fmt::Display for Tenor {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.normalized() {
+            Tenor::Days(n) => write!(f, "{}D", n),
+            Tenor::Weeks(n) => write!(f, "{}W", n),
+            Tenor::Years(n) => write!(f, "{}Y", n),
+            Tenor::Months(n) if n.abs() > 12 => {
+                let sign = if n < 0 { "-" } else { "" };
+                write!(f, "{}{}Y{}M", sign, n.abs() / 12, n.abs() % 12)
+            }
+            Tenor::Months(n) => write!(f, "{}M", n),
+        }
+    }
+}
+
+/// Parses `3M`, `1Y6M`, `2W`, `-10D` and the money-market tenors `ON`, `TN` and `SN`,
+/// ignoring case. The money-market tenors are read as their maturity from the trade
+/// date under T+2 settlement: `1D`, `2D` and `3D`.
+impl FromStr for Tenor {
+    type Err = TenorError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let text = s.trim().to_ascii_uppercase();
+        match text.as_str() {
+            "" => return Err(TenorError::Empty),
+            "ON" => return Ok(Tenor::Days(1)),
+            "TN" => return Ok(Tenor::Days(2)),
+            "SN" => return Ok(Tenor::Days(3)),
+            _ => {}
+        }
+
+        let invalid = || TenorError::Invalid(s.to_string());
+        let (sign, body) = match text.strip_prefix('-') {
+            Some(rest) => (-1, rest),
+            None => (1, text.strip_prefix('+').unwrap_or(&text)),
+        };
+
+        let mut total: Option<Tenor> = None;
+        let mut digits = String::new();
+        for c in body.chars() {
+            if c.is_ascii_digit() {
+                digits.push(c);
+                continue;
+            }
+            let n: i32 = digits.parse().map_err(|_| invalid())?;
+            digits.clear();
+            let part = match c {
+                'D' => Tenor::Days(sign * n),
+                'W' => Tenor::Weeks(sign * n),
+                'M' => Tenor::Months(sign * n),
+                'Y' => Tenor::Years(sign * n),
+                _ => return Err(invalid()),
+            };
+            total = match total {
+                None => Some(part),
+                Some(total) => Some(total.checked_add(part).ok_or_else(invalid)?),
+            };
+        }
+        if !digits.is_empty() {
+            return Err(invalid());
+        }
+        // Lengths in days or months must fit the same range as the count
+        let tenor = total.ok_or_else(invalid)?;
+        match tenor.days().or(tenor.months()).map(i32::try_from) {
+            Some(Ok(_)) => Ok(tenor),
+            _ => Err(invalid()),
+        }
+    }
+}
@@ -1,24 +1,554 @@
-// use chrono::{DateTime, Utc};
-
-// /// Trait for anything that can be used to build a yield curve
-// pub trait CurveInstrument {
-//     fn maturity_date(&self) -> DateTime<Utc>;
-//     // You might add methods for par rate, market price, etc.
-// }
-
-// pub struct YieldCurve<T: CurveInstrument> {
-//     pub evaluation_date: DateTime<Utc>,
-//     pub instruments: Vec<T>,
-// }
-
-// impl<T: CurveInstrument> YieldCurve<T> {
-//     pub fn new(evaluation_date: DateTime<Utc>, mut instruments: Vec<T>) -> Self {
-//         // Sort by maturity using the trait method
-//         instruments.sort_by_key(|inst| inst.maturity_date());
-        
-//         Self {
-//             evaluation_date,
-//             instruments,
-//         }
-//     }
-// }
\ No newline at end of file
+use chrono::NaiveDate;
+
+use crate::core::curve_interpolation::{
+    CurveInterpolation, CurveNodes, InterpolationVariable, solve_node,
+};
+use crate::core::error::CurveError;
+use crate::core::period::{DayCountConvention, PeriodCalculator};
+use crate::core::rate::{Compounding, Frequency, InterestRate};
+use crate::core::tenor::Tenor;
+use crate::traits::{market_view::check_reference_date, rate_curve::RateCurve};
+use crate::types::Real;
+
+/// Market quote that pins down the curve at its maturity date.
+pub trait CurveInstrument<'a, T: Real> {
+    /// Last date the instrument depends on; this becomes a curve pillar.
+    fn maturity_date(&self) -> NaiveDate;
+
+    /// Quoted value, in the same units as `implied_quote`.
+    fn quote(&self) -> T;
+
+    /// Quote implied by `curve`. Bootstrapping solves `implied_quote == quote`.
+    fn implied_quote(&self, curve: &YieldCurve<'a, T>) -> T;
+}
+
+/// Simply compounded forward rate between two dates.
+fn forward_rate<'a, T: Real>(
+    curve: &YieldCurve<'a, T>,
+    start: NaiveDate,
+    end: NaiveDate,
+    convention: DayCountConvention<'a>,
+) -> T {
+    let tau = curve.year_fraction(start, end, convention);
+    (curve.discount_factor_at(start) / curve.discount_factor_at(end) - T::one()) / tau
+}
+
+/// Cash deposit quoted as a simple rate.
+#[derive(Debug, Clone, Copy)]
+pub struct Deposit<'a, T> {
+    pub start: NaiveDate,
+    pub end: NaiveDate,
+    pub rate: T,
+    pub convention: DayCountConvention<'a>,
+}
+
+impl<'a, T> Deposit<'a, T> {
+    pub fn new(
+        start: NaiveDate,
+        end: NaiveDate,
+        rate: T,
+        convention: DayCountConvention<'a>,
+    ) -> Self {
+        Self {
+            start,
+            end,
+            rate,
+            convention,
+        }
+    }
+}
+
+impl<'a, T: Real> CurveInstrument<'a, T> for Deposit<'a, T> {
+    fn maturity_date(&self) -> NaiveDate {
+        self.end
+    }
+
+    fn quote(&self) -> T {
+        self.rate
+    }
+
+    fn implied_quote(&self, curve: &YieldCurve<'a, T>) -> T {
+        forward_rate(curve, self.start, self.end, self.convention)
+    }
+}
+
+/// Forward rate agreement quoted as the simple forward rate over its accrual period.
+#[derive(Debug, Clone, Copy)]
+pub struct ForwardRateAgreement<'a, T> {
+    pub start: NaiveDate,
+    pub end: NaiveDate,
+    pub rate: T,
+    pub convention: DayCountConvention<'a>,
+}
+
+impl<'a, T> ForwardRateAgreement<'a, T> {
+    pub fn new(
+        start: NaiveDate,
+        end: NaiveDate,
+        rate: T,
+        convention: DayCountConvention<'a>,
+    ) -> Self {
+        Self {
+            start,
+            end,
+            rate,
+            convention,
+        }
+    }
+}
+
+impl<'a, T: Real> CurveInstrument<'a, T> for ForwardRateAgreement<'a, T> {
+    fn maturity_date(&self) -> NaiveDate {
+        self.end
+    }
+
+    fn quote(&self) -> T {
+        self.rate
+    }
+
+    fn implied_quote(&self, curve: &YieldCurve<'a, T>) -> T {
+        forward_rate(curve, self.start, self.end, self.convention)
+    }
+}
+
+/// Short-rate future quoted as `100 * (1 - futures rate)`. The futures rate is the
+/// forward rate plus `convexity_adjustment`.
+#[derive(Debug, Clone, Copy)]
+pub struct RateFuture<'a, T> {
+    pub start: NaiveDate,
+    pub end: NaiveDate,
+    pub price: T,
+    pub convexity_adjustment: T,
+    pub convention: DayCountConvention<'a>,
+}
+
+impl<'a, T: Real> RateFuture<'a, T> {
+    pub fn new(
+        start: NaiveDate,
+        end: NaiveDate,
+        price: T,
+        convention: DayCountConvention<'a>,
+    ) -> Self {
+        Self {
+            start,
+            end,
+            price,
+            convexity_adjustment: T::zero(),
+            convention,
+        }
+    }
+
+    pub fn with_convexity_adjustment(mut self, convexity_adjustment: T) -> Self {
+        self.convexity_adjustment = convexity_adjustment;
+        self
+    }
+}
+
+impl<'a, T: Real> CurveInstrument<'a, T> for RateFuture<'a, T> {
+    fn maturity_date(&self) -> NaiveDate {
+        self.end
+    }
+
+    fn quote(&self) -> T {
+        self.price
+    }
+
+    fn implied_quote(&self, curve: &YieldCurve<'a, T>) -> T {
+        let forward = forward_rate(curve, self.start, self.end, self.convention);
+        T::from_f64(100.0) * (T::one() - forward - self.convexity_adjustment)
+    }
+}
+
+/// Fixed-for-floating swap quoted at its par fixed rate.
+///
+/// On a single curve the floating leg is worth `DF(start) - DF(maturity)`. When the
+/// curve is a projection curve, the floating leg is projected on it over its own
+/// schedule and both legs are discounted on the discount curve.
+#[derive(Debug, Clone)]
+pub struct ParSwap<'a, T> {
+    pub start: NaiveDate,
+    pub maturity: NaiveDate,
+    pub rate: T,
+    pub fixed_tenor: Tenor,
+    pub fixed_convention: DayCountConvention<'a>,
+    pub float_tenor: Tenor,
+    pub float_convention: DayCountConvention<'a>,
+    payment_dates: Vec<NaiveDate>,
+    float_payment_dates: Vec<NaiveDate>,
+}
+
+impl<'a, T> ParSwap<'a, T> {
+    /// Fixed payments roll forward from `start` every `fixed_tenor`, with a short
+    /// final stub if the tenor does not divide the swap. The floating leg follows the
+    /// fixed schedule until `with_floating_leg` says otherwise.
+    pub fn new(
+        start: NaiveDate,
+        maturity: NaiveDate,
+        rate: T,
+        fixed_tenor: Tenor,
+        fixed_convention: DayCountConvention<'a>,
+    ) -> Self {
+        let payment_dates = fixed_tenor.roll(start, maturity);
+
+        Self {
+            start,
+            maturity,
+            rate,
+            fixed_tenor,
+            fixed_convention,
+            float_tenor: fixed_tenor,
+            float_convention: fixed_convention,
+            float_payment_dates: payment_dates.clone(),
+            payment_dates,
+        }
+    }
+
+    pub fn with_floating_leg(mut self, tenor: Tenor, convention: DayCountConvention<'a>) -> Self {
+        self.float_tenor = tenor;
+        self.float_convention = convention;
+        self.float_payment_dates = tenor.roll(self.start, self.maturity);
+        self
+    }
+
+    pub fn payment_dates(&self) -> &[NaiveDate] {
+        &self.payment_dates
+    }
+
+    pub fn float_payment_dates(&self) -> &[NaiveDate] {
+        &self.float_payment_dates
+    }
+}
+
+impl<'a, T: Real> CurveInstrument<'a, T> for ParSwap<'a, T> {
+    fn maturity_date(&self) -> NaiveDate {
+        self.maturity
+    }
+
+    fn quote(&self) -> T {
+        self.rate
+    }
+
+    fn implied_quote(&self, curve: &YieldCurve<'a, T>) -> T {
+        let mut annuity = T::zero();
+        let mut accrual_start = self.start;
+        for &date in &self.payment_dates {
+            let tau = curve.year_fraction(accrual_start, date, self.fixed_convention);
+            annuity += tau * curve.discounting_factor_at(date);
+            accrual_start = date;
+        }
+
+        if !curve.is_projection() {
+            let floating =
+                curve.discount_factor_at(self.start) - curve.discount_factor_at(self.maturity);
+            return floating / annuity;
+        }
+
+        let mut floating = T::zero();
+        let mut accrual_start = self.start;
+        for &date in &self.float_payment_dates {
+            let tau = curve.year_fraction(accrual_start, date, self.float_convention);
+            let forward = forward_rate(curve, accrual_start, date, self.float_convention);
+            floating += tau * forward * curve.discounting_factor_at(date);
+            accrual_start = date;
+        }
+        floating / annuity
+    }
+}
+
+/// Discount curve bootstrapped from market instruments.
+///
+/// Curve time is the year fraction from `reference_date` under `day_count`. Pillars
+/// sit at the instrument maturities and are solved one at a time, shortest first,
+/// so that each instrument only sees pillars that are already fixed plus its own.
+/// Schemes where a node moves the curve on earlier intervals (splines, monotone
+/// schemes) are swept again until every instrument reprices.
+///
+/// A projection curve is bootstrapped against a separate discount curve, which must
+/// share its reference date and day count; instruments then project forwards on this
+/// curve and discount their cash flows on the other.
+#[derive(Clone)]
+pub struct YieldCurve<'a, T: Real> {
+    reference_date: NaiveDate,
+    discounting: Option<&'a dyn RateCurve<T>>,
+    day_count: DayCountConvention<'a>,
+    calculator: &'a dyn PeriodCalculator<'a>,
+    interpolation: CurveInterpolation,
+    pillars: Vec<NaiveDate>,
+    times: Vec<T>,
+    values: Vec<T>,
+    nodes: CurveNodes<T>,
+}
+
+impl<'a, T: Real> YieldCurve<'a, T> {
+    /// Accuracy on each instrument's quote, relative to `1 + |quote|`.
+    pub const ACCURACY: f64 = 1e-12;
+    const MAX_PASSES: usize = 50;
+
+    pub fn bootstrap(
+        reference_date: NaiveDate,
+        instruments: &[&dyn CurveInstrument<'a, T>],
+        interpolation: CurveInterpolation,
+        day_count: DayCountConvention<'a>,
+        calculator: &'a dyn PeriodCalculator<'a>,
+    ) -> Result<Self, CurveError> {
+        Self::build(
+            reference_date,
+            instruments,
+            None,
+            interpolation,
+            day_count,
+            calculator,
+        )
+    }
+
+    /// Bootstraps a projection curve for one index, discounting on `discount_curve`.
+    ///
+    /// The discount curve is read in this curve's time, so a dated discount curve must
+    /// share `reference_date` and give the same curve time at every maturity.
+    pub fn bootstrap_projection(
+        reference_date: NaiveDate,
+        instruments: &[&dyn CurveInstrument<'a, T>],
+        discount_curve: &'a dyn RateCurve<T>,
+        interpolation: CurveInterpolation,
+        day_count: DayCountConvention<'a>,
+        calculator: &'a dyn PeriodCalculator<'a>,
+    ) -> Result<Self, CurveError> {
+        check_reference_date(&discount_curve, reference_date)?;
+        for instrument in instruments {
+            let maturity = instrument.maturity_date();
+            let time = T::from_f64(
+                calculator
+                    .year_fraction(reference_date, maturity, day_count)
+                    .0,
+            );
+            if let Some(discount_time) = discount_curve.curve_time(maturity)
+                && (discount_time - time).abs() > T::from_f64(1e-12)
+            {
+                return Err(CurveError::DayCountMismatch(maturity));
+            }
+        }
+
+        Self::build(
+            reference_date,
+            instruments,
+            Some(discount_curve),
+            interpolation,
+            day_count,
+            calculator,
+        )
+    }
+
+    fn build(
+        reference_date: NaiveDate,
+        instruments: &[&dyn CurveInstrument<'a, T>],
+        discounting: Option<&'a dyn RateCurve<T>>,
+        interpolation: CurveInterpolation,
+        day_count: DayCountConvention<'a>,
+        calculator: &'a dyn PeriodCalculator<'a>,
+    ) -> Result<Self, CurveError> {
+        if instruments.is_empty() {
+            return Err(CurveError::NoInstruments);
+        }
+
+        let mut ordered: Vec<&dyn CurveInstrument<'a, T>> = instruments.to_vec();
+        ordered.sort_by_key(|instrument| instrument.maturity_date());
+
+        for pair in ordered.windows(2) {
+            if pair[0].maturity_date() == pair[1].maturity_date() {
+                return Err(CurveError::DuplicatePillar(pair[1].maturity_date()));
+            }
+        }
+        if ordered[0].maturity_date() <= reference_date {
+            return Err(CurveError::InvalidPillar(ordered[0].maturity_date()));
+        }
+
+        // Node at t = 0 keeps every interpolator well posed from the first pillar on
+        let origin = match interpolation.variable {
+            InterpolationVariable::DiscountFactor => T::one(),
+            _ => T::zero(),
+        };
+
+        let mut curve = Self {
+            reference_date,
+            discounting,
+            day_count,
+            calculator,
+            interpolation,
+            pillars: vec![reference_date],
+            times: vec![T::zero()],
+            values: vec![origin],
+            nodes: CurveNodes::new(
+                interpolation,
+                vec![T::zero(), T::one()],
+                vec![origin, origin],
+            )?,
+        };
+
+        for (k, instrument) in ordered.iter().enumerate() {
+            let pillar = instrument.maturity_date();
+            let t = curve.time(pillar);
+
+            // Start from the previous pillar's zero rate
+            let guess_rate = curve.zero_rate(t);
+            curve.pillars.push(pillar);
+            curve.times.push(t);
+            curve.values.push(curve.encode(guess_rate, t));
+
+            curve.solve_pillar(k + 1, *instrument)?;
+        }
+
+        for _ in 0..Self::MAX_PASSES {
+            if curve.reprices(&ordered) {
+                return Ok(curve);
+            }
+            for (k, instrument) in ordered.iter().enumerate() {
+                curve.solve_pillar(k + 1, *instrument)?;
+            }
+        }
+
+        if curve.reprices(&ordered) {
+            Ok(curve)
+        } else {
+            Err(CurveError::BootstrapFailed(
+                curve.pillars[curve.pillars.len() - 1],
+            ))
+        }
+    }
+
+    pub fn reference_date(&self) -> NaiveDate {
+        self.reference_date
+    }
+
+    pub fn interpolation(&self) -> CurveInterpolation {
+        self.interpolation
+    }
+
+    /// Pillar dates, starting with the reference date.
+    pub fn pillars(&self) -> &[NaiveDate] {
+        &self.pillars
+    }
+
+    /// Pillar times, starting with zero.
+    pub fn times(&self) -> &[T] {
+        &self.times
+    }
+
+    /// Year fraction from the reference date on the curve's own day count.
+    pub fn time(&self, date: NaiveDate) -> T {
+        self.year_fraction(self.reference_date, date, self.day_count)
+    }
+
+    pub fn year_fraction(
+        &self,
+        start: NaiveDate,
+        end: NaiveDate,
+        convention: DayCountConvention<'a>,
+    ) -> T {
+        T::from_f64(self.calculator.year_fraction(start, end, convention).0)
+    }
+
+    pub fn discount_factor_at(&self, date: NaiveDate) -> T {
+        self.discount_factor(self.time(date))
+    }
+
+    /// Zero rate to `date` quoted with the given conventions, whatever the curve's own.
+    pub fn zero_rate_at(
+        &self,
+        date: NaiveDate,
+        convention: DayCountConvention<'a>,
+        compounding: Compounding,
+        frequency: Frequency,
+    ) -> InterestRate<'a, T> {
+        let t = self.year_fraction(self.reference_date, date, convention);
+        InterestRate::implied_rate(
+            self.discount_factor_at(date),
+            t,
+            compounding,
+            frequency,
+            convention,
+        )
+    }
+
+    /// Whether this curve projects forwards for another discount curve.
+    pub fn is_projection(&self) -> bool {
+        self.discounting.is_some()
+    }
+
+    /// Discount factor for present-valuing cash flows: the discount curve's for a
+    /// projection curve, otherwise this curve's own.
+    pub fn discounting_factor_at(&self, date: NaiveDate) -> T {
+        match self.discounting {
+            Some(curve) => curve.discount_factor(self.time(date)),
+            None => self.discount_factor_at(date),
+        }
+    }
+
+    /// Node value representing a continuously compounded zero rate `r` at time `t`.
+    fn encode(&self, r: T, t: T) -> T {
+        match self.interpolation.variable {
+            InterpolationVariable::ZeroRate => r,
+            InterpolationVariable::DiscountFactor => (-r * t).exp(),
+            InterpolationVariable::LogDiscount => -r * t,
+            InterpolationVariable::InstantaneousForward => r,
+        }
+    }
+
+    fn rebuild(&mut self) -> Result<(), CurveError> {
+        self.nodes = CurveNodes::new(self.interpolation, self.times.clone(), self.values.clone())?;
+        Ok(())
+    }
+
+    fn tolerance(quote: T) -> T {
+        T::from_f64(Self::ACCURACY) * (T::one() + quote.abs())
+    }
+
+    fn reprices(&self, instruments: &[&dyn CurveInstrument<'a, T>]) -> bool {
+        instruments.iter().all(|instrument| {
+            let quote = instrument.quote();
+            (instrument.implied_quote(self) - quote).abs() <= Self::tolerance(quote)
+        })
+    }
+
+    /// Moves the node at `index` until the instrument reprices.
+    fn solve_pillar(
+        &mut self,
+        index: usize,
+        instrument: &dyn CurveInstrument<'a, T>,
+    ) -> Result<(), CurveError> {
+        let quote = instrument.quote();
+        let guess = self.values[index];
+
+        let solved = solve_node(guess, Self::tolerance(quote), |x| {
+            self.values[index] = x;
+            self.rebuild()?;
+            Ok::<T, CurveError>(instrument.implied_quote(self) - quote)
+        })?;
+
+        match solved {
+            Some(_) => Ok(()),
+            None => Err(CurveError::BootstrapFailed(instrument.maturity_date())),
+        }
+    }
+}
+
+impl<'a, T: Real> RateCurve<T> for YieldCurve<'a, T> {
+    fn zero_rate(&self, t: T) -> T {
+        self.nodes.zero_rate(t)
+    }
+
+    fn discount_factor(&self, t: T) -> T {
+        self.nodes.discount_factor(t)
+    }
+
+    fn instantaneous_forward(&self, t: T) -> T {
+        self.nodes.instantaneous_forward(t)
+    }
+
+    fn reference_date(&self) -> Option<NaiveDate> {
+        Some(self.reference_date)
+    }
+
+    fn curve_time(&self, date: NaiveDate) -> Option<T> {
+        Some(self.time(date))
+    }
+}
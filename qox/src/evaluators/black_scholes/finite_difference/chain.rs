@@ -0,0 +1,220 @@
+use std::collections::HashMap;
+
+use chrono::{DateTime, Utc};
+use rayon::prelude::*;
+
+use crate::{
+    core::period::DayCountConvention,
+    evaluators::black_scholes::finite_difference::VanillaPayoff,
+    instruments::{
+        OptionInstrument, OptionType,
+        stock_option::{ExerciseStyle, StockOption},
+    },
+    methods::{
+        complementarity::policy_iteration::PolicyIteration,
+        constraints::american::AmericanConstraint,
+        finite_difference::{
+            meshers::uniform::UniformMesher1d,
+            solver::{FdmConfig, Solver},
+        },
+        linear_operators::tridiagonal_operator::TridiagonalOperator,
+        step_policy::{
+            american_policy::AmericanPolicy, linear_policy::LinearPolicy,
+            unified_policy::UnifiedPolicy,
+        },
+        time_stepping::{
+            butcher_jackiewicz2::ButcherJackiewicz2, glm::GlmWorkspace, input_vectors::InputVector,
+        },
+        transforms::log::LogTransform,
+    },
+    processes::{
+        FdmProcess,
+        black_scholes::{BlackScholesProcess, rate_time_volatility},
+    },
+    traits::{
+        market_view::OptionMarketView, payoff::PayoffAsInitialConditions, rate_curve::RateCurve,
+        vol_surface::VolSurface,
+    },
+    types::Real,
+};
+
+/// Number of stages of the `ButcherJackiewicz2` stepper used for every solve.
+const STAGES: usize = 2;
+
+/// Options that can be valued from a single backward solve.
+///
+/// Vanilla payoffs are homogeneous in (spot, strike), so every option in a group is
+/// priced from one unit-strike solve in log-moneyness: `V(S, K) = K * v(S / K)`.
+struct ChainGroup<T> {
+    option_type: OptionType,
+    exercise_style: ExerciseStyle,
+    years_to_expiry: T,
+    rate: T,
+    vol: T,
+    members: Vec<usize>,
+}
+
+/// Prices a whole chain of `StockOption`s against one market in parallel.
+pub struct ChainEvaluator {
+    pub config: FdmConfig,
+}
+
+impl ChainEvaluator {
+    pub fn new(config: FdmConfig) -> Self {
+        Self { config }
+    }
+
+    /// Returns one price per option, in the same order as `options`.
+    ///
+    /// # Panics
+    ///
+    /// If the market's rate curve does not start on its evaluation date, see
+    /// `MarketView::check_reference_date`.
+    pub fn evaluate<T, RC, VS, M>(&self, options: &[StockOption], market: &M) -> Vec<T>
+    where
+        T: Real + Send + Sync,
+        RC: RateCurve<T>,
+        VS: VolSurface<T>,
+        M: OptionMarketView<T, RC, VS>,
+    {
+        if let Err(error) = market.check_reference_date() {
+            panic!("cannot price against this market: {error}");
+        }
+        if options.is_empty() {
+            return Vec::new();
+        }
+
+        let spot = market.spot_price();
+        let groups = self.group_options(options, market);
+
+        // One log-moneyness mesh wide enough for every strike in the chain, so each
+        // worker builds it once and reuses it for all the groups it picks up.
+        let (k_min, k_max) = options
+            .iter()
+            .fold((f64::INFINITY, f64::NEG_INFINITY), |(lo, hi), o| {
+                (lo.min(o.strike), hi.max(o.strike))
+            });
+        let x_min = (T::from_f64(0.01) / T::from_f64(k_max)).ln();
+        let x_max = (spot * T::from_f64(5.0) / T::from_f64(k_min)).ln();
+        let nodes = self.config.nodes;
+
+        let priced: Vec<Vec<(usize, T)>> = groups
+            .par_iter()
+            .map_init(
+                || {
+                    (
+                        GlmWorkspace::<T>::new(STAGES, nodes),
+                        UniformMesher1d::new(x_min, x_max, nodes, LogTransform::new()),
+                    )
+                },
+                |(workspace, mesher), group| {
+                    self.solve_group(group, options, spot, mesher, workspace)
+                },
+            )
+            .collect();
+
+        let mut prices = vec![T::zero(); options.len()];
+        for (index, price) in priced.into_iter().flatten() {
+            prices[index] = price;
+        }
+        prices
+    }
+
+    fn group_options<T, RC, VS, M>(&self, options: &[StockOption], market: &M) -> Vec<ChainGroup<T>>
+    where
+        T: Real,
+        RC: RateCurve<T>,
+        VS: VolSurface<T>,
+        M: OptionMarketView<T, RC, VS>,
+    {
+        let mut keyed: HashMap<(DateTime<Utc>, OptionType, ExerciseStyle), Vec<usize>> =
+            HashMap::new();
+        for (i, option) in options.iter().enumerate() {
+            keyed
+                .entry((option.expiry, option.option_type, option.exercise_style))
+                .or_default()
+                .push(i);
+        }
+
+        keyed
+            .into_iter()
+            .map(|((_, option_type, exercise_style), members)| {
+                let years_to_expiry =
+                    <StockOption as OptionInstrument<T, VanillaPayoff>>::years_to_expiry(
+                        options[members[0]],
+                        market.evaluation_time(),
+                    );
+                let expiry = options[members[0]].expiry;
+                let vol = market.vol_surface().volatility(0.0, T::zero());
+
+                ChainGroup {
+                    option_type,
+                    exercise_style,
+                    years_to_expiry,
+                    rate: market.rate_curve().zero_rate(years_to_expiry),
+                    vol: rate_time_volatility(
+                        vol,
+                        market.variance_time(expiry),
+                        years_to_expiry,
+                    ),
+                    members,
+                }
+            })
+            .collect()
+    }
+
+    fn solve_group<T: Real>(
+        &self,
+        group: &ChainGroup<T>,
+        options: &[StockOption],
+        spot: T,
+        mesher: &UniformMesher1d<T, LogTransform<T>>,
+        workspace: &mut GlmWorkspace<T>,
+    ) -> Vec<(usize, T)> {
+        let solver = Solver {
+            config: self.config,
+        };
+
+        let dt = group.years_to_expiry / T::from_f64(self.config.time_steps as f64);
+        let initial_conditions = PayoffAsInitialConditions::new(VanillaPayoff {
+            strike: 1.0,
+            option_type: group.option_type,
+        });
+
+        let process = BlackScholesProcess::new(
+            group.rate,
+            group.vol,
+            mesher.transform,
+            DayCountConvention::Actual365Fixed,
+        );
+        let operator: TridiagonalOperator<T> = process.build_operator(mesher);
+
+        let policy = match group.exercise_style {
+            ExerciseStyle::European => UnifiedPolicy::Linear(LinearPolicy::new(&operator)),
+            ExerciseStyle::American => UnifiedPolicy::American(AmericanPolicy::new(
+                &operator,
+                PolicyIteration::new(1e-12, 100),
+                AmericanConstraint::new(initial_conditions),
+            )),
+        };
+
+        let vector = solver.solve_with_workspace(
+            ButcherJackiewicz2::new(),
+            initial_conditions,
+            mesher,
+            dt,
+            &policy,
+            workspace,
+        );
+
+        group
+            .members
+            .iter()
+            .map(|&i| {
+                let strike = T::from_f64(options[i].strike);
+                let value = solver.interpolate(mesher, vector.step_slice(0), spot / strike);
+                (i, strike * value)
+            })
+            .collect()
+    }
+}
@@ -1,3 +1,4 @@
+pub mod chain;
 pub mod evaluator;
 pub mod evaluator_kernel;
 
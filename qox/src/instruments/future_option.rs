@@ -1,8 +1,8 @@
 use crate::{
-    core::period::{DayCountConvention, DefaultPeriodCalculator, PeriodCalculator},
+    core::clock::ActualClock,
     instruments::{Instrument, OptionInstrument, OptionType},
     traits::{
-        market_view::OptionMarketView, payoff::Payoff, rate_curve::RateCurve,
+        clock::Clock, market_view::OptionMarketView, payoff::Payoff, rate_curve::RateCurve,
         vol_surface::VolSurface,
     },
     types::Real,
@@ -39,14 +39,8 @@ impl<T: Real, P: Payoff<T> + Copy> OptionInstrument<T, P> for FutureOption<P> {
         self.option_type
     }
 
-    fn years_to_expiry(self) -> T {
-        let now = Utc::now().date_naive();
-        let expiry_date = self.expiry.date_naive();
-
-        let calculator = DefaultPeriodCalculator;
-        let years = calculator.year_fraction(now, expiry_date, DayCountConvention::Actual365Fixed);
-
-        Real::from_f64(years.0)
+    fn years_to_expiry(self, evaluation_time: DateTime<Utc>) -> T {
+        Real::from_f64(ActualClock.year_fraction(evaluation_time, self.expiry))
     }
 
     fn evaluate<M, RC, VS>(self, _market_frame: &M) -> T
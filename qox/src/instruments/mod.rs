@@ -1,6 +1,9 @@
 pub mod cash;
 pub mod future_option;
 pub mod stock_option;
+pub mod swap;
+
+use chrono::{DateTime, Utc};
 
 use crate::{
     traits::{
@@ -12,7 +15,7 @@ use crate::{
 
 pub trait Instrument {}
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum OptionType {
     Call,
     Put,
@@ -21,7 +24,8 @@ pub enum OptionType {
 pub trait OptionInstrument<T: Real, P: Payoff<T> + Copy>: Instrument {
     fn strike(self) -> f64;
     fn option_type(self) -> OptionType;
-    fn years_to_expiry(self) -> T;
+    /// Act/365F year fraction from `evaluation_time` to expiry, to the millisecond.
+    fn years_to_expiry(self, evaluation_time: DateTime<Utc>) -> T;
 
     fn get_payoff(self) -> P;
 
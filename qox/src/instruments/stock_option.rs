@@ -1,24 +1,29 @@
 use crate::instruments::{Instrument, OptionInstrument, OptionType};
+use crate::methods::complementarity::policy_iteration::PolicyIteration;
+use crate::methods::constraints::american::AmericanConstraint;
 use crate::methods::finite_difference::meshers::uniform::UniformMesher1d;
 use crate::methods::finite_difference::solver::{FdmConfig, Solver};
+use crate::methods::linear_operators::tridiagonal_operator::TridiagonalOperator;
+use crate::methods::step_policy::american_policy::{AmericanPolicy, ExerciseBoundary};
 use crate::methods::step_policy::linear_policy::LinearPolicy;
 use crate::methods::step_policy::unified_policy::UnifiedPolicy;
 use crate::methods::time_stepping::butcher_jackiewicz2::ButcherJackiewicz2;
 use crate::methods::time_stepping::input_vectors::InputVector;
 use crate::methods::transforms::log::LogTransform;
 use crate::processes::FdmProcess;
-use crate::processes::black_scholes::BlackScholesProcess;
+use crate::processes::black_scholes::{BlackScholesProcess, rate_time_volatility};
+use crate::traits::clock::Clock;
 use crate::traits::rate_curve::RateCurve;
 use crate::traits::vol_surface::VolSurface;
 use crate::types::Real;
 use crate::{
-    core::period::{DayCountConvention, DefaultPeriodCalculator, PeriodCalculator},
+    core::{clock::ActualClock, period::DayCountConvention},
     evaluators::black_scholes::finite_difference::VanillaPayoff,
     traits::{market_view::OptionMarketView, payoff::PayoffAsInitialConditions},
 };
 use chrono::{DateTime, Utc};
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum ExerciseStyle {
     American,
     European,
@@ -48,35 +53,28 @@ impl StockOption {
     }
 }
 
-impl Instrument for StockOption {}
-
-impl<T: Real> OptionInstrument<T, VanillaPayoff> for StockOption {
-    fn strike(self) -> f64 {
-        self.strike
-    }
-
-    fn option_type(self) -> OptionType {
-        self.option_type
-    }
-
-    fn years_to_expiry(self) -> T {
-        let now = Utc::now().date_naive();
-
-        let expiry_date = self.expiry.date_naive();
-
-        let calculator = DefaultPeriodCalculator;
-        let years = calculator.year_fraction(now, expiry_date, DayCountConvention::Actual365Fixed);
-
-        Real::from_f64(years.0)
-    }
-
-    fn evaluate<M, RC, VS>(self, market_frame: &M) -> T
+impl StockOption {
+    /// Prices the option by finite differences. For American exercise the
+    /// early-exercise boundary recorded during the solve is returned alongside.
+    ///
+    /// # Panics
+    ///
+    /// If the market's rate curve does not start on its evaluation date, see
+    /// `MarketView::check_reference_date`.
+    pub fn evaluate_with_exercise_boundary<T, M, RC, VS>(
+        self,
+        market_frame: &M,
+    ) -> (T, Option<ExerciseBoundary<T>>)
     where
         T: Real,
         RC: RateCurve<T>,
         VS: VolSurface<T>,
         M: OptionMarketView<T, RC, VS>,
     {
+        if let Err(error) = market_frame.check_reference_date() {
+            panic!("cannot price against this market: {error}");
+        }
+
         let solver = Solver {
             config: FdmConfig {
                 nodes: 1000,
@@ -84,11 +82,19 @@ impl<T: Real> OptionInstrument<T, VanillaPayoff> for StockOption {
             },
         };
 
-        let rate = market_frame.rate_curve().zero_rate(self.years_to_expiry());
-        let vol = market_frame.vol_surface().volatility(0.0, T::zero());
+        let years_to_expiry = <StockOption as OptionInstrument<T, VanillaPayoff>>::years_to_expiry(
+            self,
+            market_frame.evaluation_time(),
+        );
+        let variance_time = market_frame.variance_time(self.expiry);
+        let rate = market_frame.rate_curve().zero_rate(years_to_expiry);
+        let vol = rate_time_volatility(
+            market_frame.vol_surface().volatility(0.0, T::zero()),
+            variance_time,
+            years_to_expiry,
+        );
 
-        let dt = <StockOption as OptionInstrument<T, VanillaPayoff>>::years_to_expiry(self)
-            / T::from_f64(solver.config.time_steps as f64);
+        let dt = years_to_expiry / T::from_f64(solver.config.time_steps as f64);
         let initial_conditions = PayoffAsInitialConditions::new(
             <StockOption as OptionInstrument<T, VanillaPayoff>>::get_payoff(self),
         );
@@ -101,15 +107,15 @@ impl<T: Real> OptionInstrument<T, VanillaPayoff> for StockOption {
             BlackScholesProcess::new(rate, vol, transform, DayCountConvention::Actual365Fixed);
         let stepper = ButcherJackiewicz2::new();
 
-        // let obstacle_policy = PostProjectionPolicy {
-        //     constraint: AmericanConstraint::new(initial_conditions),
-        // };
-
-        let operator = process.build_operator(&mesher);
+        let operator: TridiagonalOperator<T> = process.build_operator(&mesher);
 
         let policy = match self.exercise_style {
             ExerciseStyle::European => UnifiedPolicy::Linear(LinearPolicy::new(&operator)),
-            ExerciseStyle::American => todo!(),
+            ExerciseStyle::American => UnifiedPolicy::American(AmericanPolicy::new(
+                &operator,
+                PolicyIteration::new(1e-12, 100),
+                AmericanConstraint::new(initial_conditions),
+            )),
         };
 
         let vector = solver.solve(
@@ -117,19 +123,45 @@ impl<T: Real> OptionInstrument<T, VanillaPayoff> for StockOption {
             initial_conditions,
             &mesher,
             dt,
-            solver.config,
-            //market_frame.spot_price(),
             &policy,
-            //obstacle_policy,
         );
 
-        solver.interpolate(&mesher, vector.step_slice(0), market_frame.spot_price())
+        let price = solver.interpolate(&mesher, vector.step_slice(0), market_frame.spot_price());
+        let boundary = match &policy {
+            UnifiedPolicy::Linear(_) => None,
+            UnifiedPolicy::American(p) => Some(p.exercise_boundary()),
+        };
+
+        (price, boundary)
+    }
+}
+
+impl Instrument for StockOption {}
 
-        // let scaled_time_deriv = vector.step_slice(1);
+impl<T: Real> OptionInstrument<T, VanillaPayoff> for StockOption {
+    fn strike(self) -> f64 {
+        self.strike
+    }
 
-        // let dv_dtau_scaled =
-        //     solver.interpolate(&mesher, scaled_time_deriv, market_frame.spot_price());
-        // -(dv_dtau_scaled)
+    fn option_type(self) -> OptionType {
+        self.option_type
+    }
+
+    fn years_to_expiry(self, evaluation_time: DateTime<Utc>) -> T {
+        Real::from_f64(ActualClock.year_fraction(evaluation_time, self.expiry))
+    }
+
+    /// # Panics
+    ///
+    /// If the market's rate curve does not start on its evaluation date.
+    fn evaluate<M, RC, VS>(self, market_frame: &M) -> T
+    where
+        T: Real,
+        RC: RateCurve<T>,
+        VS: VolSurface<T>,
+        M: OptionMarketView<T, RC, VS>,
+    {
+        self.evaluate_with_exercise_boundary(market_frame).0
     }
 
     fn get_payoff(self) -> VanillaPayoff {
@@ -0,0 +1,195 @@
+use chrono::NaiveDate;
+
+use crate::{
+    core::{error::CurveError, period::DayCountConvention, rate::RateIndex, tenor::Tenor},
+    instruments::Instrument,
+    traits::{market_view::RatesMarketView, rate_curve::RateCurve},
+    types::Real,
+};
+
+/// Which side of the fixed leg the holder is on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SwapSide {
+    /// Pays fixed, receives floating.
+    Payer,
+    /// Receives fixed, pays floating.
+    Receiver,
+}
+
+/// Fixed coupons accruing from `start` to each payment date in turn.
+#[derive(Debug, Clone)]
+pub struct FixedLeg<'a, T> {
+    pub start: NaiveDate,
+    pub payment_dates: Vec<NaiveDate>,
+    pub rate: T,
+    pub notional: T,
+    pub convention: DayCountConvention<'a>,
+}
+
+impl<'a, T: Real> FixedLeg<'a, T> {
+    /// Payments roll forward from `start` every `tenor`, with a short final stub.
+    pub fn new(
+        start: NaiveDate,
+        maturity: NaiveDate,
+        tenor: Tenor,
+        rate: T,
+        notional: T,
+        convention: DayCountConvention<'a>,
+    ) -> Self {
+        Self {
+            start,
+            payment_dates: tenor.roll(start, maturity),
+            rate,
+            notional,
+            convention,
+        }
+    }
+
+    /// Present value of one unit of fixed rate on this schedule. Payments on or before
+    /// the market's reference date are already paid and left out.
+    ///
+    /// Fails if the market's curves do not start on its reference date.
+    pub fn annuity<M, D, P>(&self, market: &M) -> Result<T, CurveError>
+    where
+        M: RatesMarketView<'a, T, D, P>,
+        D: RateCurve<T>,
+        P: RateCurve<T>,
+    {
+        market.check_reference_dates()?;
+        let discount = market.discount_curve();
+        let mut annuity = T::zero();
+        let mut accrual_start = self.start;
+        for &date in &self.payment_dates {
+            if date > market.reference_date() {
+                let tau = market.year_fraction(accrual_start, date, self.convention);
+                annuity += tau * discount.discount_factor(market.time(date));
+            }
+            accrual_start = date;
+        }
+        Ok(self.notional * annuity)
+    }
+
+    pub fn npv<M, D, P>(&self, market: &M) -> Result<T, CurveError>
+    where
+        M: RatesMarketView<'a, T, D, P>,
+        D: RateCurve<T>,
+        P: RateCurve<T>,
+    {
+        Ok(self.rate * self.annuity(market)?)
+    }
+}
+
+/// Floating coupons fixing on `index` plus `spread`, projected on the index's curve
+/// and discounted on the market's discount curve.
+#[derive(Debug, Clone)]
+pub struct FloatingLeg<'a, T> {
+    pub index: RateIndex<'a>,
+    pub start: NaiveDate,
+    pub payment_dates: Vec<NaiveDate>,
+    pub spread: T,
+    pub notional: T,
+}
+
+impl<'a, T: Real> FloatingLeg<'a, T> {
+    /// Payments roll forward from `start` every index tenor, with a short final stub.
+    pub fn new(index: RateIndex<'a>, start: NaiveDate, maturity: NaiveDate, notional: T) -> Self {
+        Self {
+            index,
+            start,
+            payment_dates: index.tenor.roll(start, maturity),
+            spread: T::zero(),
+            notional,
+        }
+    }
+
+    pub fn with_spread(mut self, spread: T) -> Self {
+        self.spread = spread;
+        self
+    }
+
+    /// Present value of the floating coupons still to be paid, or an error if the
+    /// market has no projection curve for the index. Coupons already accruing on the
+    /// market's reference date fixed in the past, which the market does not hold, so
+    /// they are an error too, as are curves that do not start on the reference date.
+    pub fn npv<M, D, P>(&self, market: &M) -> Result<T, CurveError>
+    where
+        M: RatesMarketView<'a, T, D, P>,
+        D: RateCurve<T>,
+        P: RateCurve<T>,
+    {
+        market.check_reference_dates()?;
+        let projection = market
+            .projection_curve(self.index.name)
+            .ok_or_else(|| CurveError::MissingProjection(self.index.name.to_string()))?;
+        let discount = market.discount_curve();
+
+        let mut npv = T::zero();
+        let mut accrual_start = self.start;
+        for &date in &self.payment_dates {
+            if date <= market.reference_date() {
+                accrual_start = date;
+                continue;
+            }
+            if accrual_start < market.reference_date() {
+                return Err(CurveError::MissingFixing(accrual_start));
+            }
+
+            let tau = market.year_fraction(accrual_start, date, self.index.convention);
+            let growth = projection.discount_factor(market.time(accrual_start))
+                / projection.discount_factor(market.time(date));
+            let forward = (growth - T::one()) / tau;
+
+            npv += (forward + self.spread) * tau * discount.discount_factor(market.time(date));
+            accrual_start = date;
+        }
+        Ok(self.notional * npv)
+    }
+}
+
+/// Fixed-for-floating interest rate swap.
+#[derive(Debug, Clone)]
+pub struct VanillaSwap<'a, T> {
+    pub side: SwapSide,
+    pub fixed_leg: FixedLeg<'a, T>,
+    pub floating_leg: FloatingLeg<'a, T>,
+}
+
+impl<'a, T> Instrument for VanillaSwap<'a, T> {}
+
+impl<'a, T: Real> VanillaSwap<'a, T> {
+    pub fn new(
+        side: SwapSide,
+        fixed_leg: FixedLeg<'a, T>,
+        floating_leg: FloatingLeg<'a, T>,
+    ) -> Self {
+        Self {
+            side,
+            fixed_leg,
+            floating_leg,
+        }
+    }
+
+    /// Value to the holder: floating minus fixed for a payer, the reverse for a receiver.
+    pub fn npv<M, D, P>(&self, market: &M) -> Result<T, CurveError>
+    where
+        M: RatesMarketView<'a, T, D, P>,
+        D: RateCurve<T>,
+        P: RateCurve<T>,
+    {
+        let payer = self.floating_leg.npv(market)? - self.fixed_leg.npv(market)?;
+        Ok(match self.side {
+            SwapSide::Payer => payer,
+            SwapSide::Receiver => -payer,
+        })
+    }
+
+    /// Fixed rate that makes the swap worth zero.
+    pub fn fair_rate<M, D, P>(&self, market: &M) -> Result<T, CurveError>
+    where
+        M: RatesMarketView<'a, T, D, P>,
+        D: RateCurve<T>,
+        P: RateCurve<T>,
+    {
+        Ok(self.floating_leg.npv(market)? / self.fixed_leg.annuity(market)?)
+    }
+}
@@ -1,3 +1,7 @@
+use chrono::{DateTime, NaiveDate, Utc};
+
+use crate::core::clock::ActualClock;
+use crate::traits::clock::Clock;
 use crate::traits::market_view::{MarketView, OptionMarketView};
 use crate::traits::rate_curve::RateCurve;
 use crate::traits::vol_surface::VolSurface;
@@ -9,6 +13,7 @@ where
     T: Real,
     RC: RateCurve<T>,
 {
+    pub evaluation_time: DateTime<Utc>,
     pub spot_price: T,
     pub rate_curve: RC,
 }
@@ -21,21 +26,29 @@ impl<T: Real, RC: RateCurve<T>> MarketView<T, RC> for MarketFrame<T, RC> {
     fn rate_curve(&self) -> &RC {
         &self.rate_curve
     }
+
+    fn evaluation_time(&self) -> DateTime<Utc> {
+        self.evaluation_time
+    }
 }
 
+/// Option market observed at `evaluation_time`. Volatility accrues on the
+/// `variance_clock`, calendar time by default.
 #[derive(Debug, Clone, Copy)]
-pub struct OptionMarketFrame<T, RC, VS>
+pub struct OptionMarketFrame<T, RC, VS, CK = ActualClock>
 where
     T: Real,
     RC: RateCurve<T>,
     VS: VolSurface<T>,
 {
+    pub evaluation_time: DateTime<Utc>,
     pub spot_price: T,
     pub rate_curve: RC,
     pub vol_surface: VS,
+    pub variance_clock: CK,
 }
 
-impl<T, RC, VS> MarketView<T, RC> for OptionMarketFrame<T, RC, VS>
+impl<T, RC, VS, CK> MarketView<T, RC> for OptionMarketFrame<T, RC, VS, CK>
 where
     T: Real,
     RC: RateCurve<T>,
@@ -48,17 +61,29 @@ where
     fn rate_curve(&self) -> &RC {
         &self.rate_curve
     }
+
+    fn evaluation_time(&self) -> DateTime<Utc> {
+        self.evaluation_time
+    }
 }
 
-impl<T, RC, VS> OptionMarketView<T, RC, VS> for OptionMarketFrame<T, RC, VS>
+impl<T, RC, VS, CK> OptionMarketView<T, RC, VS> for OptionMarketFrame<T, RC, VS, CK>
 where
     T: Real,
     RC: RateCurve<T>,
     VS: VolSurface<T>,
+    CK: Clock,
 {
     fn vol_surface(&self) -> &VS {
         &self.vol_surface
     }
+
+    fn variance_time(&self, expiry: DateTime<Utc>) -> T {
+        T::from_f64(
+            self.variance_clock
+                .year_fraction(self.evaluation_time, expiry),
+        )
+    }
 }
 
 impl<T, RC, VS> OptionMarketFrame<T, RC, VS>
@@ -67,11 +92,40 @@ where
     RC: RateCurve<T>,
     VS: VolSurface<T>,
 {
-    pub fn new(spot_price: T, rate_curve: RC, vol_surface: VS) -> Self {
+    /// Market observed at the start of `evaluation_date`, UTC. A dated rate curve keeps
+    /// its own reference date; see `MarketView::check_reference_date`.
+    pub fn new(evaluation_date: NaiveDate, spot_price: T, rate_curve: RC, vol_surface: VS) -> Self {
         Self {
+            evaluation_time: evaluation_date.and_time(Default::default()).and_utc(),
             spot_price: spot_price,
             rate_curve,
             vol_surface,
+            variance_clock: ActualClock,
+        }
+    }
+}
+
+impl<T, RC, VS, CK> OptionMarketFrame<T, RC, VS, CK>
+where
+    T: Real,
+    RC: RateCurve<T>,
+    VS: VolSurface<T>,
+{
+    pub fn with_evaluation_time(mut self, evaluation_time: DateTime<Utc>) -> Self {
+        self.evaluation_time = evaluation_time;
+        self
+    }
+
+    pub fn with_variance_clock<C: Clock>(
+        self,
+        variance_clock: C,
+    ) -> OptionMarketFrame<T, RC, VS, C> {
+        OptionMarketFrame {
+            evaluation_time: self.evaluation_time,
+            spot_price: self.spot_price,
+            rate_curve: self.rate_curve,
+            vol_surface: self.vol_surface,
+            variance_clock,
         }
     }
 }
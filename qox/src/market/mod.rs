@@ -1,3 +1,5 @@
 pub mod market_frame;
+pub mod multi_curve;
 pub mod rate_curve;
+pub mod spreaded_curve;
 pub mod vol_surface;
\ No newline at end of file
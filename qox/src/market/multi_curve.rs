@@ -0,0 +1,102 @@
+use std::collections::HashMap;
+use std::marker::PhantomData;
+
+use chrono::NaiveDate;
+
+use crate::core::error::CurveError;
+use crate::core::period::{DayCountConvention, PeriodCalculator};
+use crate::traits::market_view::{RatesMarketView, check_reference_date};
+use crate::traits::rate_curve::RateCurve;
+use crate::types::Real;
+
+/// Discount curve plus projection curves keyed by index name.
+///
+/// Every curve is read in the same time: year fractions from `reference_date` under
+/// `day_count`.
+#[derive(Clone)]
+pub struct MultiCurveFrame<'a, T, D, P>
+where
+    T: Real,
+    D: RateCurve<T>,
+    P: RateCurve<T>,
+{
+    pub reference_date: NaiveDate,
+    pub day_count: DayCountConvention<'a>,
+    pub discount_curve: D,
+    projection_curves: HashMap<String, P>,
+    calculator: &'a dyn PeriodCalculator<'a>,
+    _marker: PhantomData<T>,
+}
+
+impl<'a, T, D, P> MultiCurveFrame<'a, T, D, P>
+where
+    T: Real,
+    D: RateCurve<T>,
+    P: RateCurve<T>,
+{
+    pub fn new(
+        reference_date: NaiveDate,
+        day_count: DayCountConvention<'a>,
+        calculator: &'a dyn PeriodCalculator<'a>,
+        discount_curve: D,
+    ) -> Self {
+        Self {
+            reference_date,
+            day_count,
+            discount_curve,
+            projection_curves: HashMap::new(),
+            calculator,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Adds or replaces the projection curve for `index`.
+    pub fn with_projection(mut self, index: &str, curve: P) -> Self {
+        self.projection_curves.insert(index.to_string(), curve);
+        self
+    }
+
+    pub fn indices(&self) -> impl Iterator<Item = &str> {
+        self.projection_curves.keys().map(String::as_str)
+    }
+}
+
+impl<'a, T, D, P> RatesMarketView<'a, T, D, P> for MultiCurveFrame<'a, T, D, P>
+where
+    T: Real,
+    D: RateCurve<T>,
+    P: RateCurve<T>,
+{
+    fn reference_date(&self) -> NaiveDate {
+        self.reference_date
+    }
+
+    fn discount_curve(&self) -> &D {
+        &self.discount_curve
+    }
+
+    fn projection_curve(&self, index: &str) -> Option<&P> {
+        self.projection_curves.get(index)
+    }
+
+    fn time(&self, date: NaiveDate) -> T {
+        self.year_fraction(self.reference_date, date, self.day_count)
+    }
+
+    fn year_fraction(
+        &self,
+        start: NaiveDate,
+        end: NaiveDate,
+        convention: DayCountConvention<'a>,
+    ) -> T {
+        T::from_f64(self.calculator.year_fraction(start, end, convention).0)
+    }
+
+    /// Projection curves included.
+    fn check_reference_dates(&self) -> Result<(), CurveError> {
+        check_reference_date(&self.discount_curve, self.reference_date)?;
+        self.projection_curves
+            .values()
+            .try_for_each(|curve| check_reference_date(curve, self.reference_date))
+    }
+}
@@ -1,12 +1,14 @@
+use crate::core::curve_interpolation::{
+    CurveInterpolation, CurveNodes, InterpolationVariable, solve_node,
+};
 use crate::core::error::CurveError;
 use crate::core::period::{DayCountConvention, PeriodCalculator};
 use crate::core::rate::Discountable;
 use crate::core::rate::{Compounding, Frequency, InterestRate};
 use crate::core::tenor::Tenor;
-use crate::math::interpolate::Interpolator1D;
-use crate::math::interpolate::LinearInterpolator;
 use crate::traits::rate_curve::RateCurve;
 use crate::types::Real;
+use crate::types::dual_array::DualArray;
 use chrono::NaiveDate;
 
 #[derive(Debug, Clone)]
@@ -32,69 +34,280 @@ impl<'a, T: Real> RateCurve<T> for FlatRateCurve<'a, T> {
 
 impl<'a, T: Real> RateCurve<T> for InterpolatedRateCurve<'a, T> {
     fn zero_rate(&self, t: T) -> T {
-        self.interpolator.interpolate(t)
+        self.nodes.zero_rate(t)
     }
 
     fn discount_factor(&self, t: T) -> T {
-        let r = self.zero_rate(t);
-
-        // 2. Wrap the interpolated rate in an InterestRate object
-        let rate = InterestRate {
-            value: r,
-            convention: self.rates[0].convention,
-            compounding: self.rates[0].compounding,
-            frequency: self.rates[0].frequency,
-        };
+        self.nodes.discount_factor(t)
+    }
+
+    fn reference_date(&self) -> Option<NaiveDate> {
+        Some(self.reference_date)
+    }
 
-        // 3. Return the calculated discount factor
-        rate.discount_factor(t)
+    fn curve_time(&self, date: NaiveDate) -> Option<T> {
+        Some(self.time(date))
     }
 }
 
-#[derive(Debug, Clone)]
+/// Curve through quoted rates at fixed tenors from `reference_date`.
+///
+/// Every quote is normalized on construction to a continuously compounded zero rate
+/// on Act/365F: its discount factor is taken under its own day count and compounding,
+/// and curve time is Act/365F. `zero_rate` returns that internal rate; use
+/// `zero_rate_at` to restate it under other conventions.
+#[derive(Clone)]
 pub struct InterpolatedRateCurve<'a, T: Real> {
-    #[allow(dead_code)]
     reference_date: NaiveDate,
-    #[allow(dead_code)]
     tenors: Vec<Tenor>,
     rates: Vec<InterestRate<'a, T>>,
-    interpolator: LinearInterpolator<T>,
+    times: Vec<T>,
+    log_discounts: Vec<T>,
+    interpolation: CurveInterpolation,
+    nodes: CurveNodes<T>,
+    calculator: &'a dyn PeriodCalculator<'a>,
+}
+
+impl<'a, T: Real> std::fmt::Debug for InterpolatedRateCurve<'a, T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("InterpolatedRateCurve")
+            .field("reference_date", &self.reference_date)
+            .field("tenors", &self.tenors)
+            .field("rates", &self.rates)
+            .field("interpolation", &self.interpolation)
+            .finish_non_exhaustive()
+    }
 }
 
 impl<'a, T: Real> InterpolatedRateCurve<'a, T> {
+    const MAX_PASSES: usize = 50;
+    const DAY_COUNT: DayCountConvention<'static> = DayCountConvention::Actual365Fixed;
+
     pub fn new(
         reference_date: NaiveDate,
         tenors: Vec<Tenor>,
         rates: Vec<InterestRate<'a, T>>,
-        calculator: &dyn PeriodCalculator<'a>,
+        calculator: &'a dyn PeriodCalculator<'a>,
     ) -> Result<Self, CurveError> {
         if tenors.len() != rates.len() {
             return Err(CurveError::LengthMismatch);
         }
 
-        let year_fractions: Vec<T> = tenors
-            .iter()
-            .zip(rates.iter())
-            .map(|(tenor, rate)| {
-                let end_date = tenor.advance(reference_date);
-                let yf = calculator
-                    .year_fraction(reference_date, end_date, rate.convention)
-                    .0;
-
-                // Lift the f64 into the generic type T
-                T::from_f64(yf)
-            })
-            .collect();
+        let mut times = Vec::with_capacity(tenors.len());
+        let mut log_discounts = Vec::with_capacity(tenors.len());
+        for (tenor, rate) in tenors.iter().zip(rates.iter()) {
+            let end_date = tenor.advance(reference_date);
 
-        let rate_values: Vec<T> = rates.iter().map(|r| r.value.clone()).collect();
-        let interpolator = LinearInterpolator::new(year_fractions, rate_values)?;
+            // Discount under the quote's own conventions, place it in curve time
+            let accrual = calculator.year_fraction(reference_date, end_date, rate.convention);
+            let time = calculator.year_fraction(reference_date, end_date, Self::DAY_COUNT);
 
-        Ok(Self {
+            log_discounts.push(rate.discount_factor(T::from_f64(accrual.0)).ln());
+            times.push(T::from_f64(time.0));
+        }
+
+        let mut curve = Self {
             reference_date,
             tenors,
             rates,
-            interpolator,
-        })
+            times,
+            log_discounts,
+            interpolation: CurveInterpolation::LINEAR_ZERO,
+            nodes: CurveNodes::new(
+                CurveInterpolation::LINEAR_ZERO,
+                vec![T::zero(), T::one()],
+                vec![T::zero(), T::zero()],
+            )?,
+            calculator,
+        };
+        curve.nodes = curve.build_nodes(curve.interpolation)?;
+        Ok(curve)
+    }
+
+    pub fn with_interpolation(
+        mut self,
+        interpolation: CurveInterpolation,
+    ) -> Result<Self, CurveError> {
+        self.nodes = self.build_nodes(interpolation)?;
+        self.interpolation = interpolation;
+        Ok(self)
+    }
+
+    pub fn interpolation(&self) -> CurveInterpolation {
+        self.interpolation
+    }
+
+    pub fn reference_date(&self) -> NaiveDate {
+        self.reference_date
+    }
+
+    pub fn tenors(&self) -> &[Tenor] {
+        &self.tenors
+    }
+
+    /// Quotes as given, before normalization.
+    pub fn rates(&self) -> &[InterestRate<'a, T>] {
+        &self.rates
+    }
+
+    /// Day count of curve time.
+    pub fn day_count(&self) -> DayCountConvention<'a> {
+        Self::DAY_COUNT
+    }
+
+    /// Curve time of `date`.
+    pub fn time(&self, date: NaiveDate) -> T {
+        let yf = self
+            .calculator
+            .year_fraction(self.reference_date, date, self.day_count());
+        T::from_f64(yf.0)
+    }
+
+    pub fn discount_factor_at(&self, date: NaiveDate) -> T {
+        self.discount_factor(self.time(date))
+    }
+
+    /// Zero rate to `date` quoted with the given conventions, whatever the curve's own.
+    pub fn zero_rate_at(
+        &self,
+        date: NaiveDate,
+        convention: DayCountConvention<'a>,
+        compounding: Compounding,
+        frequency: Frequency,
+    ) -> InterestRate<'a, T> {
+        let t = self
+            .calculator
+            .year_fraction(self.reference_date, date, convention);
+        InterestRate::implied_rate(
+            self.discount_factor_at(date),
+            T::from_f64(t.0),
+            compounding,
+            frequency,
+            convention,
+        )
+    }
+
+    fn build_nodes(&self, interpolation: CurveInterpolation) -> Result<CurveNodes<T>, CurveError> {
+        if interpolation.variable == InterpolationVariable::ZeroRate {
+            let zeros = self
+                .times
+                .iter()
+                .zip(&self.log_discounts)
+                .map(|(&t, &log_df)| -log_df / t)
+                .collect();
+            return Ok(CurveNodes::new(interpolation, self.times.clone(), zeros)?);
+        }
+
+        // Discount factors pin the curve to one at t = 0
+        let mut times = Vec::with_capacity(self.times.len() + 1);
+        let mut log_dfs = Vec::with_capacity(self.times.len() + 1);
+        if self.times[0] > T::zero() {
+            times.push(T::zero());
+            log_dfs.push(T::zero());
+        }
+        times.extend_from_slice(&self.times);
+        log_dfs.extend_from_slice(&self.log_discounts);
+
+        match interpolation.variable {
+            InterpolationVariable::DiscountFactor => {
+                let values = log_dfs.iter().map(|l| l.exp()).collect();
+                Ok(CurveNodes::new(interpolation, times, values)?)
+            }
+            InterpolationVariable::LogDiscount => {
+                Ok(CurveNodes::new(interpolation, times, log_dfs)?)
+            }
+            _ => self.fit_forwards(interpolation, times, log_dfs),
+        }
+    }
+
+    /// Forward nodes whose integral reproduces every quoted discount factor, starting
+    /// from the discrete forwards and sweeping until no node moves the others.
+    fn fit_forwards(
+        &self,
+        interpolation: CurveInterpolation,
+        times: Vec<T>,
+        log_dfs: Vec<T>,
+    ) -> Result<CurveNodes<T>, CurveError> {
+        let n = times.len();
+        let mut values = vec![T::zero(); n];
+        for i in 1..n {
+            values[i] = (log_dfs[i - 1] - log_dfs[i]) / (times[i] - times[i - 1]);
+        }
+        let tolerance = T::from_f64(1e-14);
+
+        let residual =
+            |nodes: &CurveNodes<T>, i: usize| nodes.discount_factor(times[i]).ln() - log_dfs[i];
+
+        for _ in 0..Self::MAX_PASSES {
+            let nodes = CurveNodes::new(interpolation, times.clone(), values.clone())?;
+            if (1..n).all(|i| residual(&nodes, i).abs() <= tolerance) {
+                return Ok(nodes);
+            }
+
+            for i in 1..n {
+                let solved = solve_node(values[i], tolerance, |x| {
+                    values[i] = x;
+                    let nodes = CurveNodes::new(interpolation, times.clone(), values.clone())?;
+                    Ok::<T, CurveError>(residual(&nodes, i))
+                })?;
+                if solved.is_none() {
+                    let index = i + self.tenors.len() - n;
+                    let date = self.tenors[index].advance(self.reference_date);
+                    return Err(CurveError::BootstrapFailed(date));
+                }
+            }
+        }
+
+        let date = self.tenors[self.tenors.len() - 1].advance(self.reference_date);
+        Err(CurveError::BootstrapFailed(date))
+    }
+}
+
+impl<'a> InterpolatedRateCurve<'a, f64> {
+    /// The same curve with quote `i` seeded as dual variable `i`, so that one pricing
+    /// pass carries the sensitivity to every node. `N` must equal the node count.
+    pub fn seed_nodes<const N: usize>(
+        &self,
+    ) -> Result<InterpolatedRateCurve<'a, DualArray<N>>, CurveError> {
+        if N != self.rates.len() {
+            return Err(CurveError::SeedMismatch {
+                nodes: self.rates.len(),
+                variables: N,
+            });
+        }
+
+        let rates = self
+            .rates
+            .iter()
+            .enumerate()
+            .map(|(i, rate)| {
+                InterestRate::new(
+                    DualArray::var(rate.value, i),
+                    rate.convention,
+                    rate.compounding,
+                    rate.frequency,
+                )
+            })
+            .collect();
+
+        InterpolatedRateCurve::new(
+            self.reference_date,
+            self.tenors.clone(),
+            rates,
+            self.calculator,
+        )?
+        .with_interpolation(self.interpolation)
+    }
+
+    /// Key-rate rho: derivative of `value` with respect to each quoted node rate,
+    /// reported by tenor.
+    pub fn key_rate_rho<const N: usize, F>(&self, value: F) -> Result<Vec<(Tenor, f64)>, CurveError>
+    where
+        F: FnOnce(&InterpolatedRateCurve<'a, DualArray<N>>) -> DualArray<N>,
+    {
+        let seeded = self.seed_nodes::<N>()?;
+        let value = value(&seeded);
+        Ok(self.tenors.iter().copied().zip(value.grad).collect())
     }
 }
 
@@ -126,4 +339,8 @@ impl<'a, T: Real> RateCurve<T> for ContinuousRateCurve<'a, T> {
     fn discount_factor(&self, t: T) -> T {
         self.rate.discount_factor(t)
     }
+
+    fn instantaneous_forward(&self, _t: T) -> T {
+        self.rate.value
+    }
 }
@@ -0,0 +1,229 @@
+use chrono::NaiveDate;
+
+use crate::core::error::InterpolationError;
+use crate::traits::rate_curve::RateCurve;
+use crate::types::Real;
+
+/// Continuously compounded spread in curve time: constant, or linear between nodes
+/// and flat beyond the first and last.
+#[derive(Debug, Clone)]
+pub struct Spread<T: Real> {
+    times: Vec<T>,
+    values: Vec<T>,
+}
+
+impl<T: Real> Spread<T> {
+    pub fn parallel(value: T) -> Self {
+        Self {
+            times: vec![T::zero()],
+            values: vec![value],
+        }
+    }
+
+    /// Spread term structure through `(times[i], values[i])`, with `times` increasing
+    /// from zero or later.
+    pub fn term(times: Vec<T>, values: Vec<T>) -> Result<Self, InterpolationError> {
+        if times.len() != values.len() {
+            return Err(InterpolationError::LengthMismatch);
+        }
+        if times.len() < 2 {
+            return Err(InterpolationError::InsufficientPoints);
+        }
+        if times.windows(2).any(|w| w[1] <= w[0]) {
+            return Err(InterpolationError::NotMonotonic);
+        }
+
+        Ok(Self { times, values })
+    }
+
+    pub fn value(&self, t: T) -> T {
+        let n = self.times.len();
+        if t <= self.times[0] {
+            return self.values[0];
+        }
+        if t >= self.times[n - 1] {
+            return self.values[n - 1];
+        }
+
+        let i = self.times.iter().rposition(|&node| node <= t).unwrap();
+        let w = (t - self.times[i]) / (self.times[i + 1] - self.times[i]);
+        self.values[i] + w * (self.values[i + 1] - self.values[i])
+    }
+
+    /// Spread integrated from zero to `t`.
+    pub fn integral(&self, t: T) -> T {
+        let half = T::from_f64(0.5);
+        if t <= self.times[0] {
+            return self.values[0] * t;
+        }
+
+        let mut total = self.values[0] * self.times[0];
+        for i in 1..self.times.len() {
+            let (t0, t1) = (self.times[i - 1], self.times[i]);
+            if t <= t1 {
+                return total + (self.values[i - 1] + self.value(t)) * (t - t0) * half;
+            }
+            total += (self.values[i - 1] + self.values[i]) * (t1 - t0) * half;
+        }
+
+        let last = self.times.len() - 1;
+        total + self.values[last] * (t - self.times[last])
+    }
+}
+
+/// Base curve with a spread added to its continuously compounded zero rates.
+#[derive(Debug, Clone)]
+pub struct ZeroSpreadedCurve<T: Real, C: RateCurve<T>> {
+    base: C,
+    spread: Spread<T>,
+}
+
+impl<T: Real, C: RateCurve<T>> ZeroSpreadedCurve<T, C> {
+    pub fn new(base: C, spread: Spread<T>) -> Self {
+        Self { base, spread }
+    }
+
+    pub fn base(&self) -> &C {
+        &self.base
+    }
+
+    pub fn spread(&self) -> &Spread<T> {
+        &self.spread
+    }
+}
+
+impl<T: Real, C: RateCurve<T>> RateCurve<T> for ZeroSpreadedCurve<T, C> {
+    /// Continuously compounded, whatever the base curve quotes.
+    fn zero_rate(&self, t: T) -> T {
+        if t <= T::zero() {
+            return self.instantaneous_forward(T::zero());
+        }
+        -self.discount_factor(t).ln() / t
+    }
+
+    fn discount_factor(&self, t: T) -> T {
+        self.base.discount_factor(t) * (-self.spread.value(t) * t).exp()
+    }
+
+    fn reference_date(&self) -> Option<NaiveDate> {
+        self.base.reference_date()
+    }
+
+    fn curve_time(&self, date: NaiveDate) -> Option<T> {
+        self.base.curve_time(date)
+    }
+}
+
+/// Base curve with a spread added to its instantaneous forwards.
+#[derive(Debug, Clone)]
+pub struct ForwardSpreadedCurve<T: Real, C: RateCurve<T>> {
+    base: C,
+    spread: Spread<T>,
+}
+
+impl<T: Real, C: RateCurve<T>> ForwardSpreadedCurve<T, C> {
+    pub fn new(base: C, spread: Spread<T>) -> Self {
+        Self { base, spread }
+    }
+
+    pub fn base(&self) -> &C {
+        &self.base
+    }
+
+    pub fn spread(&self) -> &Spread<T> {
+        &self.spread
+    }
+}
+
+impl<T: Real, C: RateCurve<T>> RateCurve<T> for ForwardSpreadedCurve<T, C> {
+    /// Continuously compounded, whatever the base curve quotes.
+    fn zero_rate(&self, t: T) -> T {
+        if t <= T::zero() {
+            return self.instantaneous_forward(T::zero());
+        }
+        -self.discount_factor(t).ln() / t
+    }
+
+    fn discount_factor(&self, t: T) -> T {
+        self.base.discount_factor(t) * (-self.spread.integral(t)).exp()
+    }
+
+    fn reference_date(&self) -> Option<NaiveDate> {
+        self.base.reference_date()
+    }
+
+    fn curve_time(&self, date: NaiveDate) -> Option<T> {
+        self.base.curve_time(date)
+    }
+
+    fn instantaneous_forward(&self, t: T) -> T {
+        self.base.instantaneous_forward(t) + self.spread.value(t)
+    }
+}
+
+/// Base curve seen from a later reference time `start`: discount factors are base
+/// forwards from `start`, and curve time is measured from it.
+#[derive(Debug, Clone)]
+pub struct ImpliedCurve<T: Real, C: RateCurve<T>> {
+    base: C,
+    start: T,
+    reference_date: Option<NaiveDate>,
+}
+
+impl<T: Real, C: RateCurve<T>> ImpliedCurve<T, C> {
+    /// Undated, since `start` is a curve time and need not fall on a date; use
+    /// `at_date` to move a dated base curve to a new reference date.
+    pub fn new(base: C, start: T) -> Self {
+        Self {
+            base,
+            start,
+            reference_date: None,
+        }
+    }
+
+    /// Base curve seen from `reference_date`, or `None` if the base curve is undated.
+    pub fn at_date(base: C, reference_date: NaiveDate) -> Option<Self> {
+        let start = base.curve_time(reference_date)?;
+        Some(Self {
+            base,
+            start,
+            reference_date: Some(reference_date),
+        })
+    }
+
+    pub fn base(&self) -> &C {
+        &self.base
+    }
+
+    /// Reference time in base curve time.
+    pub fn start(&self) -> T {
+        self.start
+    }
+}
+
+impl<T: Real, C: RateCurve<T>> RateCurve<T> for ImpliedCurve<T, C> {
+    /// Continuously compounded.
+    fn zero_rate(&self, t: T) -> T {
+        if t <= T::zero() {
+            return self.instantaneous_forward(T::zero());
+        }
+        -self.discount_factor(t).ln() / t
+    }
+
+    fn discount_factor(&self, t: T) -> T {
+        self.base.discount_factor(self.start + t) / self.base.discount_factor(self.start)
+    }
+
+    fn reference_date(&self) -> Option<NaiveDate> {
+        self.reference_date
+    }
+
+    fn curve_time(&self, date: NaiveDate) -> Option<T> {
+        self.reference_date?;
+        self.base.curve_time(date).map(|t| t - self.start)
+    }
+
+    fn instantaneous_forward(&self, t: T) -> T {
+        self.base.instantaneous_forward(self.start + t)
+    }
+}
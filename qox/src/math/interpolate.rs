@@ -16,46 +16,56 @@ pub struct LinearInterpolator<T: Real> {
 
 impl<T: Real> LinearInterpolator<T> {
     pub fn new(x: Vec<T>, y: Vec<T>) -> Result<Self, InterpolationError> {
-        if x.len() != y.len() {
-            return Err(InterpolationError::LengthMismatch);
-        }
-        if x.len() < 2 {
-            return Err(InterpolationError::InsufficientPoints);
-        }
-
-        // Check x is sorted
-        for i in 1..x.len() {
-            if x[i] <= x[i - 1] {
-                return Err(InterpolationError::NotMonotonic);
-            }
-        }
-
+        check_nodes(&x, &y)?;
         Ok(Self { x, y })
     }
 
     fn find_interval(&self, x: T) -> usize {
-        // Binary search for the interval
-        if x <= self.x[0] {
-            return 0;
-        }
-        if x >= self.x[self.x.len() - 1] {
-            return self.x.len() - 2;
-        }
+        find_interval(&self.x, x)
+    }
+}
 
-        let mut left = 0;
-        let mut right = self.x.len() - 1;
+fn check_nodes<T: Real>(x: &[T], y: &[T]) -> Result<(), InterpolationError> {
+    if x.len() != y.len() {
+        return Err(InterpolationError::LengthMismatch);
+    }
+    if x.len() < 2 {
+        return Err(InterpolationError::InsufficientPoints);
+    }
 
-        while right - left > 1 {
-            let mid = (left + right) / 2;
-            if self.x[mid] <= x {
-                left = mid;
-            } else {
-                right = mid;
-            }
+    // Check x is sorted
+    for i in 1..x.len() {
+        if x[i] <= x[i - 1] {
+            return Err(InterpolationError::NotMonotonic);
         }
+    }
 
-        left
+    Ok(())
+}
+
+/// Index of the interval `[x[i], x[i + 1]]` holding `v`, clamped to the end intervals.
+fn find_interval<T: Real>(x: &[T], v: T) -> usize {
+    // Binary search for the interval
+    if v <= x[0] {
+        return 0;
+    }
+    if v >= x[x.len() - 1] {
+        return x.len() - 2;
+    }
+
+    let mut left = 0;
+    let mut right = x.len() - 1;
+
+    while right - left > 1 {
+        let mid = (left + right) / 2;
+        if x[mid] <= v {
+            left = mid;
+        } else {
+            right = mid;
+        }
     }
+
+    left
 }
 
 impl<T: Real> Interpolator1D<T> for LinearInterpolator<T> {
@@ -78,6 +88,417 @@ impl<T: Real> Interpolator1D<T> for LinearInterpolator<T> {
     }
 }
 
+/// Cubic Hermite segment through `(x[i], y[i])` with end slopes `d[i]`, extended
+/// linearly with the end slopes outside the nodes.
+fn hermite<T: Real>(x: &[T], y: &[T], d: &[T], v: T) -> T {
+    let n = x.len();
+    if v < x[0] {
+        return y[0] + d[0] * (v - x[0]);
+    }
+    if v > x[n - 1] {
+        return y[n - 1] + d[n - 1] * (v - x[n - 1]);
+    }
+
+    let i = find_interval(x, v);
+    let h = x[i + 1] - x[i];
+    let s = (v - x[i]) / h;
+    let s2 = s * s;
+    let s3 = s2 * s;
+
+    let two = T::from_f64(2.0);
+    let three = T::from_f64(3.0);
+    let h00 = two * s3 - three * s2 + T::one();
+    let h10 = s3 - two * s2 + s;
+    let h01 = three * s2 - two * s3;
+    let h11 = s3 - s2;
+
+    h00 * y[i] + h10 * h * d[i] + h01 * y[i + 1] + h11 * h * d[i + 1]
+}
+
+fn secants<T: Real>(x: &[T], y: &[T]) -> Vec<T> {
+    x.windows(2)
+        .zip(y.windows(2))
+        .map(|(x, y)| (y[1] - y[0]) / (x[1] - x[0]))
+        .collect()
+}
+
+/// End conditions for a cubic spline.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SplineBoundary<T> {
+    /// Zero second derivative at both ends.
+    Natural,
+    /// Prescribed first derivative at each end.
+    Clamped { start: T, end: T },
+}
+
+/// Twice continuously differentiable cubic spline.
+#[derive(Clone, Debug)]
+pub struct CubicSplineInterpolator<T: Real> {
+    x: Vec<T>,
+    y: Vec<T>,
+    slopes: Vec<T>,
+}
+
+impl<T: Real> CubicSplineInterpolator<T> {
+    pub fn new(
+        x: Vec<T>,
+        y: Vec<T>,
+        boundary: SplineBoundary<T>,
+    ) -> Result<Self, InterpolationError> {
+        check_nodes(&x, &y)?;
+
+        let n = x.len();
+        let s = secants(&x, &y);
+        let two = T::from_f64(2.0);
+        let three = T::from_f64(3.0);
+
+        // Tridiagonal system for the node slopes: continuity of the second derivative
+        // at interior nodes, the boundary condition at the ends.
+        let mut lower = vec![T::zero(); n];
+        let mut diag = vec![T::one(); n];
+        let mut upper = vec![T::zero(); n];
+        let mut rhs = vec![T::zero(); n];
+
+        match boundary {
+            SplineBoundary::Natural => {
+                diag[0] = two;
+                upper[0] = T::one();
+                rhs[0] = three * s[0];
+                lower[n - 1] = T::one();
+                diag[n - 1] = two;
+                rhs[n - 1] = three * s[n - 2];
+            }
+            SplineBoundary::Clamped { start, end } => {
+                rhs[0] = start;
+                rhs[n - 1] = end;
+            }
+        }
+
+        for i in 1..n - 1 {
+            let h_prev = x[i] - x[i - 1];
+            let h_next = x[i + 1] - x[i];
+            lower[i] = h_next;
+            diag[i] = two * (h_prev + h_next);
+            upper[i] = h_prev;
+            rhs[i] = three * (h_next * s[i - 1] + h_prev * s[i]);
+        }
+
+        // Thomas algorithm
+        for i in 1..n {
+            let m = lower[i] / diag[i - 1];
+            diag[i] -= m * upper[i - 1];
+            let carry = m * rhs[i - 1];
+            rhs[i] -= carry;
+        }
+        let mut slopes = vec![T::zero(); n];
+        slopes[n - 1] = rhs[n - 1] / diag[n - 1];
+        for i in (0..n - 1).rev() {
+            slopes[i] = (rhs[i] - upper[i] * slopes[i + 1]) / diag[i];
+        }
+
+        Ok(Self { x, y, slopes })
+    }
+}
+
+impl<T: Real> Interpolator1D<T> for CubicSplineInterpolator<T> {
+    fn interpolate(&self, x: T) -> T {
+        hermite(&self.x, &self.y, &self.slopes, x)
+    }
+}
+
+/// Fritsch-Carlson monotone cubic: a Hermite cubic whose slopes are limited so the
+/// interpolant never overshoots monotone data.
+#[derive(Clone, Debug)]
+pub struct MonotoneCubicInterpolator<T: Real> {
+    x: Vec<T>,
+    y: Vec<T>,
+    slopes: Vec<T>,
+}
+
+impl<T: Real> MonotoneCubicInterpolator<T> {
+    pub fn new(x: Vec<T>, y: Vec<T>) -> Result<Self, InterpolationError> {
+        check_nodes(&x, &y)?;
+
+        let n = x.len();
+        let s = secants(&x, &y);
+
+        let mut slopes = vec![T::zero(); n];
+        slopes[0] = s[0];
+        slopes[n - 1] = s[n - 2];
+        for i in 1..n - 1 {
+            if s[i - 1] * s[i] > T::zero() {
+                slopes[i] = (s[i - 1] + s[i]) * T::from_f64(0.5);
+            }
+        }
+
+        for k in 0..n - 1 {
+            if s[k] == T::zero() {
+                slopes[k] = T::zero();
+                slopes[k + 1] = T::zero();
+                continue;
+            }
+
+            let alpha = slopes[k] / s[k];
+            let beta = slopes[k + 1] / s[k];
+            let radius = alpha * alpha + beta * beta;
+            if radius > T::from_f64(9.0) {
+                let tau = T::from_f64(3.0) / radius.sqrt();
+                slopes[k] = tau * alpha * s[k];
+                slopes[k + 1] = tau * beta * s[k];
+            }
+        }
+
+        Ok(Self { x, y, slopes })
+    }
+}
+
+impl<T: Real> Interpolator1D<T> for MonotoneCubicInterpolator<T> {
+    fn interpolate(&self, x: T) -> T {
+        hermite(&self.x, &self.y, &self.slopes, x)
+    }
+}
+
+/// Zero-rate interpolator with piecewise flat forwards: `y(x) * x` is linear between
+/// nodes. The zero rate is held flat before the first node and the last forward is
+/// extended after the final one.
+#[derive(Clone, Debug)]
+pub struct FlatForwardInterpolator<T: Real> {
+    x: Vec<T>,
+    y: Vec<T>,
+    /// `y * x` at each node.
+    yx: Vec<T>,
+}
+
+impl<T: Real> FlatForwardInterpolator<T> {
+    pub fn new(x: Vec<T>, y: Vec<T>) -> Result<Self, InterpolationError> {
+        check_nodes(&x, &y)?;
+        let yx = x.iter().zip(&y).map(|(&x, &y)| x * y).collect();
+        Ok(Self { x, y, yx })
+    }
+}
+
+impl<T: Real> Interpolator1D<T> for FlatForwardInterpolator<T> {
+    fn interpolate(&self, x: T) -> T {
+        if x <= self.x[0] && self.x[0] > T::zero() {
+            return self.y[0];
+        }
+
+        let i = find_interval(&self.x, x);
+        let forward = (self.yx[i + 1] - self.yx[i]) / (self.x[i + 1] - self.x[i]);
+        if x <= T::zero() {
+            return forward;
+        }
+
+        (self.yx[i] + forward * (x - self.x[i])) / x
+    }
+}
+
+/// Hagan-West monotone convex interpolation of zero rates.
+///
+/// The instantaneous forward on each interval is a piecewise quadratic that integrates
+/// to the discrete forward, is continuous at the nodes and stays between neighbouring
+/// discrete forwards. A node at `x = 0` is added if the first node is later.
+#[derive(Clone, Debug)]
+pub struct MonotoneConvexInterpolator<T: Real> {
+    x: Vec<T>,
+    /// `y * x` at each node.
+    yx: Vec<T>,
+    /// Discrete forward over each interval.
+    discrete: Vec<T>,
+    /// Instantaneous forward at each node.
+    forwards: Vec<T>,
+}
+
+impl<T: Real> MonotoneConvexInterpolator<T> {
+    pub fn new(x: Vec<T>, y: Vec<T>) -> Result<Self, InterpolationError> {
+        check_nodes(&x, &y)?;
+
+        let (x, y) = if x[0] > T::zero() {
+            let mut x0 = vec![T::zero()];
+            x0.extend(x);
+            let mut y0 = vec![y[0]];
+            y0.extend(y);
+            (x0, y0)
+        } else {
+            (x, y)
+        };
+
+        let yx: Vec<T> = x.iter().zip(&y).map(|(&x, &y)| x * y).collect();
+        let discrete = secants(&x, &yx);
+
+        let m = x.len();
+        let mut forwards = vec![T::zero(); m];
+        for i in 1..m - 1 {
+            let h_prev = x[i] - x[i - 1];
+            let h_next = x[i + 1] - x[i];
+            forwards[i] = (h_prev * discrete[i] + h_next * discrete[i - 1]) / (h_prev + h_next);
+        }
+
+        let half = T::from_f64(0.5);
+        if m == 2 {
+            forwards[0] = discrete[0];
+            forwards[1] = discrete[0];
+        } else {
+            forwards[0] = discrete[0] - half * (forwards[1] - discrete[0]);
+            forwards[m - 1] = discrete[m - 2] - half * (forwards[m - 2] - discrete[m - 2]);
+        }
+
+        Ok(Self {
+            x,
+            yx,
+            discrete,
+            forwards,
+        })
+    }
+
+    /// Integral over `[0, s]` of the forward's deviation from the discrete forward on a
+    /// unit interval, given the deviations `g0` and `g1` at its ends.
+    fn deviation_integral(g0: T, g1: T, s: T) -> T {
+        let zero = T::zero();
+        let two = T::from_f64(2.0);
+        let three = T::from_f64(3.0);
+        let half = T::from_f64(0.5);
+
+        if g0 == zero && g1 == zero {
+            return zero;
+        }
+
+        let sector_i = (g0 < zero && -half * g0 <= g1 && g1 <= -two * g0)
+            || (g0 > zero && -half * g0 >= g1 && g1 >= -two * g0);
+        let sector_ii = (g0 < zero && g1 > -two * g0) || (g0 > zero && g1 < -two * g0);
+        let sector_iii = (g0 > zero && zero > g1 && g1 > -half * g0)
+            || (g0 < zero && zero < g1 && g1 < -half * g0);
+
+        if sector_i {
+            let s2 = s * s;
+            let s3 = s2 * s;
+            g0 * (s - two * s2 + s3) + g1 * (s3 - s2)
+        } else if sector_ii {
+            let eta = (g1 + two * g0) / (g1 - g0);
+            if s <= eta {
+                g0 * s
+            } else {
+                let d = s - eta;
+                let w = T::one() - eta;
+                g0 * s + (g1 - g0) * d * d * d / (three * w * w)
+            }
+        } else if sector_iii {
+            let eta = three * g1 / (g1 - g0);
+            let d = (eta - s).max(zero);
+            g1 * s + (g0 - g1) * (eta * eta * eta - d * d * d) / (three * eta * eta)
+        } else {
+            let eta = g1 / (g1 + g0);
+            let a = -g0 * g1 / (g0 + g1);
+            let mut integral = a * s;
+            if eta > zero {
+                let d = (eta - s).max(zero);
+                integral += (g0 - a) * (eta * eta * eta - d * d * d) / (three * eta * eta);
+            }
+            if s > eta {
+                let e = s - eta;
+                let w = T::one() - eta;
+                integral += (g1 - a) * e * e * e / (three * w * w);
+            }
+            integral
+        }
+    }
+}
+
+impl<T: Real> Interpolator1D<T> for MonotoneConvexInterpolator<T> {
+    fn interpolate(&self, x: T) -> T {
+        let m = self.x.len();
+        if x <= T::zero() {
+            return self.forwards[0];
+        }
+        if x >= self.x[m - 1] {
+            return (self.yx[m - 1] + self.forwards[m - 1] * (x - self.x[m - 1])) / x;
+        }
+
+        let i = find_interval(&self.x, x);
+        let h = self.x[i + 1] - self.x[i];
+        let s = (x - self.x[i]) / h;
+        let g0 = self.forwards[i] - self.discrete[i];
+        let g1 = self.forwards[i + 1] - self.discrete[i];
+
+        let integral = Self::deviation_integral(g0, g1, s);
+        (self.yx[i] + self.discrete[i] * (x - self.x[i]) + h * integral) / x
+    }
+}
+
+/// One-dimensional interpolation schemes that can be chosen at run time.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum InterpolationMethod {
+    Linear,
+    NaturalCubic,
+    /// Cubic spline with prescribed end slopes.
+    ClampedCubic {
+        start: f64,
+        end: f64,
+    },
+    /// Fritsch-Carlson monotone cubic.
+    MonotoneCubic,
+    /// Hagan-West monotone convex; `y` must be zero rates at times `x`.
+    MonotoneConvex,
+    /// Piecewise flat forwards; `y` must be zero rates at times `x`.
+    FlatForward,
+}
+
+impl InterpolationMethod {
+    pub fn build<T: Real>(
+        &self,
+        x: Vec<T>,
+        y: Vec<T>,
+    ) -> Result<AnyInterpolator<T>, InterpolationError> {
+        Ok(match *self {
+            InterpolationMethod::Linear => AnyInterpolator::Linear(LinearInterpolator::new(x, y)?),
+            InterpolationMethod::NaturalCubic => AnyInterpolator::CubicSpline(
+                CubicSplineInterpolator::new(x, y, SplineBoundary::Natural)?,
+            ),
+            InterpolationMethod::ClampedCubic { start, end } => {
+                AnyInterpolator::CubicSpline(CubicSplineInterpolator::new(
+                    x,
+                    y,
+                    SplineBoundary::Clamped {
+                        start: T::from_f64(start),
+                        end: T::from_f64(end),
+                    },
+                )?)
+            }
+            InterpolationMethod::MonotoneCubic => {
+                AnyInterpolator::MonotoneCubic(MonotoneCubicInterpolator::new(x, y)?)
+            }
+            InterpolationMethod::MonotoneConvex => {
+                AnyInterpolator::MonotoneConvex(MonotoneConvexInterpolator::new(x, y)?)
+            }
+            InterpolationMethod::FlatForward => {
+                AnyInterpolator::FlatForward(FlatForwardInterpolator::new(x, y)?)
+            }
+        })
+    }
+}
+
+/// Interpolator built from an `InterpolationMethod`.
+#[derive(Clone, Debug)]
+pub enum AnyInterpolator<T: Real> {
+    Linear(LinearInterpolator<T>),
+    CubicSpline(CubicSplineInterpolator<T>),
+    MonotoneCubic(MonotoneCubicInterpolator<T>),
+    MonotoneConvex(MonotoneConvexInterpolator<T>),
+    FlatForward(FlatForwardInterpolator<T>),
+}
+
+impl<T: Real> Interpolator1D<T> for AnyInterpolator<T> {
+    fn interpolate(&self, x: T) -> T {
+        match self {
+            AnyInterpolator::Linear(i) => i.interpolate(x),
+            AnyInterpolator::CubicSpline(i) => i.interpolate(x),
+            AnyInterpolator::MonotoneCubic(i) => i.interpolate(x),
+            AnyInterpolator::MonotoneConvex(i) => i.interpolate(x),
+            AnyInterpolator::FlatForward(i) => i.interpolate(x),
+        }
+    }
+}
+
 #[derive(Clone, Debug)]
 pub struct BilinearInterpolator<T: Real> {
     x: Vec<T>,
@@ -1,2 +1,3 @@
 pub mod interpolate;
-pub mod payoffs;
\ No newline at end of file
+pub mod payoffs;
+pub mod special;
\ No newline at end of file
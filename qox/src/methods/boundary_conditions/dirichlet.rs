@@ -0,0 +1,42 @@
+use crate::{
+    instruments::OptionType,
+    traits::boundary::{BoundaryCondition, BoundaryRow, BoundarySide, BoundaryStencil},
+    types::Real,
+};
+
+#[derive(Debug, Clone, Copy)]
+pub enum DirichletBoundary {
+    /// Holds the boundary node at its payoff value.
+    Fixed,
+    /// Tracks the discounted intrinsic value `w * (S_b - K * exp(-r * tau))` on the
+    /// in-the-money wing and zero on the other one.
+    DiscountedIntrinsic(OptionType),
+}
+
+impl<T: Real> BoundaryCondition<T> for DirichletBoundary {
+    fn row(&self, stencil: &BoundaryStencil<T>) -> BoundaryRow<T> {
+        match self {
+            Self::Fixed => BoundaryRow {
+                diag: T::zero(),
+                off: T::zero(),
+                source: T::zero(),
+            },
+            Self::DiscountedIntrinsic(option_type) => {
+                // Differentiating the intrinsic value in tau gives
+                // dV/dtau = -r * V + w * r * S_b, independent of the strike.
+                let rate = -stencil.reaction;
+                let source = match (option_type, stencil.side) {
+                    (OptionType::Call, BoundarySide::Upper) => rate * stencil.spot,
+                    (OptionType::Put, BoundarySide::Lower) => -rate * stencil.spot,
+                    _ => T::zero(),
+                };
+
+                BoundaryRow {
+                    diag: stencil.reaction,
+                    off: T::zero(),
+                    source,
+                }
+            }
+        }
+    }
+}
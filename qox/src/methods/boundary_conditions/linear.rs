@@ -0,0 +1,27 @@
+use crate::{
+    traits::boundary::{BoundaryCondition, BoundaryRow, BoundaryStencil},
+    types::Real,
+};
+
+/// Zero-gamma condition: the price is linear in the underlying at the boundary.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct LinearBoundary;
+
+impl LinearBoundary {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl<T: Real> BoundaryCondition<T> for LinearBoundary {
+    fn row(&self, stencil: &BoundaryStencil<T>) -> BoundaryRow<T> {
+        // With V_SS = 0 only the drift survives; V_x is taken one-sided towards the interior.
+        let slope = stencil.drift / stencil.h;
+
+        BoundaryRow {
+            diag: slope + stencil.reaction,
+            off: -slope,
+            source: T::zero(),
+        }
+    }
+}
@@ -0,0 +1,3 @@
+pub mod dirichlet;
+pub mod linear;
+pub mod neumann;
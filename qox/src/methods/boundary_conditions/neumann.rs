@@ -0,0 +1,32 @@
+use crate::{
+    traits::boundary::{BoundaryCondition, BoundaryRow, BoundaryStencil},
+    types::Real,
+};
+
+/// Fixes the physical delta `dV/dS` at the boundary.
+#[derive(Debug, Clone, Copy)]
+pub struct NeumannBoundary<T> {
+    pub delta: T,
+}
+
+impl<T> NeumannBoundary<T> {
+    pub fn new(delta: T) -> Self {
+        Self { delta }
+    }
+}
+
+impl<T: Real> BoundaryCondition<T> for NeumannBoundary<T> {
+    fn row(&self, stencil: &BoundaryStencil<T>) -> BoundaryRow<T> {
+        let two = T::from_f64(2.0);
+        let h = stencil.h;
+        let a = stencil.diffusion;
+        let slope = self.delta * stencil.jacobian;
+
+        // Ghost node outside the mesh: V_g = V_n + 2 * h * V_x
+        BoundaryRow {
+            diag: -two * a / (h * h) + stencil.reaction,
+            off: two * a / (h * h),
+            source: (two * a / h + stencil.convection) * slope,
+        }
+    }
+}
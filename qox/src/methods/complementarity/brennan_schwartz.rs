@@ -40,7 +40,7 @@ where
         // 1. Forward Elimination
         // We transform the system (I - dt * L)x = rhs
         d_star[0] = T::one() - dt * op.diag[0];
-        rhs_star[0] = rhs[0];
+        rhs_star[0] = rhs[0] + dt * op.source_at(0);
 
         for i in 1..n {
             let a_i = -dt * op.lower[i];
@@ -49,7 +49,7 @@ where
 
             let m = a_i / d_star[i - 1];
             d_star[i] = d_i - m * c_prev;
-            rhs_star[i] = rhs[i] - m * rhs_star[i - 1];
+            rhs_star[i] = rhs[i] + dt * op.source_at(i) - m * rhs_star[i - 1];
         }
 
         // 2. Backward Substitution with Brennan-Schwartz Constraint
@@ -7,8 +7,97 @@ use crate::{
 };
 
 pub mod brennan_schwartz;
+pub mod penalty;
+pub mod policy_iteration;
+pub mod projection;
 pub mod psor;
 
+/// Running counters kept by the iterative solvers.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct IterationStats {
+    /// Number of `solve` calls.
+    pub solves: usize,
+    /// Iterations used by the most recent solve.
+    pub last_iterations: usize,
+    /// Iterations summed over every solve.
+    pub total_iterations: usize,
+    /// Solves that hit `max_iter` before meeting the tolerance.
+    pub failures: usize,
+}
+
+impl IterationStats {
+    pub(crate) fn record(self, iterations: usize, converged: bool) -> Self {
+        Self {
+            solves: self.solves + 1,
+            last_iterations: iterations,
+            total_iterations: self.total_iterations + iterations,
+            failures: self.failures + usize::from(!converged),
+        }
+    }
+}
+
+/// Buffers an iterative solver keeps between solves, so the once-per-stage calls do
+/// not allocate. Grown to the grid size on first use.
+pub(crate) struct Scratch<T> {
+    pub floor: Vec<T>,
+    pub cap: Vec<Option<T>>,
+    pub a: Vec<T>,
+    pub c: Vec<T>,
+    pub d: Vec<T>,
+    pub b: Vec<T>,
+    pub work: Vec<T>,
+}
+
+impl<T: Real> Scratch<T> {
+    pub fn new() -> Self {
+        Self {
+            floor: Vec::new(),
+            cap: Vec::new(),
+            a: Vec::new(),
+            c: Vec::new(),
+            d: Vec::new(),
+            b: Vec::new(),
+            work: Vec::new(),
+        }
+    }
+
+    /// Sizes every buffer for `n` nodes and fills in the obstacles. Where the cap dips
+    /// below the floor the floor wins, as in `Constraint::apply`.
+    pub fn prepare<SG, C>(&mut self, n: usize, constraint: &C, grid: &SG)
+    where
+        SG: SpatialGrid<T>,
+        C: Constraint<T, SG>,
+    {
+        self.floor.clear();
+        self.floor.extend((0..n).map(|i| constraint.lower_bound(i, grid)));
+        self.cap.clear();
+        self.cap.extend(
+            (0..n).map(|i| constraint.upper_bound(i, grid).map(|u| u.max(self.floor[i]))),
+        );
+        for buffer in [&mut self.a, &mut self.c, &mut self.d, &mut self.b, &mut self.work] {
+            buffer.resize(n, T::zero());
+        }
+    }
+}
+
+/// Thomas algorithm for a tridiagonal system with row `i` reading
+/// `a[i] * x[i-1] + d[i] * x[i] + c[i] * x[i+1] = b[i]`. Overwrites `d` and `b`.
+pub(crate) fn solve_tridiagonal<T: Real>(a: &[T], d: &mut [T], c: &[T], b: &mut [T], x: &mut [T]) {
+    let n = d.len();
+
+    for i in 1..n {
+        let m = a[i] / d[i - 1];
+        d[i] -= m * c[i - 1];
+        let carry = m * b[i - 1];
+        b[i] -= carry;
+    }
+
+    x[n - 1] = b[n - 1] / d[n - 1];
+    for i in (0..n - 1).rev() {
+        x[i] = (b[i] - c[i] * x[i + 1]) / d[i];
+    }
+}
+
 pub trait ComplementaritySolver<T, SG, L, C>
 where
     T: Real,
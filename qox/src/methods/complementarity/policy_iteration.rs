@@ -0,0 +1,208 @@
+use std::cell::{Cell, RefCell};
+
+use crate::{
+    methods::{
+        complementarity::{ComplementaritySolver, IterationStats, Scratch, solve_tridiagonal},
+        constraints::Constraint,
+        finite_difference::meshers::SpatialGrid,
+        linear_operators::{LinearOperator, tridiagonal_operator::TridiagonalOperator},
+    },
+    types::Real,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Policy {
+    Continue,
+    Floor,
+    Cap,
+}
+
+/// Howard policy iteration on `min(max(A x - b, x - cap), x - floor) = 0`.
+///
+/// Every pass picks, node by node, whichever branch of the LCP is active, then
+/// solves the resulting linear system exactly. The floor policy is iterated to a
+/// fixed point before the cap set is revisited, since a joint update can cycle
+/// between the two obstacles. Branches within `tolerance` of each other keep their
+/// current policy, so round-off cannot make a node flip back and forth.
+pub struct PolicyIteration<T> {
+    pub tolerance: f64,
+    pub max_iter: usize,
+    stats: Cell<IterationStats>,
+    scratch: RefCell<Scratch<T>>,
+    policy: RefCell<Vec<Policy>>,
+}
+
+impl<T: Real> PolicyIteration<T> {
+    pub fn new(tolerance: f64, max_iter: usize) -> Self {
+        Self {
+            tolerance,
+            max_iter,
+            stats: Cell::new(IterationStats::default()),
+            scratch: RefCell::new(Scratch::new()),
+            policy: RefCell::new(Vec::new()),
+        }
+    }
+
+    pub fn stats(&self) -> IterationStats {
+        self.stats.get()
+    }
+
+    pub fn reset_stats(&self) {
+        self.stats.set(IterationStats::default());
+    }
+}
+
+/// `(I - kappa * L) x - rhs - kappa * source`, the continuation branch of the LCP.
+fn residual_into<T: Real>(
+    op: &TridiagonalOperator<T>,
+    rhs: &[T],
+    kappa: T,
+    x: &[T],
+    out: &mut [T],
+) {
+    let n = op.size();
+    for i in 0..n {
+        let mut r = (T::one() - kappa * op.diag[i]) * x[i] - rhs[i] - kappa * op.source_at(i);
+        if i > 0 {
+            r -= kappa * op.lower[i] * x[i - 1];
+        }
+        if i + 1 < n {
+            r -= kappa * op.upper[i] * x[i + 1];
+        }
+        out[i] = r;
+    }
+}
+
+impl<T, SG, C> ComplementaritySolver<T, SG, TridiagonalOperator<T>, C> for PolicyIteration<T>
+where
+    T: Real,
+    SG: SpatialGrid<T>,
+    C: Constraint<T, SG>,
+{
+    fn solve(
+        &self,
+        op: &TridiagonalOperator<T>,
+        rhs: &[T],
+        kappa: T,
+        constraint: &C,
+        mesher: &SG,
+        x: &mut [T],
+    ) {
+        let n = op.size();
+        let tol = T::from_f64(self.tolerance);
+
+        let mut scratch = self.scratch.borrow_mut();
+        scratch.prepare(n, constraint, mesher);
+        let Scratch {
+            floor,
+            cap,
+            a,
+            c,
+            d,
+            b,
+            work: residual,
+        } = &mut *scratch;
+        let mut policy = self.policy.borrow_mut();
+        policy.clear();
+        policy.resize(n, Policy::Continue);
+
+        // Seed the policy from the initial guess
+        residual_into(op, rhs, kappa, x, residual);
+        update_cap_policy(&mut policy, x, residual, cap, tol);
+        update_floor_policy(&mut policy, x, residual, floor, tol);
+
+        let mut iterations = 0;
+        let mut converged = false;
+
+        'outer: loop {
+            // Settle the floor for the current cap set
+            loop {
+                if iterations == self.max_iter {
+                    break 'outer;
+                }
+                iterations += 1;
+
+                for i in 0..n {
+                    match policy[i] {
+                        Policy::Continue => {
+                            a[i] = -kappa * op.lower[i];
+                            d[i] = T::one() - kappa * op.diag[i];
+                            c[i] = -kappa * op.upper[i];
+                            b[i] = rhs[i] + kappa * op.source_at(i);
+                        }
+                        Policy::Floor => {
+                            (a[i], d[i], c[i]) = (T::zero(), T::one(), T::zero());
+                            b[i] = floor[i];
+                        }
+                        Policy::Cap => {
+                            (a[i], d[i], c[i]) = (T::zero(), T::one(), T::zero());
+                            b[i] = cap[i].unwrap_or(floor[i]);
+                        }
+                    }
+                }
+
+                solve_tridiagonal(a, d, c, b, x);
+                residual_into(op, rhs, kappa, x, residual);
+
+                if !update_floor_policy(&mut policy, x, residual, floor, tol) {
+                    break;
+                }
+            }
+
+            if !update_cap_policy(&mut policy, x, residual, cap, tol) {
+                converged = true;
+                break;
+            }
+        }
+
+        self.stats
+            .set(self.stats.get().record(iterations, converged));
+    }
+}
+
+/// Chooses between continuation and the floor at every node outside the cap set.
+/// Returns whether any node changed.
+fn update_floor_policy<T: Real>(
+    policy: &mut [Policy],
+    x: &[T],
+    residual: &[T],
+    floor: &[T],
+    tol: T,
+) -> bool {
+    let mut changed = false;
+    for i in 0..policy.len() {
+        let choice = match policy[i] {
+            Policy::Cap => continue,
+            Policy::Continue if x[i] - floor[i] < residual[i] - tol => Policy::Floor,
+            Policy::Floor if residual[i] < x[i] - floor[i] - tol => Policy::Continue,
+            current => current,
+        };
+        changed |= choice != policy[i];
+        policy[i] = choice;
+    }
+    changed
+}
+
+/// Moves nodes in and out of the cap set. Floor nodes sit below the cap already.
+/// Returns whether any node changed.
+fn update_cap_policy<T: Real>(
+    policy: &mut [Policy],
+    x: &[T],
+    residual: &[T],
+    cap: &[Option<T>],
+    tol: T,
+) -> bool {
+    let mut changed = false;
+    for i in 0..policy.len() {
+        let Some(u) = cap[i] else { continue };
+        let choice = match policy[i] {
+            Policy::Floor => continue,
+            Policy::Continue if x[i] - u > residual[i] + tol => Policy::Cap,
+            Policy::Cap if residual[i] > x[i] - u + tol => Policy::Continue,
+            current => current,
+        };
+        changed |= choice != policy[i];
+        policy[i] = choice;
+    }
+    changed
+}
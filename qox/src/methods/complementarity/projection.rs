@@ -0,0 +1,36 @@
+use crate::{
+    methods::{
+        complementarity::ComplementaritySolver, constraints::Constraint,
+        finite_difference::meshers::SpatialGrid, linear_operators::LinearOperator,
+    },
+    types::Real,
+};
+
+/// Solves the unconstrained system with the operator's own factorisation, then
+/// projects the result onto the constraint. Cheap, but only first-order accurate
+/// near the free boundary.
+#[derive(Default)]
+pub struct Projection;
+
+impl Projection {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl<T, SG, L, C> ComplementaritySolver<T, SG, L, C> for Projection
+where
+    T: Real,
+    SG: SpatialGrid<T>,
+    L: LinearOperator<T>,
+    C: Constraint<T, SG>,
+{
+    fn solve(&self, op: &L, rhs: &[T], kappa: T, constraint: &C, mesher: &SG, x: &mut [T]) {
+        let mut z_buffer = vec![T::zero(); op.size()];
+
+        op.setup_coeff(kappa);
+        op.solve_inverse_into(rhs, x, &mut z_buffer);
+
+        constraint.apply(x, mesher);
+    }
+}
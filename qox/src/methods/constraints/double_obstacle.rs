@@ -0,0 +1,45 @@
+use crate::{
+    methods::{constraints::Constraint, finite_difference::meshers::SpatialGrid},
+    traits::payoff::InitialConditions,
+    types::Real,
+};
+
+/// Keeps the value between a holder floor (e.g. a put or exercise value) and an
+/// issuer cap (e.g. a call price), as for callable and puttable claims.
+#[derive(Clone, Copy)]
+pub struct DoubleObstacleConstraint<F, C> {
+    pub floor: F,
+    pub cap: C,
+}
+
+impl<F, C> DoubleObstacleConstraint<F, C> {
+    pub fn new(floor: F, cap: C) -> Self {
+        Self { floor, cap }
+    }
+}
+
+impl<T, F, C, SG> Constraint<T, SG> for DoubleObstacleConstraint<F, C>
+where
+    T: Real,
+    F: InitialConditions<T> + Copy,
+    C: InitialConditions<T> + Copy,
+    SG: SpatialGrid<T>,
+{
+    #[inline(always)]
+    fn apply(&self, price: &mut [T], grid: &SG) {
+        for (i, p) in price.iter_mut().enumerate() {
+            let s = grid.location(i);
+            *p = p.min(self.cap.get_value(s)).max(self.floor.get_value(s));
+        }
+    }
+
+    #[inline(always)]
+    fn lower_bound(&self, i: usize, mesher: &SG) -> T {
+        self.floor.get_value(mesher.location(i))
+    }
+
+    #[inline(always)]
+    fn upper_bound(&self, i: usize, mesher: &SG) -> Option<T> {
+        Some(self.cap.get_value(mesher.location(i)))
+    }
+}
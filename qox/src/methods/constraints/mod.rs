@@ -1,9 +1,15 @@
 use crate::{methods::finite_difference::meshers::SpatialGrid, types::Real};
 
 pub mod american;
+pub mod double_obstacle;
 pub mod none;
 
 pub trait Constraint<T: Real, SG: SpatialGrid<T>> {
     fn apply(&self, price: &mut [T], mesher: &SG);
     fn lower_bound(&self, i: usize, mesher: &SG) -> T;
+
+    /// Cap on the value at node `i`, e.g. an issuer call price. `None` when unbounded.
+    fn upper_bound(&self, _i: usize, _mesher: &SG) -> Option<T> {
+        None
+    }
 }
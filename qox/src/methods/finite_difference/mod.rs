@@ -1,3 +1,4 @@
 pub mod free_boundary;
 pub mod meshers;
 pub mod solver;
+pub mod stencils;
@@ -24,13 +24,13 @@ pub struct FdmConfig {
 }
 
 impl Solver {
+    /// Takes `config.time_steps` steps of `dt` on a grid of `config.nodes` nodes.
     pub fn solve<T, L, SG, Step, IC, SP, const S: usize, const R: usize>(
         &self,
         stepper: Step,
         initial_conditions: IC,
         grid: &SG,
         dt: T,
-        config: FdmConfig,
         step_policy: &SP,
     ) -> NordsieckVector<T>
     where
@@ -41,8 +41,40 @@ impl Solver {
         IC: InitialConditions<T> + Copy,
         SP: StepPolicy<T, SG, L>,
     {
+        let mut workspace = GlmWorkspace::<T>::new(S, self.config.nodes);
+
+        self.solve_with_workspace(
+            stepper,
+            initial_conditions,
+            grid,
+            dt,
+            step_policy,
+            &mut workspace,
+        )
+    }
+
+    /// Same as `solve`, but reuses a caller-owned workspace so repeated solves on the
+    /// same grid size do not reallocate the stage buffers. Grid size and step count
+    /// come from the solver's own config.
+    pub fn solve_with_workspace<T, L, SG, Step, IC, SP, const S: usize, const R: usize>(
+        &self,
+        stepper: Step,
+        initial_conditions: IC,
+        grid: &SG,
+        dt: T,
+        step_policy: &SP,
+        workspace: &mut GlmWorkspace<T>,
+    ) -> NordsieckVector<T>
+    where
+        T: Real,
+        SG: SpatialGrid<T>,
+        Step: TimeStepper<T, NordsieckVector<T>, S, R>,
+        L: LinearOperator<T>,
+        IC: InitialConditions<T> + Copy,
+        SP: StepPolicy<T, SG, L>,
+    {
+        let config = self.config;
         let mut vector = NordsieckVector::<T>::new(R, config.nodes, T::zero());
-        let mut workspace = GlmWorkspace::<T>::new(S, config.nodes);
 
         let initial_v = self.initialize_payoff(initial_conditions, grid);
 
@@ -54,6 +86,7 @@ impl Solver {
             step_policy.get_operator().apply_into(y_slice, f_slice);
         }
 
+        step_policy.begin_solve();
         for _ in 0..config.time_steps {
             let next_t = vector.current_time + dt;
 
@@ -74,7 +107,7 @@ impl Solver {
 
                 step_policy.solve_stage_into(
                     &workspace.rhs_buffer,
-                    dt,
+                    stage_coeff,
                     &grid,
                     stage_slice,
                     &mut workspace.z_buffer,
@@ -89,8 +122,10 @@ impl Solver {
                 );
             }
 
-            stepper.finalize_step(&mut vector, &workspace, dt);
+            stepper.finalize_step(&mut vector, workspace, dt);
             vector.current_time = next_t;
+
+            step_policy.end_step(next_t, grid, vector.step_slice_mut(0));
         }
 
         vector
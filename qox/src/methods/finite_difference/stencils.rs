@@ -0,0 +1,41 @@
+use crate::types::Real;
+
+/// Finite-difference weights for the value, first and second derivative at `z`
+/// from the nodes `x` (Fornberg, 1988). `weights[k][j]` multiplies `v(x[j])` in
+/// the k-th derivative.
+pub fn fornberg_weights<T: Real, const N: usize>(z: T, x: &[T; N]) -> [[T; N]; 3] {
+    let mut c = [[T::zero(); N]; 3];
+    let mut c1 = T::one();
+    let mut c4 = x[0] - z;
+    c[0][0] = T::one();
+
+    for i in 1..N {
+        let mn = i.min(2);
+        let mut c2 = T::one();
+        let c5 = c4;
+        c4 = x[i] - z;
+
+        for j in 0..i {
+            let c3 = x[i] - x[j];
+            c2 = c2 * c3;
+
+            if j == i - 1 {
+                for k in (1..=mn).rev() {
+                    let kf = T::from_f64(k as f64);
+                    c[k][i] = c1 * (kf * c[k - 1][i - 1] - c5 * c[k][i - 1]) / c2;
+                }
+                c[0][i] = -c1 * c5 * c[0][i - 1] / c2;
+            }
+
+            for k in (1..=mn).rev() {
+                let kf = T::from_f64(k as f64);
+                c[k][j] = (c4 * c[k][j] - kf * c[k - 1][j]) / c3;
+            }
+            c[0][j] = c4 * c[0][j] / c3;
+        }
+
+        c1 = c2;
+    }
+
+    c
+}
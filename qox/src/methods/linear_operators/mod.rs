@@ -1,5 +1,6 @@
 use crate::types::Real;
 
+pub mod pentadiagonal_operator;
 pub mod tridiagonal_operator;
 
 pub trait LinearOperator<T: Real> {
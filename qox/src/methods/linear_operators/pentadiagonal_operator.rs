@@ -0,0 +1,181 @@
+use crate::{methods::linear_operators::LinearOperator, types::Real};
+use std::cell::RefCell;
+
+// Banded LU factors of (I - coeff * L): unit lower factor with two sub-diagonals,
+// upper factor with the diagonal (stored inverted) and two super-diagonals.
+struct PentadiagonalCache<T> {
+    coeff: T,
+    l1: Vec<T>,
+    l2: Vec<T>,
+    u0_inv: Vec<T>,
+    u1: Vec<T>,
+    u2: Vec<T>,
+}
+
+/// Banded operator with two bands either side of the diagonal. Row `i` reads
+/// `lower2[i] * v[i-2] + lower[i] * v[i-1] + diag[i] * v[i] + upper[i] * v[i+1] + upper2[i] * v[i+2]`;
+/// entries that would fall outside the grid are ignored.
+pub struct PentadiagonalOperator<T> {
+    pub lower2: Vec<T>,
+    pub lower: Vec<T>,
+    pub diag: Vec<T>,
+    pub upper: Vec<T>,
+    pub upper2: Vec<T>,
+    /// Inhomogeneous term from the boundary rows, so the operator is `L * v + source`.
+    pub source: Option<Vec<T>>,
+    cache: RefCell<Option<PentadiagonalCache<T>>>,
+}
+
+impl<T: Real> PentadiagonalOperator<T> {
+    pub fn new(lower2: Vec<T>, lower: Vec<T>, diag: Vec<T>, upper: Vec<T>, upper2: Vec<T>) -> Self {
+        let n = diag.len();
+        if n < 5 {
+            panic!(
+                "PentadiagonalOperator requires at least 5 elements, found {}",
+                n
+            );
+        }
+
+        PentadiagonalOperator {
+            lower2,
+            lower,
+            diag,
+            upper,
+            upper2,
+            source: None,
+            cache: RefCell::new(None),
+        }
+    }
+
+    pub fn with_source(mut self, source: Vec<T>) -> Self {
+        self.source = Some(source);
+        self
+    }
+
+    #[inline(always)]
+    pub fn source_at(&self, i: usize) -> T {
+        match &self.source {
+            Some(source) => source[i],
+            None => T::zero(),
+        }
+    }
+}
+
+impl<T: Real> LinearOperator<T> for PentadiagonalOperator<T> {
+    fn size(&self) -> usize {
+        self.diag.len()
+    }
+
+    fn apply_into(&self, v: &[T], out: &mut [T]) {
+        let n = self.size();
+
+        for i in 0..n {
+            let mut acc = self.diag[i] * v[i];
+            if i >= 2 {
+                acc += self.lower2[i] * v[i - 2];
+            }
+            if i >= 1 {
+                acc += self.lower[i] * v[i - 1];
+            }
+            if i + 1 < n {
+                acc += self.upper[i] * v[i + 1];
+            }
+            if i + 2 < n {
+                acc += self.upper2[i] * v[i + 2];
+            }
+            out[i] = acc + self.source_at(i);
+        }
+    }
+
+    fn setup_coeff(&self, coeff: T) {
+        let mut cache = self.cache.borrow_mut();
+
+        // Skip if already computed for this coeff
+        if let Some(ref c) = *cache
+            && (c.coeff - coeff).abs() < T::from_f64(1e-12)
+        {
+            return;
+        }
+
+        let n = self.size();
+        let mut l1 = vec![T::zero(); n];
+        let mut l2 = vec![T::zero(); n];
+        let mut u0 = vec![T::zero(); n];
+        let mut u1 = vec![T::zero(); n];
+        let mut u2 = vec![T::zero(); n];
+
+        // Doolittle factorisation of A = I - coeff * L without pivoting
+        for i in 0..n {
+            let d = T::one() - coeff * self.diag[i];
+
+            if i >= 2 {
+                l2[i] = -coeff * self.lower2[i] / u0[i - 2];
+            }
+            if i >= 1 {
+                let a1 = -coeff * self.lower[i];
+                let carry = if i >= 2 { l2[i] * u1[i - 2] } else { T::zero() };
+                l1[i] = (a1 - carry) / u0[i - 1];
+            }
+
+            let mut pivot = d;
+            if i >= 1 {
+                pivot -= l1[i] * u1[i - 1];
+            }
+            if i >= 2 {
+                pivot -= l2[i] * u2[i - 2];
+            }
+            u0[i] = pivot;
+
+            if i + 1 < n {
+                let e1 = -coeff * self.upper[i];
+                u1[i] = if i >= 1 { e1 - l1[i] * u2[i - 1] } else { e1 };
+            }
+            if i + 2 < n {
+                u2[i] = -coeff * self.upper2[i];
+            }
+        }
+
+        let u0_inv = u0.iter().map(|&u| T::one() / u).collect();
+
+        *cache = Some(PentadiagonalCache {
+            coeff,
+            l1,
+            l2,
+            u0_inv,
+            u1,
+            u2,
+        });
+    }
+
+    fn solve_inverse_into(&self, b: &[T], dest: &mut [T], z_buffer: &mut [T]) {
+        let cache = self.cache.borrow();
+        let c = cache
+            .as_ref()
+            .expect("PentadiagonalOperator: setup_coeff must be called first");
+        let n = self.size();
+
+        // Forward substitution on (I - coeff * L) x = b + coeff * source
+        for i in 0..n {
+            let mut z = b[i] + c.coeff * self.source_at(i);
+            if i >= 1 {
+                z -= c.l1[i] * z_buffer[i - 1];
+            }
+            if i >= 2 {
+                z -= c.l2[i] * z_buffer[i - 2];
+            }
+            z_buffer[i] = z;
+        }
+
+        // Back substitution
+        for i in (0..n).rev() {
+            let mut x = z_buffer[i];
+            if i + 1 < n {
+                x -= c.u1[i] * dest[i + 1];
+            }
+            if i + 2 < n {
+                x -= c.u2[i] * dest[i + 2];
+            }
+            dest[i] = x * c.u0_inv[i];
+        }
+    }
+}
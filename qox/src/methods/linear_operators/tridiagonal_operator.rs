@@ -13,6 +13,8 @@ pub struct TridiagonalOperator<T> {
     pub lower: Vec<T>,
     pub diag: Vec<T>,
     pub upper: Vec<T>,
+    /// Inhomogeneous term from the boundary rows, so the operator is `L * v + source`.
+    pub source: Option<Vec<T>>,
     cache: RefCell<Option<TridiagonalCache<T>>>,
 }
 
@@ -30,9 +32,23 @@ impl<T: Real> TridiagonalOperator<T> {
             lower,
             diag,
             upper,
+            source: None,
             cache: RefCell::new(None),
         }
     }
+
+    pub fn with_source(mut self, source: Vec<T>) -> Self {
+        self.source = Some(source);
+        self
+    }
+
+    #[inline(always)]
+    pub fn source_at(&self, i: usize) -> T {
+        match &self.source {
+            Some(source) => source[i],
+            None => T::zero(),
+        }
+    }
 }
 
 impl<T: Real> LinearOperator<T> for TridiagonalOperator<T> {
@@ -59,6 +75,12 @@ impl<T: Real> LinearOperator<T> for TridiagonalOperator<T> {
 
         let last = n - 1;
         out[last] = self.lower[last] * v[last - 1] + self.diag[last] * v[last];
+
+        if let Some(source) = &self.source {
+            for (o, s) in out.iter_mut().zip(source) {
+                *o += *s;
+            }
+        }
     }
 
     fn setup_coeff(&self, coeff: T) {
@@ -115,9 +137,11 @@ impl<T: Real> LinearOperator<T> for TridiagonalOperator<T> {
             .expect("TridiagonalOperator: setup_coeff must be called first");
         let n = self.size();
 
-        z_buffer[0] = b[0] * c.m_inv[0];
+        // (I - coeff * L) x = b + coeff * source
+        z_buffer[0] = (b[0] + c.coeff * self.source_at(0)) * c.m_inv[0];
         for i in 1..n {
-            z_buffer[i] = (b[i] - c.a_prime[i] * z_buffer[i - 1]) * c.m_inv[i];
+            let b_i = b[i] + c.coeff * self.source_at(i);
+            z_buffer[i] = (b_i - c.a_prime[i] * z_buffer[i - 1]) * c.m_inv[i];
         }
 
         // Back substitution: Use the precomputed c_prime
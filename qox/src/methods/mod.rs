@@ -1,4 +1,5 @@
 pub mod analytic;
+pub mod boundary_conditions;
 pub mod complementarity;
 pub mod constraints;
 pub mod finite_difference;
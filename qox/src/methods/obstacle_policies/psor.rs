@@ -3,7 +3,7 @@ use crate::{
         complementarity::{ComplementaritySolver, psor::Psor},
         constraints::Constraint,
         finite_difference::meshers::SpatialGrid,
-        linear_operators::{LinearOperator, tridiagonal_operator::TridiagonalOperator},
+        linear_operators::LinearOperator,
         obstacle_policies::ObstaclePolicy,
     },
     types::Real,
@@ -14,18 +14,15 @@ pub struct PsorObstaclePolicy<C> {
     pub psor: Psor,
 }
 
-impl<T: Real, SG: SpatialGrid<T>, C: Constraint<T, SG>>
-    ObstaclePolicy<T, SG, TridiagonalOperator<T>> for PsorObstaclePolicy<C>
+impl<T, SG, L, C> ObstaclePolicy<T, SG, L> for PsorObstaclePolicy<C>
+where
+    T: Real,
+    SG: SpatialGrid<T>,
+    L: LinearOperator<T>,
+    C: Constraint<T, SG>,
+    Psor: ComplementaritySolver<T, SG, L, C>,
 {
-    fn solve_stage(
-        &self,
-        op: &TridiagonalOperator<T>,
-        b: &[T],
-        dt: T,
-        grid: &SG,
-        dest: &mut [T],
-        _z: &mut [T],
-    ) {
+    fn solve_stage(&self, op: &L, b: &[T], dt: T, grid: &SG, dest: &mut [T], _z: &mut [T]) {
         self.psor.solve(op, b, dt, &self.constraint, grid, dest);
 
         //op.solve_psor_into(b, coeff, &self.constraint, m, dest, z);
@@ -33,7 +30,7 @@ impl<T: Real, SG: SpatialGrid<T>, C: Constraint<T, SG>>
 
     fn compute_stage_derivative<IC>(
         &self,
-        operator: &TridiagonalOperator<T>,
+        operator: &L,
         stage_slice: &[T],
         grid: &SG,
         initial_conditions: IC,
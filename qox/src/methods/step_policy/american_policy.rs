@@ -0,0 +1,188 @@
+use std::{cell::RefCell, marker::PhantomData};
+
+use crate::{
+    methods::{
+        complementarity::ComplementaritySolver, constraints::Constraint,
+        finite_difference::meshers::SpatialGrid, linear_operators::LinearOperator,
+        step_policy::StepPolicy,
+    },
+    traits::payoff::InitialConditions,
+    types::Real,
+};
+
+/// Early-exercise boundary as a time series, one entry per time step.
+#[derive(Debug, Clone)]
+pub struct ExerciseBoundary<T> {
+    /// Time to expiry at the end of each step, in years.
+    pub times: Vec<T>,
+    /// Critical spot at that time, or `None` if no node is exercised.
+    pub spots: Vec<Option<T>>,
+}
+
+impl<T: Real> ExerciseBoundary<T> {
+    pub fn len(&self) -> usize {
+        self.times.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.times.is_empty()
+    }
+
+    /// Critical spot at the longest time to expiry, i.e. today.
+    pub fn current(&self) -> Option<T> {
+        self.spots.last().copied().flatten()
+    }
+
+    fn clear(&mut self) {
+        self.times.clear();
+        self.spots.clear();
+    }
+
+    fn push(&mut self, tau: T, spot: Option<T>) {
+        self.times.push(tau);
+        self.spots.push(spot);
+    }
+}
+
+/// Step policy for early exercise: every implicit stage is solved as a linear
+/// complementarity problem against the constraint, and the exercise boundary is
+/// recorded after each step.
+pub struct AmericanPolicy<'a, T, SG, L, CS, C> {
+    pub operator: &'a L,
+    pub solver: CS,
+    pub constraint: C,
+    boundary: RefCell<ExerciseBoundary<T>>,
+    _marker: PhantomData<SG>,
+}
+
+impl<'a, T, SG, L, CS, C> AmericanPolicy<'a, T, SG, L, CS, C>
+where
+    T: Real,
+    SG: SpatialGrid<T>,
+    C: Constraint<T, SG>,
+{
+    pub fn new(operator: &'a L, solver: CS, constraint: C) -> Self {
+        Self {
+            operator,
+            solver,
+            constraint,
+            boundary: RefCell::new(ExerciseBoundary {
+                times: Vec::new(),
+                spots: Vec::new(),
+            }),
+            _marker: PhantomData,
+        }
+    }
+
+    /// Boundary recorded by the latest solve, ordered by increasing time to expiry.
+    pub fn exercise_boundary(&self) -> ExerciseBoundary<T> {
+        self.boundary.borrow().clone()
+    }
+
+    /// Finds the spot where `values` leaves the obstacle, starting from the wing
+    /// where the obstacle is larger (the low end for puts, the high end for calls).
+    ///
+    /// Near the free boundary `V - g` grows quadratically (smooth pasting), so
+    /// `sqrt(V - g)` is close to linear there and is extrapolated to zero from the
+    /// first two continuation nodes.
+    pub fn locate_boundary(&self, grid: &SG, values: &[T]) -> Option<T> {
+        let n = values.len();
+        let gap = |i: usize| {
+            let g = self.constraint.lower_bound(i, grid);
+            let tol = T::from_f64(1e-10) * (T::one() + g.abs());
+            (values[i] - g, g, tol)
+        };
+
+        let from_low =
+            self.constraint.lower_bound(0, grid) >= self.constraint.lower_bound(n - 1, grid);
+        let index = |k: usize| if from_low { k } else { n - 1 - k };
+
+        // The exercise region starts at the wing, where the obstacle is in the money
+        let (d0, g0, tol0) = gap(index(0));
+        if d0 > tol0 || g0 <= T::zero() {
+            return None;
+        }
+
+        let mut k = 0;
+        while k + 1 < n {
+            let (d, g, tol) = gap(index(k + 1));
+            if d > tol || g <= T::zero() {
+                break;
+            }
+            k += 1;
+        }
+
+        // Every node is exercised, or the continuation region is a single node wide
+        if k + 2 >= n {
+            return None;
+        }
+
+        let s_contact = grid.location(index(k));
+        let s1 = grid.location(index(k + 1));
+        let s2 = grid.location(index(k + 2));
+        let e1 = gap(index(k + 1)).0.max(T::zero()).sqrt();
+        let e2 = gap(index(k + 2)).0.max(T::zero()).sqrt();
+
+        if e2 <= e1 {
+            return Some(s_contact);
+        }
+
+        let root = s1 - e1 * (s2 - s1) / (e2 - e1);
+        let (lo, hi) = if from_low {
+            (s_contact, s1)
+        } else {
+            (s1, s_contact)
+        };
+        Some(root.max(lo).min(hi))
+    }
+}
+
+impl<'a, T, SG, L, CS, C> StepPolicy<T, SG, L> for AmericanPolicy<'a, T, SG, L, CS, C>
+where
+    T: Real,
+    SG: SpatialGrid<T>,
+    L: LinearOperator<T>,
+    CS: ComplementaritySolver<T, SG, L, C>,
+    C: Constraint<T, SG>,
+{
+    fn solve_stage_into(&self, rhs: &[T], coeff: T, grid: &SG, dest: &mut [T], _z: &mut [T]) {
+        self.solver
+            .solve(self.operator, rhs, coeff, &self.constraint, grid, dest);
+    }
+
+    fn compute_stage_derivative<IC>(
+        &self,
+        stage_slice: &[T],
+        grid: &SG,
+        _initial_conditions: IC,
+        l_stage_slice: &mut [T],
+    ) where
+        IC: InitialConditions<T> + Copy,
+    {
+        self.operator.apply_into(stage_slice, l_stage_slice);
+
+        // No time decay on exercised nodes
+        for j in 0..self.operator.size() {
+            let obstacle = self.constraint.lower_bound(j, grid);
+            if stage_slice[j] <= obstacle + T::from_f64(f64::EPSILON) {
+                l_stage_slice[j] = T::zero();
+            }
+        }
+    }
+
+    fn get_operator(&self) -> &L {
+        self.operator
+    }
+
+    fn begin_solve(&self) {
+        self.boundary.borrow_mut().clear();
+    }
+
+    fn end_step(&self, tau: T, grid: &SG, values: &mut [T]) {
+        // The GLM output blends stages, so re-impose the obstacle before reading it
+        self.constraint.apply(values, grid);
+
+        let spot = self.locate_boundary(grid, values);
+        self.boundary.borrow_mut().push(tau, spot);
+    }
+}
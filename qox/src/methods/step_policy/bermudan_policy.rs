@@ -0,0 +1,90 @@
+use std::marker::PhantomData;
+
+use crate::{
+    methods::{
+        finite_difference::meshers::SpatialGrid, linear_operators::LinearOperator,
+        step_policy::StepPolicy,
+    },
+    traits::payoff::InitialConditions,
+    types::Real,
+};
+
+/// Step policy for exercise on a set of dates: steps are linear, and after the step
+/// reaching an exercise time each node is floored at `exercise(tau, location)`.
+///
+/// The exercise value takes the time to expiry, so it can change with time, as it
+/// must when the operator does not discount at the full short rate.
+pub struct BermudanPolicy<'a, T, SG, L, F> {
+    pub operator: &'a L,
+    /// Times to expiry of the exercise dates, which should fall on the time steps.
+    pub exercise_times: Vec<T>,
+    pub exercise: F,
+    _marker: PhantomData<SG>,
+}
+
+impl<'a, T, SG, L, F> BermudanPolicy<'a, T, SG, L, F>
+where
+    T: Real,
+    F: Fn(T, T) -> T,
+{
+    pub fn new(operator: &'a L, exercise_times: Vec<T>, exercise: F) -> Self {
+        Self {
+            operator,
+            exercise_times,
+            exercise,
+            _marker: PhantomData,
+        }
+    }
+
+    fn is_exercise_time(&self, tau: T) -> bool {
+        // Step times are accumulated, so allow for rounding
+        let tolerance = T::from_f64(1e-9) * tau.max(T::one());
+        self.exercise_times
+            .iter()
+            .any(|&time| (time - tau).abs() <= tolerance)
+    }
+}
+
+impl<'a, T, SG, L, F> StepPolicy<T, SG, L> for BermudanPolicy<'a, T, SG, L, F>
+where
+    T: Real,
+    SG: SpatialGrid<T>,
+    L: LinearOperator<T>,
+    F: Fn(T, T) -> T,
+{
+    fn solve_stage_into(
+        &self,
+        rhs: &[T],
+        _coeff: T,
+        _grid: &SG,
+        dest: &mut [T],
+        z_buffer: &mut [T],
+    ) {
+        self.operator.solve_inverse_into(rhs, dest, z_buffer);
+    }
+
+    fn compute_stage_derivative<IC>(
+        &self,
+        stage_slice: &[T],
+        _grid: &SG,
+        _initial_conditions: IC,
+        l_stage_slice: &mut [T],
+    ) where
+        IC: InitialConditions<T> + Copy,
+    {
+        self.operator.apply_into(stage_slice, l_stage_slice);
+    }
+
+    fn get_operator(&self) -> &L {
+        self.operator
+    }
+
+    fn end_step(&self, tau: T, grid: &SG, values: &mut [T]) {
+        if !self.is_exercise_time(tau) {
+            return;
+        }
+        for (i, value) in values.iter_mut().enumerate() {
+            *value = value.max((self.exercise)(tau, grid.location(i)));
+        }
+    }
+}
@@ -26,7 +26,14 @@ impl<'a, T, SG, L> LinearPolicy<'a, T, SG, L> {
 impl<'a, T: Real, SG: SpatialGrid<T>, L: LinearOperator<T>> StepPolicy<T, SG, L>
     for LinearPolicy<'a, T, SG, L>
 {
-    fn solve_stage_into(&self, rhs: &[T], _dt: T, _grid: &SG, dest: &mut [T], z_buffer: &mut [T]) {
+    fn solve_stage_into(
+        &self,
+        rhs: &[T],
+        _coeff: T,
+        _grid: &SG,
+        dest: &mut [T],
+        z_buffer: &mut [T],
+    ) {
         self.operator.solve_inverse_into(rhs, dest, z_buffer);
     }
 
@@ -1,12 +1,16 @@
 use crate::traits::payoff::InitialConditions;
 
+pub mod american_policy;
+pub mod bermudan_policy;
 pub mod linear_policy;
 pub mod unified_policy;
 
 pub trait StepPolicy<T, SG, L> {
     fn get_operator(&self) -> &L;
 
-    fn solve_stage_into(&self, rhs: &[T], dt: T, grid: &SG, dest: &mut [T], z_buffer: &mut [T]);
+    /// Solves the implicit stage `(I - coeff * L) x = rhs`, where `coeff` is the
+    /// stage's diagonal tableau entry times the step size.
+    fn solve_stage_into(&self, rhs: &[T], coeff: T, grid: &SG, dest: &mut [T], z_buffer: &mut [T]);
 
     fn compute_stage_derivative<IC>(
         &self,
@@ -16,4 +20,11 @@ pub trait StepPolicy<T, SG, L> {
         l_stage_slice: &mut [T],
     ) where
         IC: InitialConditions<T> + Copy;
+
+    /// Called before the first time step of every solve.
+    fn begin_solve(&self) {}
+
+    /// Called once a time step is complete, with the new time to expiry and the
+    /// solution at that time.
+    fn end_step(&self, _tau: T, _grid: &SG, _values: &mut [T]) {}
 }
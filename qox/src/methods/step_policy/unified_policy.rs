@@ -1,38 +1,39 @@
 use crate::{
     methods::{
+        complementarity::{ComplementaritySolver, projection::Projection},
+        constraints::{Constraint, none::NoConstraint},
         finite_difference::meshers::SpatialGrid,
         linear_operators::LinearOperator,
-        step_policy::{StepPolicy, linear_policy::LinearPolicy},
+        step_policy::{StepPolicy, american_policy::AmericanPolicy, linear_policy::LinearPolicy},
     },
     traits::payoff::InitialConditions,
     types::Real,
 };
-// Assuming AmericanPolicy is your other variant's type
-// use crate::methods::step_policy::american_policy::AmericanPolicy;
 
-pub enum UnifiedPolicy<'a, T, SG, L> {
+pub enum UnifiedPolicy<'a, T, SG, L, CS = Projection, C = NoConstraint> {
     Linear(LinearPolicy<'a, T, SG, L>),
-    // Replace with your actual American policy type
-    //American(AmericanPolicy<'a, T, SG, L>),
+    American(AmericanPolicy<'a, T, SG, L, CS, C>),
 }
 
-impl<'a, T, SG, L> StepPolicy<T, SG, L> for UnifiedPolicy<'a, T, SG, L>
+impl<'a, T, SG, L, CS, C> StepPolicy<T, SG, L> for UnifiedPolicy<'a, T, SG, L, CS, C>
 where
     T: Real,
     L: LinearOperator<T>,
     SG: SpatialGrid<T>,
+    CS: ComplementaritySolver<T, SG, L, C>,
+    C: Constraint<T, SG>,
 {
     fn get_operator(&self) -> &L {
         match self {
             Self::Linear(p) => p.get_operator(),
-            //Self::American(p) => p.get_operator(),
+            Self::American(p) => p.get_operator(),
         }
     }
 
-    fn solve_stage_into(&self, rhs: &[T], dt: T, grid: &SG, dest: &mut [T], z_buffer: &mut [T]) {
+    fn solve_stage_into(&self, rhs: &[T], coeff: T, grid: &SG, dest: &mut [T], z_buffer: &mut [T]) {
         match self {
-            Self::Linear(p) => p.solve_stage_into(rhs, dt, grid, dest, z_buffer),
-            //Self::American(p) => p.solve_stage_into(rhs, dt, grid, dest, z_buffer),
+            Self::Linear(p) => p.solve_stage_into(rhs, coeff, grid, dest, z_buffer),
+            Self::American(p) => p.solve_stage_into(rhs, coeff, grid, dest, z_buffer),
         }
     }
 
@@ -48,9 +49,24 @@ where
         match self {
             Self::Linear(p) => {
                 p.compute_stage_derivative(stage_slice, grid, initial_conditions, l_stage_slice)
-            } // Self::American(p) => {
-              //     p.compute_stage_derivative(stage_slice, grid, initial_conditions, l_stage_slice)
-              // }
+            }
+            Self::American(p) => {
+                p.compute_stage_derivative(stage_slice, grid, initial_conditions, l_stage_slice)
+            }
+        }
+    }
+
+    fn begin_solve(&self) {
+        match self {
+            Self::Linear(p) => p.begin_solve(),
+            Self::American(p) => p.begin_solve(),
+        }
+    }
+
+    fn end_step(&self, tau: T, grid: &SG, values: &mut [T]) {
+        match self {
+            Self::Linear(p) => p.end_step(tau, grid, values),
+            Self::American(p) => p.end_step(tau, grid, values),
         }
     }
 }
@@ -7,6 +7,20 @@ pub struct IdentityTransform<T> {
     _marker: PhantomData<T>,
 }
 
+impl<T: Real> IdentityTransform<T> {
+    pub fn new() -> Self {
+        Self {
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<T: Real> Default for IdentityTransform<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl<T: Real> Transform<T> for IdentityTransform<T> {
     fn to_physical(&self, x: T) -> T {
         x
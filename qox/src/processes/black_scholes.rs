@@ -1,25 +1,42 @@
 use crate::{
     core::period::DayCountConvention,
     methods::{
-        finite_difference::meshers::Mesher1d,
-        linear_operators::tridiagonal_operator::TridiagonalOperator, transforms::Transform,
+        boundary_conditions::linear::LinearBoundary,
+        finite_difference::{meshers::Mesher1d, stencils::fornberg_weights},
+        linear_operators::{
+            pentadiagonal_operator::PentadiagonalOperator,
+            tridiagonal_operator::TridiagonalOperator,
+        },
+        transforms::Transform,
     },
     processes::FdmProcess,
+    traits::boundary::{BoundaryCondition, BoundaryRow, BoundarySide, BoundaryStencil},
     types::Real,
 };
 
-pub struct BlackScholesProcess<'a, T: Real, Tr: Transform<T>> {
+pub struct BlackScholesProcess<
+    'a,
+    T: Real,
+    Tr: Transform<T>,
+    LB = LinearBoundary,
+    UB = LinearBoundary,
+> {
     pub r: T,
     pub sigma: T,
     pub transform: Tr,
     pub day_count_convention: DayCountConvention<'a>,
+    pub lower_boundary: LB,
+    pub upper_boundary: UB,
 }
 
-impl<'a, T, M, Tr> FdmProcess<T, TridiagonalOperator<T>, M, Tr> for BlackScholesProcess<'a, T, Tr>
+impl<'a, T, M, Tr, LB, UB> FdmProcess<T, TridiagonalOperator<T>, M, Tr>
+    for BlackScholesProcess<'a, T, Tr, LB, UB>
 where
     T: Real,
     M: Mesher1d<T>,
     Tr: Transform<T> + Copy,
+    LB: BoundaryCondition<T>,
+    UB: BoundaryCondition<T>,
 {
     fn build_operator(&self, mesher: &M) -> TridiagonalOperator<T> {
         let n = mesher.size();
@@ -32,39 +49,90 @@ where
         let mut upper = vec![T::zero(); n];
 
         for i in 1..n - 1 {
-            let xi = centers[i];
-            let hm = h_minus[i];
-            let hp = h_plus[i];
+            (lower[i], diag[i], upper[i]) = self.three_point_row(centers[i], h_minus[i], h_plus[i]);
+        }
+
+        // Boundary conditions
+        let (lower_row, upper_row) = self.boundary_rows(centers);
+        let mut source = vec![T::zero(); n];
+
+        diag[0] = lower_row.diag;
+        upper[0] = lower_row.off;
+        source[0] = lower_row.source;
+
+        diag[n - 1] = upper_row.diag;
+        lower[n - 1] = upper_row.off;
+        source[n - 1] = upper_row.source;
 
-            let j = self.transform.jacobian(xi);
-            let h = self.transform.hessian(xi);
-            let (a, b, c) = self.stencil(xi, j, h);
+        TridiagonalOperator::<T>::new(lower, diag, upper).with_source(source)
+    }
+
+    fn transform(&self) -> Tr {
+        self.transform
+    }
+}
+
+/// Fourth-order central differences on the five-point stencil. The rows next to the
+/// boundary fall back to the three-point scheme.
+impl<'a, T, M, Tr, LB, UB> FdmProcess<T, PentadiagonalOperator<T>, M, Tr>
+    for BlackScholesProcess<'a, T, Tr, LB, UB>
+where
+    T: Real,
+    M: Mesher1d<T>,
+    Tr: Transform<T> + Copy,
+    LB: BoundaryCondition<T>,
+    UB: BoundaryCondition<T>,
+{
+    fn build_operator(&self, mesher: &M) -> PentadiagonalOperator<T> {
+        let n = mesher.size();
+        let centers = mesher.centers();
+        let h_minus = mesher.h_minus();
+        let h_plus = mesher.h_plus();
+
+        let mut lower2 = vec![T::zero(); n];
+        let mut lower = vec![T::zero(); n];
+        let mut diag = vec![T::zero(); n];
+        let mut upper = vec![T::zero(); n];
+        let mut upper2 = vec![T::zero(); n];
 
-            // Weights for non-uniform finite differences
-            let denom = hm * hp * (hm + hp);
+        for i in [1, n - 2] {
+            (lower[i], diag[i], upper[i]) = self.three_point_row(centers[i], h_minus[i], h_plus[i]);
+        }
 
-            // Second derivative term
-            let a_lower = (T::from_f64(2.0) * hp) / denom;
-            let a_diag = (T::from_f64(-2.0) * (hm + hp)) / denom;
-            let a_upper = (T::from_f64(2.0) * hm) / denom;
+        for i in 2..n - 2 {
+            let xi = centers[i];
+            let (a, b, c) =
+                self.stencil(xi, self.transform.jacobian(xi), self.transform.hessian(xi));
 
-            // First derivative term
-            let b_lower = -(hp * hp) / denom;
-            let b_diag = (hp * hp - hm * hm) / denom;
-            let b_upper = (hm * hm) / denom;
+            let nodes = [
+                centers[i - 2],
+                centers[i - 1],
+                xi,
+                centers[i + 1],
+                centers[i + 2],
+            ];
+            let w = fornberg_weights(xi, &nodes);
 
-            lower[i] = a * a_lower + b * b_lower;
-            diag[i] = a * a_diag + b * b_diag + c;
-            upper[i] = a * a_upper + b * b_upper;
+            lower2[i] = a * w[2][0] + b * w[1][0];
+            lower[i] = a * w[2][1] + b * w[1][1];
+            diag[i] = a * w[2][2] + b * w[1][2] + c;
+            upper[i] = a * w[2][3] + b * w[1][3];
+            upper2[i] = a * w[2][4] + b * w[1][4];
         }
 
         // Boundary conditions
-        diag[0] = T::one();
-        upper[0] = T::zero();
-        lower[n - 1] = T::zero();
-        diag[n - 1] = T::one();
+        let (lower_row, upper_row) = self.boundary_rows(centers);
+        let mut source = vec![T::zero(); n];
 
-        TridiagonalOperator::<T>::new(lower, diag, upper)
+        diag[0] = lower_row.diag;
+        upper[0] = lower_row.off;
+        source[0] = lower_row.source;
+
+        diag[n - 1] = upper_row.diag;
+        lower[n - 1] = upper_row.off;
+        source[n - 1] = upper_row.source;
+
+        PentadiagonalOperator::<T>::new(lower2, lower, diag, upper, upper2).with_source(source)
     }
 
     fn transform(&self) -> Tr {
@@ -84,6 +152,87 @@ impl<'a, T: Real, Tr: Transform<T>> BlackScholesProcess<'a, T, Tr> {
             sigma: vol,
             transform,
             day_count_convention,
+            lower_boundary: LinearBoundary,
+            upper_boundary: LinearBoundary,
+        }
+    }
+}
+
+impl<'a, T: Real, Tr: Transform<T>, LB, UB> BlackScholesProcess<'a, T, Tr, LB, UB> {
+    pub fn with_boundaries<NLB, NUB>(
+        self,
+        lower_boundary: NLB,
+        upper_boundary: NUB,
+    ) -> BlackScholesProcess<'a, T, Tr, NLB, NUB> {
+        BlackScholesProcess {
+            r: self.r,
+            sigma: self.sigma,
+            transform: self.transform,
+            day_count_convention: self.day_count_convention,
+            lower_boundary,
+            upper_boundary,
+        }
+    }
+
+    /// Second-order (lower, diag, upper) coefficients at an interior node.
+    fn three_point_row(&self, xi: T, hm: T, hp: T) -> (T, T, T) {
+        let j = self.transform.jacobian(xi);
+        let h = self.transform.hessian(xi);
+        let (a, b, c) = self.stencil(xi, j, h);
+
+        // Weights for non-uniform finite differences
+        let denom = hm * hp * (hm + hp);
+
+        // Second derivative term
+        let a_lower = (T::from_f64(2.0) * hp) / denom;
+        let a_diag = (T::from_f64(-2.0) * (hm + hp)) / denom;
+        let a_upper = (T::from_f64(2.0) * hm) / denom;
+
+        // First derivative term
+        let b_lower = -(hp * hp) / denom;
+        let b_diag = (hp * hp - hm * hm) / denom;
+        let b_upper = (hm * hm) / denom;
+
+        (
+            a * a_lower + b * b_lower,
+            a * a_diag + b * b_diag + c,
+            a * a_upper + b * b_upper,
+        )
+    }
+
+    fn boundary_rows(&self, centers: &[T]) -> (BoundaryRow<T>, BoundaryRow<T>)
+    where
+        LB: BoundaryCondition<T>,
+        UB: BoundaryCondition<T>,
+    {
+        let n = centers.len();
+        let lower = self.lower_boundary.row(&self.boundary_stencil(
+            BoundarySide::Lower,
+            centers[0],
+            centers[1],
+        ));
+        let upper = self.upper_boundary.row(&self.boundary_stencil(
+            BoundarySide::Upper,
+            centers[n - 1],
+            centers[n - 2],
+        ));
+        (lower, upper)
+    }
+
+    fn boundary_stencil(&self, side: BoundarySide, xb: T, xn: T) -> BoundaryStencil<T> {
+        let j = self.transform.jacobian(xb);
+        let (a, b, c) = self.stencil(xb, j, self.transform.hessian(xb));
+        let s = self.transform.to_physical(xb);
+
+        BoundaryStencil {
+            side,
+            h: xb - xn,
+            spot: s,
+            jacobian: j,
+            diffusion: a,
+            convection: b,
+            drift: self.r * s / j,
+            reaction: c,
         }
     }
 
@@ -102,3 +251,14 @@ impl<'a, T: Real, Tr: Transform<T>> BlackScholesProcess<'a, T, Tr> {
         (a, b, c)
     }
 }
+
+/// Volatility that accrues `sigma`'s variance over `variance_time` when the process runs
+/// for `rate_time`, so rates stay on calendar time while volatility follows its own
+/// clock.
+pub fn rate_time_volatility<T: Real>(sigma: T, variance_time: T, rate_time: T) -> T {
+    if rate_time > T::zero() {
+        sigma * (variance_time / rate_time).sqrt()
+    } else {
+        sigma
+    }
+}
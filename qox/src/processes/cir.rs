@@ -0,0 +1,134 @@
+use crate::{
+    instruments::OptionType,
+    math::special::non_central_chi_squared_cdf,
+    methods::{
+        finite_difference::meshers::Mesher1d,
+        linear_operators::tridiagonal_operator::TridiagonalOperator,
+        transforms::identity::IdentityTransform,
+    },
+    processes::{
+        FdmProcess,
+        short_rate::{ShortRateModel, short_rate_operator},
+    },
+    traits::rate_curve::RateCurve,
+    types::Real,
+};
+
+/// Cox-Ingersoll-Ross short rate `dr = a (b - r) dt + sigma sqrt(r) dW`, started at `r0`.
+#[derive(Debug, Clone, Copy)]
+pub struct CirProcess<T: Real> {
+    /// Mean reversion speed.
+    pub a: T,
+    /// Long-run mean.
+    pub b: T,
+    pub sigma: T,
+    pub r0: T,
+}
+
+impl<T: Real> CirProcess<T> {
+    pub fn new(a: T, b: T, sigma: T, r0: T) -> Self {
+        Self { a, b, sigma, r0 }
+    }
+
+    /// `2 a b >= sigma^2`: the rate stays strictly positive.
+    pub fn feller_condition(&self) -> bool {
+        T::from_f64(2.0) * self.a * self.b >= self.sigma * self.sigma
+    }
+
+    fn h(&self) -> T {
+        (self.a * self.a + T::from_f64(2.0) * self.sigma * self.sigma).sqrt()
+    }
+
+    /// Bond price factors `(A(tau), B(tau))` with `P = A exp(-B r)`.
+    fn factors(&self, tau: T) -> (T, T) {
+        let two = T::from_f64(2.0);
+        let h = self.h();
+        let growth = (h * tau).exp() - T::one();
+        let denom = two * h + (self.a + h) * growth;
+
+        let a = (two * h * ((self.a + h) * tau / two).exp() / denom)
+            .powf(two * self.a * self.b / (self.sigma * self.sigma));
+        (a, two * growth / denom)
+    }
+
+    /// Price at `t` of the zero-coupon bond paying one at `maturity`, given `r(t) = r`.
+    pub fn zero_coupon_bond(&self, t: T, maturity: T, r: T) -> T {
+        let (a, b) = self.factors(maturity - t);
+        a * (-b * r).exp()
+    }
+
+    /// Price today of a European option expiring at `expiry` on the zero-coupon bond
+    /// maturing at `maturity`, from the non-central chi-squared law of `r(expiry)`.
+    pub fn bond_option(&self, option_type: OptionType, strike: T, expiry: T, maturity: T) -> T {
+        let two = T::from_f64(2.0);
+        let sigma_sq = self.sigma * self.sigma;
+        let h = self.h();
+
+        let (a_tail, b_tail) = self.factors(maturity - expiry);
+        // Rate at expiry above which the bond is worth less than the strike
+        let critical = (a_tail / strike).ln() / b_tail;
+
+        let rho = two * h / (sigma_sq * ((h * expiry).exp() - T::one()));
+        let psi = (self.a + h) / sigma_sq;
+        let dof = T::from_f64(4.0) * self.a * self.b / sigma_sq;
+        let spread = two * rho * rho * self.r0 * (h * expiry).exp();
+
+        let expiry_bond = self.discount_factor(expiry);
+        let maturity_bond = self.discount_factor(maturity);
+        let call = maturity_bond
+            * non_central_chi_squared_cdf(
+                two * critical * (rho + psi + b_tail),
+                dof,
+                spread / (rho + psi + b_tail),
+            )
+            - strike
+                * expiry_bond
+                * non_central_chi_squared_cdf(
+                    two * critical * (rho + psi),
+                    dof,
+                    spread / (rho + psi),
+                );
+
+        match option_type {
+            OptionType::Call => call,
+            OptionType::Put => call - maturity_bond + strike * expiry_bond,
+        }
+    }
+}
+
+impl<T: Real> ShortRateModel<T> for CirProcess<T> {
+    fn drift(&self, r: T) -> T {
+        self.a * (self.b - r)
+    }
+
+    fn variance(&self, r: T) -> T {
+        self.sigma * self.sigma * r.max(T::zero())
+    }
+}
+
+/// Model-implied curve from `r0`.
+impl<T: Real> RateCurve<T> for CirProcess<T> {
+    fn zero_rate(&self, t: T) -> T {
+        if t <= T::zero() {
+            return self.r0;
+        }
+        -self.discount_factor(t).ln() / t
+    }
+
+    fn discount_factor(&self, t: T) -> T {
+        self.zero_coupon_bond(T::zero(), t, self.r0)
+    }
+}
+
+/// The mesh should start at `r = 0`, where the diffusion vanishes.
+impl<T: Real, M: Mesher1d<T>> FdmProcess<T, TridiagonalOperator<T>, M, IdentityTransform<T>>
+    for CirProcess<T>
+{
+    fn transform(&self) -> IdentityTransform<T> {
+        IdentityTransform::new()
+    }
+
+    fn build_operator(&self, mesher: &M) -> TridiagonalOperator<T> {
+        short_rate_operator(self, mesher)
+    }
+}
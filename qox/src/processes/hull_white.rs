@@ -0,0 +1,143 @@
+use crate::{
+    instruments::OptionType,
+    methods::{
+        finite_difference::meshers::Mesher1d,
+        linear_operators::tridiagonal_operator::TridiagonalOperator,
+        transforms::identity::IdentityTransform,
+    },
+    processes::{
+        FdmProcess,
+        short_rate::{ShortRateModel, gaussian_bond_option, short_rate_operator},
+    },
+    traits::rate_curve::RateCurve,
+    types::Real,
+};
+
+/// One-factor Hull-White short rate `dr = (theta(t) - a r) dt + sigma dW`, with
+/// `theta` fitted so that the model reprices `curve`.
+///
+/// Written as `r(t) = x(t) + alpha(t)` with `dx = -a x dt + sigma dW` and `x(0) = 0`.
+#[derive(Debug, Clone)]
+pub struct HullWhiteProcess<T: Real, C: RateCurve<T>> {
+    /// Mean reversion speed.
+    pub a: T,
+    pub sigma: T,
+    curve: C,
+}
+
+impl<T: Real, C: RateCurve<T>> HullWhiteProcess<T, C> {
+    pub fn new(a: T, sigma: T, curve: C) -> Self {
+        Self { a, sigma, curve }
+    }
+
+    pub fn curve(&self) -> &C {
+        &self.curve
+    }
+
+    fn b_factor(&self, tau: T) -> T {
+        (T::one() - (-self.a * tau).exp()) / self.a
+    }
+
+    /// Fitted drift `theta(t) = df/dt + a f + sigma^2 / (2a) (1 - exp(-2at))`.
+    pub fn theta(&self, t: T) -> T {
+        let h = T::from_f64(1e-4);
+        let lo = (t - h).max(T::zero());
+        let hi = lo + h + h;
+        let slope = (self.curve.instantaneous_forward(hi) - self.curve.instantaneous_forward(lo))
+            / (hi - lo);
+
+        let two = T::from_f64(2.0);
+        slope
+            + self.a * self.curve.instantaneous_forward(t)
+            + self.sigma * self.sigma / (two * self.a) * (T::one() - (-two * self.a * t).exp())
+    }
+
+    /// Deterministic shift `alpha(t) = r(t) - x(t)`.
+    pub fn alpha(&self, t: T) -> T {
+        let two = T::from_f64(2.0);
+        let decay = T::one() - (-self.a * t).exp();
+        self.curve.instantaneous_forward(t)
+            + self.sigma * self.sigma / (two * self.a * self.a) * decay * decay
+    }
+
+    /// `exp(-integral of alpha from t to maturity)`: values rolled back on the `x` mesh of
+    /// the `FdmProcess` operator are multiplied by this to give prices in `r`.
+    pub fn shift_discount(&self, t: T, maturity: T) -> T {
+        let two = T::from_f64(2.0);
+        let a = self.a;
+        let decay = |s: T| (-a * s).exp();
+        let integral = maturity - t - two * (decay(t) - decay(maturity)) / a
+            + (decay(two * t) - decay(two * maturity)) / (two * a);
+
+        self.curve.discount_factor(maturity) / self.curve.discount_factor(t)
+            * (-self.sigma * self.sigma / (two * a * a) * integral).exp()
+    }
+
+    /// Value at `t` of exercising into `price(r)`, on the scale of values rolled back on
+    /// the `x` mesh from `maturity`: `price` at `r = x + alpha(t)`, divided by
+    /// `shift_discount(t, maturity)`. Early exercise must compare against this, not
+    /// against `price(x)`, since the rolled back values are not yet discounted at
+    /// `alpha` between `t` and `maturity`.
+    pub fn exercise_value(&self, t: T, maturity: T, x: T, price: impl Fn(T) -> T) -> T {
+        price(x + self.alpha(t)) / self.shift_discount(t, maturity)
+    }
+
+    /// Price at `t` of the zero-coupon bond paying one at `maturity`, given `r(t) = r`.
+    pub fn zero_coupon_bond(&self, t: T, maturity: T, r: T) -> T {
+        let two = T::from_f64(2.0);
+        let b = self.b_factor(maturity - t);
+        let market = self.curve.discount_factor(maturity) / self.curve.discount_factor(t);
+        let convexity = self.sigma * self.sigma / (two * two * self.a)
+            * (T::one() - (-two * self.a * t).exp())
+            * b
+            * b;
+
+        market * (b * (self.curve.instantaneous_forward(t) - r) - convexity).exp()
+    }
+
+    /// Price today of a European option expiring at `expiry` on the zero-coupon bond
+    /// maturing at `maturity`.
+    pub fn bond_option(&self, option_type: OptionType, strike: T, expiry: T, maturity: T) -> T {
+        let two = T::from_f64(2.0);
+        let variance = (T::one() - (-two * self.a * expiry).exp()) / (two * self.a);
+        let sigma_p = self.sigma * variance.sqrt() * self.b_factor(maturity - expiry);
+
+        gaussian_bond_option(
+            option_type,
+            strike,
+            self.curve.discount_factor(expiry),
+            self.curve.discount_factor(maturity),
+            sigma_p,
+        )
+    }
+}
+
+/// Dynamics of the state `x = r - alpha(t)`, which are time-homogeneous.
+impl<T: Real, C: RateCurve<T>> ShortRateModel<T> for HullWhiteProcess<T, C> {
+    fn drift(&self, x: T) -> T {
+        -self.a * x
+    }
+
+    fn variance(&self, _x: T) -> T {
+        self.sigma * self.sigma
+    }
+}
+
+/// Operator on the `x` mesh, discounting at `x` only. Rolled back values times
+/// `shift_discount(t, maturity)` are prices; early exercise rescales the exercise value
+/// with `exercise_value` instead, e.g. in a `BermudanPolicy`.
+impl<T, C, M> FdmProcess<T, TridiagonalOperator<T>, M, IdentityTransform<T>>
+    for HullWhiteProcess<T, C>
+where
+    T: Real,
+    C: RateCurve<T>,
+    M: Mesher1d<T>,
+{
+    fn transform(&self) -> IdentityTransform<T> {
+        IdentityTransform::new()
+    }
+
+    fn build_operator(&self, mesher: &M) -> TridiagonalOperator<T> {
+        short_rate_operator(self, mesher)
+    }
+}
@@ -7,6 +7,10 @@ use crate::{
 };
 
 pub mod black_scholes;
+pub mod cir;
+pub mod hull_white;
+pub mod short_rate;
+pub mod vasicek;
 
 pub trait FdmProcess<T: Real, L: LinearOperator<T>, M: Mesher1d<T>, Tr: Transform<T> + Copy> {
     fn transform(&self) -> Tr;
@@ -0,0 +1,105 @@
+use crate::{
+    instruments::OptionType,
+    methods::{
+        boundary_conditions::linear::LinearBoundary, finite_difference::meshers::Mesher1d,
+        linear_operators::tridiagonal_operator::TridiagonalOperator,
+    },
+    traits::boundary::{BoundaryCondition, BoundarySide, BoundaryStencil},
+    types::Real,
+};
+
+/// One-factor short-rate dynamics `dx = drift(x) dt + sqrt(variance(x)) dW`, with
+/// the short rate a function of the state `x`.
+pub trait ShortRateModel<T: Real> {
+    fn drift(&self, x: T) -> T;
+    fn variance(&self, x: T) -> T;
+
+    /// Rate discounted at in state `x`.
+    fn short_rate(&self, x: T) -> T {
+        x
+    }
+}
+
+/// Pricing operator `dV/dtau = variance / 2 * V_xx + drift * V_x - r * V` on an
+/// identity-transform mesh, with zero-gamma rows at both ends.
+pub(crate) fn short_rate_operator<T, S, M>(model: &S, mesher: &M) -> TridiagonalOperator<T>
+where
+    T: Real,
+    S: ShortRateModel<T>,
+    M: Mesher1d<T>,
+{
+    let n = mesher.size();
+    let centers = mesher.centers();
+    let h_minus = mesher.h_minus();
+    let h_plus = mesher.h_plus();
+    let two = T::from_f64(2.0);
+    let coefficients = |x: T| {
+        (
+            model.variance(x) / two,
+            model.drift(x),
+            -model.short_rate(x),
+        )
+    };
+
+    let mut lower = vec![T::zero(); n];
+    let mut diag = vec![T::zero(); n];
+    let mut upper = vec![T::zero(); n];
+
+    for i in 1..n - 1 {
+        let (a, b, c) = coefficients(centers[i]);
+        let (hm, hp) = (h_minus[i], h_plus[i]);
+        let denom = hm * hp * (hm + hp);
+
+        lower[i] = (a * two * hp - b * hp * hp) / denom;
+        diag[i] = (-a * two * (hm + hp) + b * (hp * hp - hm * hm)) / denom + c;
+        upper[i] = (a * two * hm + b * hm * hm) / denom;
+    }
+
+    let stencil = |side, xb: T, xn: T| {
+        let (a, b, c) = coefficients(xb);
+        BoundaryStencil {
+            side,
+            h: xb - xn,
+            spot: xb,
+            jacobian: T::one(),
+            diffusion: a,
+            convection: b,
+            drift: b,
+            reaction: c,
+        }
+    };
+    let lower_row = LinearBoundary.row(&stencil(BoundarySide::Lower, centers[0], centers[1]));
+    let upper_row = LinearBoundary.row(&stencil(
+        BoundarySide::Upper,
+        centers[n - 1],
+        centers[n - 2],
+    ));
+
+    diag[0] = lower_row.diag;
+    upper[0] = lower_row.off;
+    diag[n - 1] = upper_row.diag;
+    lower[n - 1] = upper_row.off;
+
+    TridiagonalOperator::<T>::new(lower, diag, upper)
+}
+
+/// Option on a zero-coupon bond when the bond's log price at expiry is normal with
+/// standard deviation `sigma_p` (Jamshidian).
+pub(crate) fn gaussian_bond_option<T: Real>(
+    option_type: OptionType,
+    strike: T,
+    expiry_bond: T,
+    maturity_bond: T,
+    sigma_p: T,
+) -> T {
+    let h = (maturity_bond / (expiry_bond * strike)).ln() / sigma_p + sigma_p / T::from_f64(2.0);
+
+    match option_type {
+        OptionType::Call => {
+            maturity_bond * h.norm_cdf() - strike * expiry_bond * (h - sigma_p).norm_cdf()
+        }
+        OptionType::Put => {
+            strike * expiry_bond * (sigma_p - h).norm_cdf() - maturity_bond * (-h).norm_cdf()
+        }
+    }
+}
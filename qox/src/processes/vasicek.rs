@@ -0,0 +1,114 @@
+use crate::{
+    instruments::OptionType,
+    methods::{
+        finite_difference::meshers::Mesher1d,
+        linear_operators::tridiagonal_operator::TridiagonalOperator,
+        transforms::identity::IdentityTransform,
+    },
+    processes::{
+        FdmProcess,
+        short_rate::{ShortRateModel, gaussian_bond_option, short_rate_operator},
+    },
+    traits::rate_curve::RateCurve,
+    types::Real,
+};
+
+/// Vasicek short rate `dr = a (b - r) dt + sigma dW`, started at `r0`.
+#[derive(Debug, Clone, Copy)]
+pub struct VasicekProcess<T: Real> {
+    /// Mean reversion speed.
+    pub a: T,
+    /// Long-run mean.
+    pub b: T,
+    pub sigma: T,
+    pub r0: T,
+}
+
+impl<T: Real> VasicekProcess<T> {
+    pub fn new(a: T, b: T, sigma: T, r0: T) -> Self {
+        Self { a, b, sigma, r0 }
+    }
+
+    fn b_factor(&self, tau: T) -> T {
+        (T::one() - (-self.a * tau).exp()) / self.a
+    }
+
+    fn ln_a_factor(&self, tau: T) -> T {
+        let b = self.b_factor(tau);
+        let sigma_sq = self.sigma * self.sigma;
+        let two = T::from_f64(2.0);
+
+        (self.b - sigma_sq / (two * self.a * self.a)) * (b - tau)
+            - sigma_sq * b * b / (two * two * self.a)
+    }
+
+    /// Price at `t` of the zero-coupon bond paying one at `maturity`, given `r(t) = r`.
+    pub fn zero_coupon_bond(&self, t: T, maturity: T, r: T) -> T {
+        let tau = maturity - t;
+        (self.ln_a_factor(tau) - self.b_factor(tau) * r).exp()
+    }
+
+    /// Price today of a European option expiring at `expiry` on the zero-coupon bond
+    /// maturing at `maturity`.
+    pub fn bond_option(&self, option_type: OptionType, strike: T, expiry: T, maturity: T) -> T {
+        let two = T::from_f64(2.0);
+        let variance = (T::one() - (-two * self.a * expiry).exp()) / (two * self.a);
+        let sigma_p = self.sigma * variance.sqrt() * self.b_factor(maturity - expiry);
+
+        gaussian_bond_option(
+            option_type,
+            strike,
+            self.discount_factor(expiry),
+            self.discount_factor(maturity),
+            sigma_p,
+        )
+    }
+}
+
+impl<T: Real> ShortRateModel<T> for VasicekProcess<T> {
+    fn drift(&self, r: T) -> T {
+        self.a * (self.b - r)
+    }
+
+    fn variance(&self, _r: T) -> T {
+        self.sigma * self.sigma
+    }
+}
+
+/// Model-implied curve from `r0`.
+impl<T: Real> RateCurve<T> for VasicekProcess<T> {
+    fn zero_rate(&self, t: T) -> T {
+        if t <= T::zero() {
+            return self.r0;
+        }
+        -self.discount_factor(t).ln() / t
+    }
+
+    fn discount_factor(&self, t: T) -> T {
+        self.zero_coupon_bond(T::zero(), t, self.r0)
+    }
+
+    fn instantaneous_forward(&self, t: T) -> T {
+        let two = T::from_f64(2.0);
+        let sigma_sq = self.sigma * self.sigma;
+        let b = self.b_factor(t);
+        let decay = (-self.a * t).exp();
+
+        // -d/dt of ln A(t) - B(t) r0, with B' = exp(-a t)
+        (self.b - sigma_sq / (two * self.a * self.a)) * (T::one() - decay)
+            + sigma_sq / (two * self.a) * b * decay
+            + decay * self.r0
+    }
+}
+
+impl<T: Real, M: Mesher1d<T>> FdmProcess<T, TridiagonalOperator<T>, M, IdentityTransform<T>>
+    for VasicekProcess<T>
+{
+    fn transform(&self) -> IdentityTransform<T> {
+        IdentityTransform::new()
+    }
+
+    fn build_operator(&self, mesher: &M) -> TridiagonalOperator<T> {
+        short_rate_operator(self, mesher)
+    }
+}
@@ -1,6 +1,37 @@
 use crate::types::Real;
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BoundarySide {
+    Lower,
+    Upper,
+}
+
+/// PDE coefficients at a boundary node, expressed in the mesh coordinate `x`:
+/// `dV/dtau = diffusion * V_xx + convection * V_x + reaction * V`.
+#[derive(Debug, Clone, Copy)]
+pub struct BoundaryStencil<T> {
+    pub side: BoundarySide,
+    /// Signed distance `x_b - x_n` from the boundary node to its interior neighbour.
+    pub h: T,
+    /// Physical location of the boundary node.
+    pub spot: T,
+    /// `dS/dx` at the boundary node.
+    pub jacobian: T,
+    pub diffusion: T,
+    pub convection: T,
+    /// Convection left over once the physical second derivative vanishes (zero gamma).
+    pub drift: T,
+    pub reaction: T,
+}
+
+/// Operator row at a boundary node: `dV_b/dtau = diag * V_b + off * V_n + source`.
+#[derive(Debug, Clone, Copy)]
+pub struct BoundaryRow<T> {
+    pub diag: T,
+    pub off: T,
+    pub source: T,
+}
+
 pub trait BoundaryCondition<T: Real> {
-    fn apply_lower(&self, t: &T) -> T;
-    fn apply_upper(&self, t: &T, s_max: &T) -> T;
+    fn row(&self, stencil: &BoundaryStencil<T>) -> BoundaryRow<T>;
 }
@@ -1,15 +1,40 @@
-use chrono::{Datelike, NaiveDate, Weekday};
+use chrono::{Datelike, Days, Months, NaiveDate, Weekday};
 use crate::core::conventions::BusinessDayConvention;
 use crate::core::tenor::Tenor;
 
 pub trait Calendar: std::fmt::Debug + Send + Sync {
     fn name(&self) -> &str;
     fn is_holiday(&self, _date: NaiveDate) -> bool { false }
+
+    fn is_weekend(&self, date: NaiveDate) -> bool {
+        let wd = date.weekday();
+        wd == Weekday::Sat || wd == Weekday::Sun
+    }
     
     fn is_business_day(&self, date: NaiveDate) -> bool {
-        let wd = date.weekday();
-        let is_weekend = wd == Weekday::Sat || wd == Weekday::Sun;
-        !is_weekend && !self.is_holiday(date)
+        !self.is_weekend(date) && !self.is_holiday(date)
+    }
+
+    /// Holidays in `[start, end]` that fall outside the weekend.
+    fn holiday_list(&self, start: NaiveDate, end: NaiveDate) -> Vec<NaiveDate> {
+        start
+            .iter_days()
+            .take_while(|&d| d <= end)
+            .filter(|&d| !self.is_weekend(d) && self.is_holiday(d))
+            .collect()
+    }
+
+    /// Business days in `[start, end)`, or zero if `end` is not after `start`.
+    ///
+    /// Checks every day in turn; wrap the calendar in a
+    /// [`CachedCalendar`](crate::core::calendars::cached::CachedCalendar) to count long
+    /// periods repeatedly.
+    fn business_days_between(&self, start: NaiveDate, end: NaiveDate) -> i64 {
+        start
+            .iter_days()
+            .take_while(|&d| d < end)
+            .filter(|&d| self.is_business_day(d))
+            .count() as i64
     }
 
     fn advance_business_days(&self, mut date: NaiveDate, n: i32) -> NaiveDate 
@@ -29,14 +54,39 @@ pub trait Calendar: std::fmt::Debug + Send + Sync {
         date
     }
 
+    /// Whether `date` is on or after the last business day of its month.
+    fn is_end_of_month(&self, date: NaiveDate) -> bool {
+        let next = date.succ_opt().expect("Date overflow");
+        let next = BusinessDayConvention::Following.adjust(next, self);
+        next.month() != date.month()
+    }
+
+    /// Last business day of the month containing `date`.
+    fn end_of_month(&self, date: NaiveDate) -> NaiveDate {
+        let last = date.with_day(1).unwrap() + Months::new(1) - Days::new(1);
+        BusinessDayConvention::Preceding.adjust(last, self)
+    }
+
+    /// Advances `date` by `period` and adjusts the result. Under the end-of-month rule a
+    /// month or year period starting at month end lands on the end of the target month:
+    /// the last business day, or the last calendar day if the period is unadjusted.
     fn advance_period(
         &self,
         date: NaiveDate,
         period: Tenor,
         convention: BusinessDayConvention,
-        _is_eom: bool,
+        is_eom: bool,
     ) -> NaiveDate {
         let raw_date = period.advance(date); // Use the Period's own advance logic
+        if is_eom && matches!(period, Tenor::Months(_) | Tenor::Years(_)) {
+            if convention == BusinessDayConvention::Unadjusted {
+                if date.succ_opt().is_some_and(|next| next.month() != date.month()) {
+                    return raw_date.with_day(1).unwrap() + Months::new(1) - Days::new(1);
+                }
+            } else if self.is_end_of_month(date) {
+                return self.end_of_month(raw_date);
+            }
+        }
         convention.adjust(raw_date, self)
     }
 }
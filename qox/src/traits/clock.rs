@@ -0,0 +1,7 @@
+use chrono::{DateTime, Utc};
+
+/// Measures elapsed time between two instants in years.
+pub trait Clock {
+    /// Years from `start` to `end`, negative if `end` is before `start`.
+    fn year_fraction(&self, start: DateTime<Utc>, end: DateTime<Utc>) -> f64;
+}
@@ -1,15 +1,75 @@
+use chrono::{DateTime, NaiveDate, Utc};
+
 use crate::{
-    traits::{rate_curve::RateCurve, vol_surface::VolSurface},
+    core::{clock::ActualClock, error::CurveError, period::DayCountConvention},
+    traits::{clock::Clock, rate_curve::RateCurve, vol_surface::VolSurface},
     types::Real,
 };
 
 pub trait MarketView<T: Real, RC: RateCurve<T>> {
     fn spot_price(&self) -> T;
     fn rate_curve(&self) -> &RC;
+
+    /// Instant the market is observed at. Instruments measure time to expiry from
+    /// here rather than from the wall clock, so prices are reproducible.
+    fn evaluation_time(&self) -> DateTime<Utc>;
+
+    fn evaluation_date(&self) -> NaiveDate {
+        self.evaluation_time().date_naive()
+    }
+
+    /// Rate curve time runs from the curve's own reference date, not the evaluation
+    /// date, so the two must agree for discounting to the evaluation date. Curves
+    /// without a date are assumed to start on the evaluation date.
+    fn check_reference_date(&self) -> Result<(), CurveError> {
+        check_reference_date(self.rate_curve(), self.evaluation_date())
+    }
 }
 
 pub trait OptionMarketView<T: Real, RC: RateCurve<T>, VS: VolSurface<T>>:
     MarketView<T, RC>
 {
     fn vol_surface(&self) -> &VS;
+
+    /// Years of variance from the evaluation time to `expiry`, the volatility time of
+    /// an option expiring then. Calendar time unless the market has a variance clock.
+    fn variance_time(&self, expiry: DateTime<Utc>) -> T {
+        T::from_f64(ActualClock.year_fraction(self.evaluation_time(), expiry))
+    }
+}
+
+/// Multi-curve rates market: one curve for discounting and one projection curve per
+/// floating index, all in the same curve time.
+pub trait RatesMarketView<'a, T: Real, D: RateCurve<T>, P: RateCurve<T>> {
+    /// Date `time` measures from. The curves keep their own reference dates; see
+    /// `check_reference_dates`.
+    fn reference_date(&self) -> NaiveDate;
+    fn discount_curve(&self) -> &D;
+    fn projection_curve(&self, index: &str) -> Option<&P>;
+
+    /// Curve time of `date`.
+    fn time(&self, date: NaiveDate) -> T;
+
+    fn year_fraction(
+        &self,
+        start: NaiveDate,
+        end: NaiveDate,
+        convention: DayCountConvention<'a>,
+    ) -> T;
+
+    /// Checks that the discount curve starts on `reference_date`, as curve times from
+    /// `time` assume. Curves without a date are assumed to.
+    fn check_reference_dates(&self) -> Result<(), CurveError> {
+        check_reference_date(self.discount_curve(), self.reference_date())
+    }
+}
+
+pub(crate) fn check_reference_date<T: Real>(
+    curve: &impl RateCurve<T>,
+    market: NaiveDate,
+) -> Result<(), CurveError> {
+    match curve.reference_date() {
+        Some(curve) if curve != market => Err(CurveError::ReferenceDateMismatch { curve, market }),
+        _ => Ok(()),
+    }
 }
@@ -1,5 +1,6 @@
 pub mod boundary;
 pub mod calendar;
+pub mod clock;
 pub mod instrument;
 pub mod market_view;
 pub mod payoff;
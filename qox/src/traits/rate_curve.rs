@@ -1,6 +1,163 @@
+use chrono::NaiveDate;
 
+use crate::core::period::{DayCountConvention, PeriodCalculator};
+use crate::core::rate::{Compounding, Frequency, InterestRate};
+use crate::types::Real;
 
-pub trait RateCurve<T> {
+/// Discounting in curve time `t`, in years from the curve's reference date.
+///
+/// Only `zero_rate` and `discount_factor` are required; the forward and par rate
+/// queries are derived from discount factors and can be overridden where a curve
+/// has them in closed form.
+pub trait RateCurve<T: Real> {
     fn zero_rate(&self, t: T) -> T;
     fn discount_factor(&self, t: T) -> T;
-}
\ No newline at end of file
+
+    /// Date curve time zero falls on, for curves built from dates; `None` for curves
+    /// defined in time only.
+    fn reference_date(&self) -> Option<NaiveDate> {
+        None
+    }
+
+    /// Curve time of `date`, measured from the reference date on the curve's own day
+    /// count; `None` for curves defined in time only.
+    fn curve_time(&self, _date: NaiveDate) -> Option<T> {
+        None
+    }
+
+    /// Instantaneous forward rate `-d ln DF / dt`, by central difference.
+    fn instantaneous_forward(&self, t: T) -> T {
+        let h = T::from_f64(1e-5);
+        let lo = (t - h).max(T::zero());
+        let hi = lo + h + h;
+        (self.discount_factor(lo).ln() - self.discount_factor(hi).ln()) / (hi - lo)
+    }
+
+    /// Forward rate over `[t1, t2]` with the given compounding, accruing over `t2 - t1`.
+    fn forward_rate(&self, t1: T, t2: T, compounding: Compounding, frequency: Frequency) -> T {
+        let df = self.discount_factor(t2) / self.discount_factor(t1);
+        InterestRate::implied_rate(
+            df,
+            t2 - t1,
+            compounding,
+            frequency,
+            DayCountConvention::Actual365Fixed,
+        )
+        .value
+    }
+
+    /// Forward rate between two dates, accruing under `convention`.
+    ///
+    /// Curve times come from `curve_time`, so they follow the curve's own day count
+    /// whatever the accrual convention.
+    ///
+    /// # Panics
+    ///
+    /// If the curve has no reference date.
+    fn forward_rate_between<'a>(
+        &self,
+        start: NaiveDate,
+        end: NaiveDate,
+        convention: DayCountConvention<'a>,
+        compounding: Compounding,
+        frequency: Frequency,
+        calculator: &dyn PeriodCalculator<'a>,
+    ) -> InterestRate<'a, T> {
+        let time = |date| {
+            self.curve_time(date)
+                .expect("date queries need a curve with a reference date")
+        };
+        let tau = T::from_f64(calculator.year_fraction(start, end, convention).0);
+
+        let df = self.discount_factor(time(end)) / self.discount_factor(time(start));
+        InterestRate::implied_rate(df, tau, compounding, frequency, convention)
+    }
+
+    /// Par rate of a fixed leg starting at `start` and paying `accruals[i]` at
+    /// `payment_times[i]`, against a floating leg worth `DF(start) - DF(end)`.
+    ///
+    /// # Panics
+    ///
+    /// If there are no payments. `payment_times` and `accruals` must have the same
+    /// length, which is only checked in debug builds.
+    fn par_rate(&self, start: T, payment_times: &[T], accruals: &[T]) -> T {
+        debug_assert_eq!(
+            payment_times.len(),
+            accruals.len(),
+            "one accrual per payment"
+        );
+        let end = *payment_times
+            .last()
+            .expect("par rate needs at least one payment");
+
+        let annuity = payment_times
+            .iter()
+            .zip(accruals)
+            .fold(T::zero(), |acc, (&t, &tau)| {
+                acc + tau * self.discount_factor(t)
+            });
+
+        (self.discount_factor(start) - self.discount_factor(end)) / annuity
+    }
+
+    /// Par rate for a schedule of dates: the first date is the start, every later one
+    /// a fixed payment accruing from the previous date under `convention`. Curve times
+    /// come from `curve_time`.
+    ///
+    /// # Panics
+    ///
+    /// If `schedule` has fewer than two dates, or the curve has no reference date.
+    fn par_rate_for_schedule<'a>(
+        &self,
+        schedule: &[NaiveDate],
+        convention: DayCountConvention<'a>,
+        calculator: &dyn PeriodCalculator<'a>,
+    ) -> T {
+        assert!(
+            schedule.len() >= 2,
+            "schedule needs a start and a payment date"
+        );
+        let time = |date| {
+            self.curve_time(date)
+                .expect("date queries need a curve with a reference date")
+        };
+
+        let payment_times: Vec<T> = schedule[1..].iter().map(|&d| time(d)).collect();
+        let accruals: Vec<T> = schedule
+            .windows(2)
+            .map(|w| T::from_f64(calculator.year_fraction(w[0], w[1], convention).0))
+            .collect();
+
+        self.par_rate(time(schedule[0]), &payment_times, &accruals)
+    }
+}
+
+impl<T: Real, C: RateCurve<T> + ?Sized> RateCurve<T> for &C {
+    fn zero_rate(&self, t: T) -> T {
+        (**self).zero_rate(t)
+    }
+
+    fn discount_factor(&self, t: T) -> T {
+        (**self).discount_factor(t)
+    }
+
+    fn reference_date(&self) -> Option<NaiveDate> {
+        (**self).reference_date()
+    }
+
+    fn curve_time(&self, date: NaiveDate) -> Option<T> {
+        (**self).curve_time(date)
+    }
+
+    fn instantaneous_forward(&self, t: T) -> T {
+        (**self).instantaneous_forward(t)
+    }
+
+    fn forward_rate(&self, t1: T, t2: T, compounding: Compounding, frequency: Frequency) -> T {
+        (**self).forward_rate(t1, t2, compounding, frequency)
+    }
+
+    fn par_rate(&self, start: T, payment_times: &[T], accruals: &[T]) -> T {
+        (**self).par_rate(start, payment_times, accruals)
+    }
+}
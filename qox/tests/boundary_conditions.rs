@@ -0,0 +1,122 @@
+use qox::core::period::DayCountConvention;
+use qox::evaluators::black_scholes::finite_difference::VanillaPayoff;
+use qox::instruments::OptionType;
+use qox::methods::analytic::black_scholes::black_scholes;
+use qox::methods::boundary_conditions::dirichlet::DirichletBoundary;
+use qox::methods::boundary_conditions::linear::LinearBoundary;
+use qox::methods::boundary_conditions::neumann::NeumannBoundary;
+use qox::methods::finite_difference::meshers::uniform::UniformMesher1d;
+use qox::methods::finite_difference::solver::{FdmConfig, Solver};
+use qox::methods::linear_operators::tridiagonal_operator::TridiagonalOperator;
+use qox::methods::step_policy::linear_policy::LinearPolicy;
+use qox::methods::time_stepping::butcher_jackiewicz2::ButcherJackiewicz2;
+use qox::methods::time_stepping::input_vectors::InputVector;
+use qox::methods::transforms::log::LogTransform;
+use qox::processes::FdmProcess;
+use qox::processes::black_scholes::BlackScholesProcess;
+use qox::traits::boundary::BoundaryCondition;
+use qox::traits::payoff::PayoffAsInitialConditions;
+
+const SPOT: f64 = 100.0;
+const STRIKE: f64 = 100.0;
+const RATE: f64 = 0.05;
+const VOL: f64 = 0.2;
+const EXPIRY: f64 = 1.0;
+
+/// Prices on a deliberately narrow mesh so the wings drive the result.
+fn price<LB, UB>(option_type: OptionType, lower: LB, upper: UB) -> f64
+where
+    LB: BoundaryCondition<f64>,
+    UB: BoundaryCondition<f64>,
+{
+    let config = FdmConfig {
+        nodes: 400,
+        time_steps: 100,
+    };
+    let solver = Solver { config };
+    let transform = LogTransform::new();
+    let mesher = UniformMesher1d::new(
+        (SPOT / 1.6_f64).ln(),
+        (SPOT * 1.6_f64).ln(),
+        config.nodes,
+        transform,
+    );
+    let process =
+        BlackScholesProcess::new(RATE, VOL, transform, DayCountConvention::Actual365Fixed)
+            .with_boundaries(lower, upper);
+    let operator: TridiagonalOperator<f64> = process.build_operator(&mesher);
+    let policy = LinearPolicy::new(&operator);
+    let initial_conditions = PayoffAsInitialConditions::new(VanillaPayoff {
+        strike: STRIKE,
+        option_type,
+    });
+
+    let vector = solver.solve(
+        ButcherJackiewicz2::new(),
+        initial_conditions,
+        &mesher,
+        EXPIRY / config.time_steps as f64,
+        &policy,
+    );
+    solver.interpolate(&mesher, vector.step_slice(0), SPOT)
+}
+
+fn analytic(option_type: OptionType) -> f64 {
+    black_scholes(
+        SPOT,
+        STRIKE,
+        EXPIRY,
+        RATE,
+        VOL,
+        option_type == OptionType::Call,
+    )
+}
+
+#[test]
+fn dirichlet_discounted_intrinsic_prices_calls_and_puts() {
+    for option_type in [OptionType::Call, OptionType::Put] {
+        let bc = DirichletBoundary::DiscountedIntrinsic(option_type);
+        let fd = price(option_type, bc, bc);
+        assert!((fd - analytic(option_type)).abs() < 1e-3);
+    }
+}
+
+#[test]
+fn neumann_delta_prices_calls_and_puts() {
+    let call = price(
+        OptionType::Call,
+        NeumannBoundary::new(0.0),
+        NeumannBoundary::new(1.0),
+    );
+    let put = price(
+        OptionType::Put,
+        NeumannBoundary::new(-1.0),
+        NeumannBoundary::new(0.0),
+    );
+
+    assert!((call - analytic(OptionType::Call)).abs() < 1e-3);
+    assert!((put - analytic(OptionType::Put)).abs() < 1e-3);
+}
+
+#[test]
+fn linear_boundary_prices_calls_and_puts() {
+    for option_type in [OptionType::Call, OptionType::Put] {
+        let fd = price(option_type, LinearBoundary, LinearBoundary);
+        assert!((fd - analytic(option_type)).abs() < 1e-3);
+    }
+}
+
+#[test]
+fn frozen_wings_miss_the_price_on_a_narrow_mesh() {
+    for option_type in [OptionType::Call, OptionType::Put] {
+        let frozen = price(
+            option_type,
+            DirichletBoundary::Fixed,
+            DirichletBoundary::Fixed,
+        );
+        let linear = price(option_type, LinearBoundary, LinearBoundary);
+
+        let expected = analytic(option_type);
+        assert!((frozen - expected).abs() > 10.0 * (linear - expected).abs());
+    }
+}
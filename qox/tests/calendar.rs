@@ -0,0 +1,447 @@
+use chrono::{NaiveDate, Weekday};
+use qox::core::calendars::bespoke::BespokeCalendar;
+use qox::core::calendars::cached::CachedCalendar;
+use qox::core::calendars::easter_sunday;
+use qox::core::calendars::japan::Japan;
+use qox::core::calendars::joint::{JointCalendar, JointCalendarRule};
+use qox::core::calendars::modified::ModifiedCalendar;
+use qox::core::calendars::target::Target;
+use qox::core::calendars::united_kingdom::UnitedKingdom;
+use qox::core::calendars::united_states::UnitedStates;
+use qox::core::calendars::weekends_only::WeekendsOnly;
+use qox::core::conventions::BusinessDayConvention;
+use qox::core::error::CalendarError;
+use qox::core::tenor::Tenor;
+use qox::traits::calendar::Calendar;
+
+fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(y, m, d).unwrap()
+}
+
+fn holidays(calendar: &dyn Calendar, year: i32) -> Vec<(u32, u32)> {
+    use chrono::Datelike;
+    calendar
+        .holiday_list(date(year, 1, 1), date(year, 12, 31))
+        .into_iter()
+        .map(|d| (d.month(), d.day()))
+        .collect()
+}
+
+#[test]
+fn easter_sundays() {
+    let expected = [
+        (1961, 4, 2),
+        (1999, 4, 4),
+        (2000, 4, 23),
+        (2008, 3, 23),
+        (2011, 4, 24),
+        (2019, 4, 21),
+        (2024, 3, 31),
+        (2025, 4, 20),
+        (2038, 4, 25),
+    ];
+    for (y, m, d) in expected {
+        assert_eq!(easter_sunday(y), date(y, m, d));
+    }
+}
+
+#[test]
+fn nyse_holidays() {
+    let nyse = UnitedStates::Nyse;
+    assert_eq!(
+        holidays(&nyse, 2024),
+        [
+            (1, 1),
+            (1, 15),
+            (2, 19),
+            (3, 29),
+            (5, 27),
+            (6, 19),
+            (7, 4),
+            (9, 2),
+            (11, 28),
+            (12, 25)
+        ]
+    );
+    // Carter's funeral, and no Friday close for the Saturday New Year of 2022
+    assert_eq!(
+        holidays(&nyse, 2025),
+        [
+            (1, 1),
+            (1, 9),
+            (1, 20),
+            (2, 17),
+            (4, 18),
+            (5, 26),
+            (6, 19),
+            (7, 4),
+            (9, 1),
+            (11, 27),
+            (12, 25)
+        ]
+    );
+    assert!(nyse.is_business_day(date(2021, 12, 31)));
+    assert!(!nyse.is_business_day(date(2021, 12, 24)));
+    assert!(!nyse.is_business_day(date(2023, 1, 2)));
+
+    for closed in [date(2001, 9, 13), date(2012, 10, 30), date(2018, 12, 5)] {
+        assert!(!nyse.is_business_day(closed));
+    }
+}
+
+#[test]
+fn us_settlement_holidays() {
+    let settlement = UnitedStates::Settlement;
+    assert_eq!(
+        holidays(&settlement, 2024),
+        [
+            (1, 1),
+            (1, 15),
+            (2, 19),
+            (5, 27),
+            (6, 19),
+            (7, 4),
+            (9, 2),
+            (10, 14),
+            (11, 11),
+            (11, 28),
+            (12, 25)
+        ]
+    );
+    // Saturday New Year's Day 2022 is observed on Friday 31 December 2021
+    assert!(!settlement.is_business_day(date(2021, 12, 31)));
+    assert!(settlement.is_business_day(date(2024, 3, 29)));
+}
+
+#[test]
+fn target_holidays() {
+    for year in 2002..=2030 {
+        let list = holidays(&Target, year);
+        let easter = easter_sunday(year);
+        let good_friday = easter - chrono::Days::new(2);
+        let easter_monday = easter + chrono::Days::new(1);
+
+        use chrono::Datelike;
+        let mut expected = vec![
+            (1, 1),
+            (good_friday.month(), good_friday.day()),
+            (easter_monday.month(), easter_monday.day()),
+            (5, 1),
+            (12, 25),
+            (12, 26),
+        ];
+        expected.retain(|&(m, d)| {
+            let day = date(year, m, d);
+            !Target.is_weekend(day)
+        });
+        assert_eq!(list, expected, "{}", year);
+    }
+    assert!(!Target.is_business_day(date(2001, 12, 31)));
+}
+
+#[test]
+fn uk_bank_holidays() {
+    assert_eq!(
+        holidays(&UnitedKingdom, 2024),
+        [
+            (1, 1),
+            (3, 29),
+            (4, 1),
+            (5, 6),
+            (5, 27),
+            (8, 26),
+            (12, 25),
+            (12, 26)
+        ]
+    );
+    // Platinum Jubilee, the Queen's funeral and a Sunday Christmas
+    assert_eq!(
+        holidays(&UnitedKingdom, 2022),
+        [
+            (1, 3),
+            (4, 15),
+            (4, 18),
+            (5, 2),
+            (6, 2),
+            (6, 3),
+            (8, 29),
+            (9, 19),
+            (12, 26),
+            (12, 27)
+        ]
+    );
+    assert_eq!(
+        holidays(&UnitedKingdom, 2020),
+        [
+            (1, 1),
+            (4, 10),
+            (4, 13),
+            (5, 8),
+            (5, 25),
+            (8, 31),
+            (12, 25),
+            (12, 28)
+        ]
+    );
+}
+
+#[test]
+fn japan_holidays() {
+    assert_eq!(
+        holidays(&Japan, 2024),
+        [
+            (1, 1),
+            (1, 2),
+            (1, 3),
+            (1, 8),
+            (2, 12),
+            (2, 23),
+            (3, 20),
+            (4, 29),
+            (5, 3),
+            (5, 6),
+            (7, 15),
+            (8, 12),
+            (9, 16),
+            (9, 23),
+            (10, 14),
+            (11, 4),
+            (12, 31)
+        ]
+    );
+    // Imperial transition week, and the Olympic moves of 2020
+    assert_eq!(
+        holidays(&Japan, 2019)[..12],
+        [
+            (1, 1),
+            (1, 2),
+            (1, 3),
+            (1, 14),
+            (2, 11),
+            (3, 21),
+            (4, 29),
+            (4, 30),
+            (5, 1),
+            (5, 2),
+            (5, 3),
+            (5, 6)
+        ]
+    );
+    for holiday in [date(2020, 7, 23), date(2020, 7, 24), date(2020, 8, 10)] {
+        assert!(Japan.is_holiday(holiday));
+    }
+    // Citizens' holiday between Respect for the Aged Day and the autumnal equinox
+    assert!(Japan.is_holiday(date(2015, 9, 22)));
+    assert!(Japan.is_holiday(date(2026, 9, 22)));
+}
+
+#[test]
+fn adjustment_uses_the_calendar() {
+    // Good Friday 2024 is a TARGET holiday: following rolls over Easter Monday
+    let adjusted = BusinessDayConvention::Following.adjust(date(2024, 3, 29), &Target);
+    assert_eq!(adjusted, date(2024, 4, 2));
+
+    let advanced = UnitedStates::Nyse.advance_business_days(date(2024, 7, 3), 1);
+    assert_eq!(advanced, date(2024, 7, 5));
+}
+
+#[test]
+fn joint_calendars_combine_members() {
+    let members =
+        || -> Vec<Box<dyn Calendar>> { vec![Box::new(Target), Box::new(UnitedStates::Settlement)] };
+    let any = JointCalendar::new(members(), JointCalendarRule::JoinHolidays);
+    let all = JointCalendar::new(members(), JointCalendarRule::JoinBusinessDays);
+
+    // Independence Day, Good Friday and Christmas in 2024
+    for (d, any_open, all_open) in [
+        (date(2024, 7, 4), false, true),
+        (date(2024, 3, 29), false, true),
+        (date(2024, 12, 25), false, false),
+        (date(2024, 7, 6), false, false),
+        (date(2024, 7, 5), true, true),
+    ] {
+        assert_eq!(any.is_business_day(d), any_open, "{}", d);
+        assert_eq!(all.is_business_day(d), all_open, "{}", d);
+    }
+    assert_eq!(any.name(), "JoinHolidays(TARGET, US settlement)");
+
+    // A Friday-Saturday weekend member closes Fridays on the joint calendar
+    let gulf = BespokeCalendar::new("Gulf", []).with_weekend([Weekday::Fri, Weekday::Sat]);
+    let joint = JointCalendar::new(
+        vec![Box::new(WeekendsOnly), Box::new(gulf)],
+        JointCalendarRule::JoinHolidays,
+    );
+    assert!(!joint.is_business_day(date(2024, 7, 5)));
+    assert!(!joint.is_business_day(date(2024, 7, 7)));
+    assert!(joint.is_business_day(date(2024, 7, 8)));
+}
+
+#[test]
+fn bespoke_calendar_from_file() {
+    let path = std::env::temp_dir().join("qox_bespoke_calendar.txt");
+    std::fs::write(&path, "# exchange closures\n2024-01-02\n\n2024-03-15\n").unwrap();
+    let calendar = BespokeCalendar::from_file("Exchange", &path).unwrap();
+    assert_eq!(
+        calendar.holiday_list(date(2024, 1, 1), date(2024, 12, 31)),
+        [date(2024, 1, 2), date(2024, 3, 15)]
+    );
+
+    std::fs::write(&path, "2024-01-02\n2024-13-01\n").unwrap();
+    let error = BespokeCalendar::from_file("Exchange", &path).unwrap_err();
+    assert!(matches!(error, CalendarError::InvalidDate { line: 2, .. }));
+    std::fs::remove_file(&path).unwrap();
+
+    let mut calendar = BespokeCalendar::new("Exchange", [date(2024, 1, 2)]);
+    calendar.add_holiday(date(2024, 1, 3));
+    calendar.remove_holiday(date(2024, 1, 2));
+    assert!(calendar.is_business_day(date(2024, 1, 2)));
+    assert!(!calendar.is_business_day(date(2024, 1, 3)));
+    assert!(!WeekendsOnly.is_business_day(date(2024, 1, 6)));
+}
+
+#[test]
+fn modified_calendar_injects_closures() {
+    let mut nyse = ModifiedCalendar::new(UnitedStates::Nyse);
+    nyse.add_holiday(date(2024, 7, 5));
+    nyse.remove_holiday(date(2024, 7, 4));
+    nyse.remove_holiday(date(2024, 7, 6));
+
+    assert!(!nyse.is_business_day(date(2024, 7, 5)));
+    assert!(nyse.is_business_day(date(2024, 7, 4)));
+    assert!(nyse.is_business_day(date(2024, 7, 6)));
+    assert_eq!(
+        nyse.advance_business_days(date(2024, 7, 3), 3),
+        date(2024, 7, 8)
+    );
+
+    nyse.add_holiday(date(2024, 7, 4));
+    assert!(!nyse.is_business_day(date(2024, 7, 4)));
+}
+
+#[test]
+fn end_of_month_rule_in_advance_period() {
+    use BusinessDayConvention::*;
+    let all = [Following, Preceding, ModifiedFollowing, ModifiedPreceding];
+    let advance =
+        |start, tenor, convention, eom| Target.advance_period(start, tenor, convention, eom);
+
+    // Month-end starts land on the last business day of the target month whatever the
+    // convention; 2024-03-29 is Good Friday
+    for convention in all {
+        for (start, tenor, end) in [
+            (date(2024, 1, 31), Tenor::Months(1), date(2024, 2, 29)),
+            (date(2023, 1, 31), Tenor::Months(1), date(2023, 2, 28)),
+            (date(2023, 2, 28), Tenor::Months(1), date(2023, 3, 31)),
+            (date(2024, 2, 29), Tenor::Months(1), date(2024, 3, 28)),
+            (date(2024, 5, 31), Tenor::Months(1), date(2024, 6, 28)),
+            (date(2024, 6, 28), Tenor::Months(1), date(2024, 7, 31)),
+            (date(2023, 2, 28), Tenor::Years(1), date(2024, 2, 29)),
+            (date(2024, 2, 29), Tenor::Months(-1), date(2024, 1, 31)),
+        ] {
+            assert_eq!(
+                advance(start, tenor, convention, true),
+                end,
+                "{:?}",
+                convention
+            );
+        }
+    }
+
+    // Without the rule the raw date is adjusted by the convention
+    let start = date(2024, 2, 29);
+    assert_eq!(
+        advance(start, Tenor::Months(1), Following, false),
+        date(2024, 4, 2)
+    );
+    assert_eq!(
+        advance(start, Tenor::Months(1), Preceding, false),
+        date(2024, 3, 28)
+    );
+    assert_eq!(
+        advance(start, Tenor::Months(1), ModifiedFollowing, false),
+        date(2024, 3, 28)
+    );
+    assert_eq!(
+        advance(start, Tenor::Months(1), ModifiedPreceding, false),
+        date(2024, 3, 28)
+    );
+    assert_eq!(
+        advance(start, Tenor::Months(1), Unadjusted, false),
+        date(2024, 3, 29)
+    );
+    assert_eq!(
+        advance(date(2024, 6, 28), Tenor::Months(1), Following, false),
+        date(2024, 7, 29)
+    );
+
+    // Unadjusted rolls calendar month ends, and only those
+    assert_eq!(
+        advance(date(2023, 2, 28), Tenor::Months(1), Unadjusted, true),
+        date(2023, 3, 31)
+    );
+    assert_eq!(
+        advance(date(2024, 2, 29), Tenor::Years(1), Unadjusted, true),
+        date(2025, 2, 28)
+    );
+    assert_eq!(
+        advance(date(2024, 2, 28), Tenor::Months(1), Unadjusted, true),
+        date(2024, 3, 28)
+    );
+    assert_eq!(
+        advance(date(2024, 6, 28), Tenor::Months(1), Unadjusted, true),
+        date(2024, 7, 28)
+    );
+
+    // Day and week periods and mid-month starts ignore the rule
+    assert_eq!(
+        advance(date(2024, 4, 30), Tenor::Weeks(1), Following, true),
+        date(2024, 5, 7)
+    );
+    assert_eq!(
+        advance(date(2024, 4, 15), Tenor::Months(1), Following, true),
+        date(2024, 5, 15)
+    );
+}
+
+fn assert_cached_matches<C: Calendar + Clone>(calendar: C) {
+    let cached = CachedCalendar::new(calendar.clone());
+    let mut start = date(1999, 12, 27);
+    while start < date(2031, 1, 1) {
+        for days in [0, 1, 2, 5, 40, 366, 800, 4000] {
+            let end = start + chrono::Days::new(days);
+            assert_eq!(
+                cached.business_days_between(start, end),
+                calendar.business_days_between(start, end),
+                "{} from {} to {}",
+                calendar.name(),
+                start,
+                end
+            );
+        }
+        assert_eq!(cached.business_days_between(start, date(1990, 1, 1)), 0);
+
+        for n in [-700, -253, -20, -1, 0, 1, 3, 22, 252, 1500] {
+            assert_eq!(
+                cached.advance_business_days(start, n),
+                calendar.advance_business_days(start, n),
+                "{} from {} by {}",
+                calendar.name(),
+                start,
+                n
+            );
+        }
+        assert_eq!(cached.is_business_day(start), calendar.is_business_day(start));
+        start = start + chrono::Days::new(97);
+    }
+}
+
+#[test]
+fn cached_calendar_matches_day_by_day_counting() {
+    assert_cached_matches(Target);
+    assert_cached_matches(Japan);
+
+    let mut modified = ModifiedCalendar::new(UnitedStates::Nyse);
+    modified.add_holiday(date(2012, 10, 30));
+    modified.remove_holiday(date(2020, 2, 29));
+    assert_cached_matches(modified);
+}
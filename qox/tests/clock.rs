@@ -0,0 +1,244 @@
+use chrono::{
+    DateTime, Datelike, Days, FixedOffset, LocalResult, NaiveDate, NaiveDateTime, NaiveTime,
+    TimeZone, Utc, Weekday,
+};
+use qox::core::calendars::united_states::UnitedStates;
+use qox::core::clock::{ActualClock, TradingClock, TradingHours, VarianceClock};
+use qox::core::period::DayCountConvention;
+use qox::instruments::stock_option::{ExerciseStyle, StockOption};
+use qox::instruments::{OptionInstrument, OptionType};
+use qox::market::market_frame::OptionMarketFrame;
+use qox::market::rate_curve::ContinuousRateCurve;
+use qox::market::vol_surface::FlatVolSurface;
+use qox::methods::analytic::black_scholes::black_scholes;
+use qox::traits::clock::Clock;
+use qox::traits::market_view::OptionMarketView;
+
+/// US Eastern time: UTC-5, and UTC-4 from 2:00 on the second Sunday of March to 2:00
+/// on the first Sunday of November.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct NewYork;
+
+impl NewYork {
+    fn standard() -> FixedOffset {
+        FixedOffset::west_opt(5 * 3600).unwrap()
+    }
+
+    fn daylight() -> FixedOffset {
+        FixedOffset::west_opt(4 * 3600).unwrap()
+    }
+
+    fn offset_at(utc: &NaiveDateTime) -> FixedOffset {
+        let sunday = |month, week| {
+            NaiveDate::from_weekday_of_month_opt(utc.year(), month, Weekday::Sun, week).unwrap()
+        };
+        let start = sunday(3, 2).and_hms_opt(7, 0, 0).unwrap();
+        let end = sunday(11, 1).and_hms_opt(6, 0, 0).unwrap();
+        if start <= *utc && *utc < end {
+            Self::daylight()
+        } else {
+            Self::standard()
+        }
+    }
+}
+
+impl TimeZone for NewYork {
+    type Offset = FixedOffset;
+
+    fn from_offset(_offset: &FixedOffset) -> Self {
+        NewYork
+    }
+
+    fn offset_from_local_date(&self, local: &NaiveDate) -> LocalResult<FixedOffset> {
+        self.offset_from_local_datetime(&local.and_time(NaiveTime::MIN))
+    }
+
+    fn offset_from_local_datetime(&self, local: &NaiveDateTime) -> LocalResult<FixedOffset> {
+        let valid = |offset: FixedOffset| Self::offset_at(&(*local - offset)) == offset;
+        match (valid(Self::daylight()), valid(Self::standard())) {
+            (true, true) => LocalResult::Ambiguous(Self::daylight(), Self::standard()),
+            (true, false) => LocalResult::Single(Self::daylight()),
+            (false, true) => LocalResult::Single(Self::standard()),
+            (false, false) => LocalResult::None,
+        }
+    }
+
+    fn offset_from_utc_date(&self, utc: &NaiveDate) -> FixedOffset {
+        Self::offset_at(&utc.and_time(NaiveTime::MIN))
+    }
+
+    fn offset_from_utc_datetime(&self, utc: &NaiveDateTime) -> FixedOffset {
+        Self::offset_at(utc)
+    }
+}
+
+/// New York local time.
+fn ny(y: i32, m: u32, d: u32, h: u32, min: u32) -> DateTime<Utc> {
+    NewYork
+        .with_ymd_and_hms(y, m, d, h, min, 0)
+        .unwrap()
+        .with_timezone(&Utc)
+}
+
+fn nyse_hours<Tz: TimeZone>(timezone: Tz) -> TradingHours<Tz> {
+    TradingHours::new(
+        NaiveTime::from_hms_opt(9, 30, 0).unwrap(),
+        NaiveTime::from_hms_opt(16, 0, 0).unwrap(),
+        timezone,
+    )
+}
+
+fn nyse_clock() -> TradingClock<UnitedStates, NewYork> {
+    TradingClock::new(UnitedStates::Nyse, nyse_hours(NewYork))
+}
+
+fn assert_close(actual: f64, expected: f64) {
+    assert!(
+        (actual - expected).abs() < 1e-12,
+        "{} vs {}",
+        actual,
+        expected
+    );
+}
+
+#[test]
+fn actual_clock_is_intraday() {
+    let t = ActualClock.year_fraction(ny(2024, 3, 15, 10, 0), ny(2024, 3, 15, 16, 0));
+    assert_close(t, 0.25 / 365.0);
+    let back = ActualClock.year_fraction(ny(2024, 3, 15, 16, 0), ny(2024, 3, 15, 10, 0));
+    assert_close(back, -t);
+}
+
+#[test]
+fn trading_clock_counts_open_hours_only() {
+    let clock = nyse_clock();
+    let session = 6.5;
+
+    // Same-day expiry at the close
+    let t = clock.year_fraction(ny(2024, 3, 15, 10, 0), ny(2024, 3, 15, 16, 0));
+    assert_close(t, 6.0 / session / 252.0);
+
+    // Friday afternoon to Monday morning skips the weekend
+    let t = clock.year_fraction(ny(2024, 3, 15, 12, 45), ny(2024, 3, 18, 10, 0));
+    assert_close(t, (3.25 + 0.5) / session / 252.0);
+
+    // Independence Day is closed
+    let t = clock.year_fraction(ny(2024, 7, 3, 15, 0), ny(2024, 7, 5, 10, 0));
+    assert_close(t, 1.5 / session / 252.0);
+
+    // Nothing accrues while the market is shut
+    let t = clock.year_fraction(ny(2024, 3, 15, 16, 0), ny(2024, 3, 18, 9, 30));
+    assert_close(t, 0.0);
+}
+
+#[test]
+fn sessions_follow_daylight_saving_time() {
+    let clock = nyse_clock();
+
+    // The first session after clocks go forward opens at 13:30 UTC, not 14:30
+    let (open, close) = (ny(2024, 3, 11, 9, 30), ny(2024, 3, 11, 16, 0));
+    assert_eq!(open, Utc.with_ymd_and_hms(2024, 3, 11, 13, 30, 0).unwrap());
+    assert_close(clock.year_fraction(open, close), 1.0 / 252.0);
+    assert_close(
+        clock.year_fraction(ny(2024, 11, 1, 9, 30), ny(2024, 11, 4, 16, 0)),
+        2.0 / 252.0,
+    );
+
+    // A fixed winter offset reads the summer session an hour early
+    let fixed = TradingClock::new(UnitedStates::Nyse, nyse_hours(NewYork::standard()));
+    assert_close(fixed.year_fraction(open, close), 5.5 / 6.5 / 252.0);
+
+    // The 23-hour Sunday still counts as one whole closed day
+    let weekend = VarianceClock::new(clock).with_weekend_weight(0.25);
+    let (saturday, monday) = (ny(2024, 3, 9, 0, 0), ny(2024, 3, 11, 0, 0));
+    assert_eq!((monday - saturday).num_hours(), 47);
+    assert_close(
+        weekend.year_fraction(saturday, monday),
+        0.5 / weekend.annual_weight(),
+    );
+    let sunday = saturday + Days::new(1);
+    assert_close(
+        weekend.year_fraction(sunday, monday),
+        0.25 / weekend.annual_weight(),
+    );
+}
+
+#[test]
+fn variance_clock_weights_nights_weekends_and_events() {
+    let trading = nyse_clock();
+    let plain = VarianceClock::new(trading);
+    let (start, end) = (ny(2024, 3, 14, 11, 0), ny(2024, 3, 19, 15, 0));
+    assert_close(
+        plain.year_fraction(start, end),
+        trading.year_fraction(start, end),
+    );
+
+    let clock = plain.with_overnight_weight(0.2).with_weekend_weight(0.1);
+    let annual = 252.0 * 1.2 + 113.0 * 0.1;
+    assert_close(clock.annual_weight(), annual);
+
+    // Friday close to Monday close: two halves of a night, a weekend and a session
+    let (friday, monday) = (ny(2024, 3, 15, 16, 0), ny(2024, 3, 18, 16, 0));
+    assert_close(
+        clock.year_fraction(friday, monday),
+        (0.2 + 0.1 + 0.1 + 1.0) / annual,
+    );
+
+    let earnings = clock.with_event(NaiveDate::from_ymd_opt(2024, 3, 18).unwrap(), 3.0);
+    assert_close(
+        earnings.year_fraction(friday, monday),
+        (0.2 + 0.1 + 0.1 + 3.0) / annual,
+    );
+}
+
+#[test]
+fn variance_time_drives_volatility_and_calendar_time_drives_rates() {
+    let evaluation_time = ny(2024, 3, 11, 10, 0);
+    let expiry = ny(2024, 3, 22, 16, 0);
+    let option = StockOption::new(100.0, expiry, OptionType::Call, ExerciseStyle::European);
+    let clock = VarianceClock::new(nyse_clock())
+        .with_overnight_weight(0.1)
+        .with_weekend_weight(0.05);
+
+    let (spot, rate, vol) = (100.0, 0.05, 0.3);
+    let market = OptionMarketFrame::new(
+        evaluation_time.date_naive(),
+        spot,
+        ContinuousRateCurve::new(rate, DayCountConvention::Actual365Fixed),
+        FlatVolSurface::new(vol),
+    )
+    .with_evaluation_time(evaluation_time)
+    .with_variance_clock(clock.clone());
+
+    let rate_time: f64 = option.years_to_expiry(evaluation_time);
+    let variance_time: f64 = market.variance_time(expiry);
+    assert_close(variance_time, clock.year_fraction(evaluation_time, expiry));
+    assert!(variance_time != rate_time);
+
+    // Same as running calendar time at the vol that accrues the clock's variance
+    let price: f64 = option.evaluate(&market);
+    let effective_vol = vol * (variance_time / rate_time).sqrt();
+    let calendar_market = OptionMarketFrame::new(
+        evaluation_time.date_naive(),
+        spot,
+        ContinuousRateCurve::new(rate, DayCountConvention::Actual365Fixed),
+        FlatVolSurface::new(effective_vol),
+    )
+    .with_evaluation_time(evaluation_time);
+    assert_close(price, option.evaluate(&calendar_market));
+
+    let expected = black_scholes(spot, 100.0, rate_time, rate, effective_vol, true);
+    assert!(
+        (price / expected - 1.0).abs() < 1e-2,
+        "{} vs {}",
+        price,
+        expected
+    );
+
+    // An earnings release before expiry adds variance
+    let event = market
+        .clone()
+        .with_variance_clock(clock.with_event(NaiveDate::from_ymd_opt(2024, 3, 20).unwrap(), 4.0));
+    let event_price: f64 = option.evaluate(&event);
+    assert!(event_price > price + 0.1);
+}
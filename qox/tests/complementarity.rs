@@ -0,0 +1,191 @@
+use qox::core::period::DayCountConvention;
+use qox::evaluators::black_scholes::finite_difference::VanillaPayoff;
+use qox::instruments::OptionType;
+use qox::methods::complementarity::ComplementaritySolver;
+use qox::methods::complementarity::penalty::Penalty;
+use qox::methods::complementarity::policy_iteration::PolicyIteration;
+use qox::methods::complementarity::psor::Psor;
+use qox::methods::constraints::american::AmericanConstraint;
+use qox::methods::constraints::double_obstacle::DoubleObstacleConstraint;
+use qox::methods::finite_difference::meshers::uniform::UniformMesher1d;
+use qox::methods::linear_operators::tridiagonal_operator::TridiagonalOperator;
+use qox::methods::transforms::log::LogTransform;
+use qox::processes::FdmProcess;
+use qox::processes::black_scholes::BlackScholesProcess;
+use qox::traits::payoff::{InitialConditions, PayoffAsInitialConditions};
+
+const STRIKE: f64 = 100.0;
+const RATE: f64 = 0.05;
+const VOL: f64 = 0.2;
+
+type Mesher = UniformMesher1d<f64, LogTransform<f64>>;
+
+fn setup(nodes: usize) -> (Mesher, TridiagonalOperator<f64>) {
+    let transform = LogTransform::new();
+    let mesher = UniformMesher1d::new(1.0_f64.ln(), 500.0_f64.ln(), nodes, transform);
+    let process =
+        BlackScholesProcess::new(RATE, VOL, transform, DayCountConvention::Actual365Fixed);
+    let operator = process.build_operator(&mesher);
+    (mesher, operator)
+}
+
+/// Exercise value of a long straddle: both wings are in the money, so the
+/// continuation region is an interval around the strike.
+#[derive(Clone, Copy)]
+struct Straddle;
+
+impl InitialConditions<f64> for Straddle {
+    fn get_value(self, spot: f64) -> f64 {
+        (spot - STRIKE).abs()
+    }
+}
+
+/// Issuer call at a fixed price, which the holder answers by exercising if that
+/// is worth more.
+#[derive(Clone, Copy)]
+struct CallPrice(f64);
+
+impl InitialConditions<f64> for CallPrice {
+    fn get_value(self, spot: f64) -> f64 {
+        self.0.max(spot - STRIKE)
+    }
+}
+
+fn reference_psor() -> Psor {
+    Psor {
+        omega: 1.5,
+        tolerance: 1e-13,
+        max_iter: 200_000,
+    }
+}
+
+/// Implicit Euler march of an American put, one LCP per step.
+fn american_put<S>(solver: &S) -> f64
+where
+    S: ComplementaritySolver<
+            f64,
+            Mesher,
+            TridiagonalOperator<f64>,
+            AmericanConstraint<PayoffAsInitialConditions<f64, VanillaPayoff>>,
+        >,
+{
+    let (mesher, operator) = setup(400);
+    let payoff = PayoffAsInitialConditions::new(VanillaPayoff {
+        strike: STRIKE,
+        option_type: OptionType::Put,
+    });
+    let constraint = AmericanConstraint::new(payoff);
+
+    let steps = 500;
+    let dt = 1.0 / steps as f64;
+    let mut v: Vec<f64> = (0..400)
+        .map(|i| payoff.get_value(mesher.centers[i].exp()))
+        .collect();
+    let mut next = v.clone();
+
+    for _ in 0..steps {
+        solver.solve(&operator, &v, dt, &constraint, &mesher, &mut next);
+        v.copy_from_slice(&next);
+    }
+
+    let x = STRIKE.ln();
+    let i = mesher.centers.partition_point(|&c| c < x) - 1;
+    let w = (x - mesher.centers[i]) / (mesher.centers[i + 1] - mesher.centers[i]);
+    v[i] + w * (v[i + 1] - v[i])
+}
+
+#[test]
+fn penalty_and_policy_iteration_price_the_american_put() {
+    // Binomial reference for S = K = 100, r = 5%, vol = 20%, one year
+    let expected = 6.0896;
+
+    let penalty = Penalty::new(1e-10, 100);
+    let policy = PolicyIteration::new(1e-12, 100);
+
+    assert!((american_put(&penalty) - expected).abs() < 1e-2);
+    assert!((american_put(&policy) - expected).abs() < 1e-2);
+
+    for stats in [penalty.stats(), policy.stats()] {
+        assert_eq!(stats.solves, 500);
+        assert_eq!(stats.failures, 0);
+        assert!(stats.total_iterations < 10 * stats.solves);
+    }
+}
+
+#[test]
+fn solvers_agree_on_a_straddle_obstacle() {
+    let nodes = 200;
+    let (mesher, operator) = setup(nodes);
+    let constraint = AmericanConstraint::new(Straddle);
+    let kappa = 0.25;
+
+    let rhs: Vec<f64> = (0..nodes)
+        .map(|i| Straddle.get_value(mesher.centers[i].exp()) * 0.9 + 1.0)
+        .collect();
+
+    let mut reference = rhs.clone();
+    reference_psor().solve(&operator, &rhs, kappa, &constraint, &mesher, &mut reference);
+
+    let mut penalized = rhs.clone();
+    Penalty::new(1e-10, 100).solve(&operator, &rhs, kappa, &constraint, &mesher, &mut penalized);
+
+    let mut policy = rhs.clone();
+    PolicyIteration::new(1e-12, 100).solve(
+        &operator,
+        &rhs,
+        kappa,
+        &constraint,
+        &mesher,
+        &mut policy,
+    );
+
+    for i in 0..nodes {
+        assert!((penalized[i] - reference[i]).abs() < 1e-6, "node {}", i);
+        assert!((policy[i] - reference[i]).abs() < 1e-6, "node {}", i);
+    }
+}
+
+#[test]
+fn callable_cap_is_respected() {
+    let nodes = 200;
+    let (mesher, operator) = setup(nodes);
+    let floor = PayoffAsInitialConditions::new(VanillaPayoff {
+        strike: STRIKE,
+        option_type: OptionType::Call,
+    });
+    let cap = CallPrice(40.0);
+    let constraint = DoubleObstacleConstraint::new(floor, cap);
+    let kappa = 0.25;
+
+    // Deep in the money the continuation value would exceed the call price
+    let rhs: Vec<f64> = (0..nodes)
+        .map(|i| floor.get_value(mesher.centers[i].exp()) + 5.0)
+        .collect();
+
+    let mut reference = rhs.clone();
+    reference_psor().solve(&operator, &rhs, kappa, &constraint, &mesher, &mut reference);
+
+    let mut penalized = rhs.clone();
+    Penalty::new(1e-10, 100).solve(&operator, &rhs, kappa, &constraint, &mesher, &mut penalized);
+
+    let mut policy = rhs.clone();
+    PolicyIteration::new(1e-12, 100).solve(
+        &operator,
+        &rhs,
+        kappa,
+        &constraint,
+        &mesher,
+        &mut policy,
+    );
+
+    for i in 0..nodes {
+        let s = mesher.centers[i].exp();
+        let g = floor.get_value(s);
+        let u = cap.get_value(s);
+
+        assert!(policy[i] <= u + 1e-9 && policy[i] >= g - 1e-9);
+        assert!((penalized[i] - reference[i]).abs() < 1e-6, "node {}", i);
+        assert!((policy[i] - reference[i]).abs() < 1e-6, "node {}", i);
+    }
+    assert!(policy.iter().any(|&v| (v - 40.0).abs() < 1e-9));
+}
@@ -0,0 +1,111 @@
+use chrono::NaiveDate;
+use qox::core::calendars::united_states::UnitedStates;
+use qox::core::date_rules::*;
+use qox::core::error::DateRuleError;
+use qox::core::tenor::Tenor;
+
+fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(y, m, d).unwrap()
+}
+
+#[test]
+fn imm_dates_and_codes() {
+    let main_cycle = [
+        (date(2025, 3, 19), "H5"),
+        (date(2025, 6, 18), "M5"),
+        (date(2025, 9, 17), "U5"),
+        (date(2025, 12, 17), "Z5"),
+    ];
+    for (imm, code) in main_cycle {
+        assert!(is_imm_date(imm, true));
+        assert_eq!(imm_code(imm).as_deref(), Some(code));
+        assert_eq!(imm_date_from_code(code, date(2025, 1, 10)).unwrap(), imm);
+    }
+    for window in main_cycle.windows(2) {
+        assert_eq!(next_imm_date(window[0].0, true), window[1].0);
+        assert_eq!(previous_imm_date(window[1].0, true), window[0].0);
+    }
+
+    assert_eq!(next_imm_date(date(2025, 1, 1), false), date(2025, 1, 15));
+    assert_eq!(next_imm_date(date(2025, 1, 1), true), date(2025, 3, 19));
+    assert_eq!(
+        previous_imm_date(date(2025, 3, 19), true),
+        date(2024, 12, 18)
+    );
+    assert!(is_imm_date(date(2025, 1, 15), false) && !is_imm_date(date(2025, 1, 15), true));
+    assert_eq!(imm_code(date(2025, 3, 18)), None);
+
+    // Codes only name the year within a decade: the next matching date is used
+    assert_eq!(
+        imm_date_from_code("z4", date(2025, 1, 10)).unwrap(),
+        date(2034, 12, 20)
+    );
+    for bad in ["", "H", "A5", "H55", "HX"] {
+        assert!(matches!(
+            imm_date_from_code(bad, date(2025, 1, 10)),
+            Err(DateRuleError::InvalidImmCode(_))
+        ));
+    }
+}
+
+#[test]
+fn asx_dates() {
+    assert!(is_asx_date(date(2025, 3, 14), true));
+    assert!(!is_asx_date(date(2025, 3, 21), true));
+    assert_eq!(next_asx_date(date(2025, 3, 14), true), date(2025, 6, 13));
+    assert_eq!(next_asx_date(date(2025, 3, 14), false), date(2025, 4, 11));
+    assert_eq!(
+        previous_asx_date(date(2025, 3, 14), true),
+        date(2024, 12, 13)
+    );
+}
+
+#[test]
+fn listed_option_expiries() {
+    let nyse = UnitedStates::Nyse;
+
+    assert_eq!(monthly_expiry(2025, 3, &nyse), date(2025, 3, 21));
+    // The third Friday of April 2025 is Good Friday
+    assert_eq!(monthly_expiry(2025, 4, &nyse), date(2025, 4, 17));
+    assert_eq!(
+        next_monthly_expiry(date(2025, 3, 21), &nyse),
+        date(2025, 3, 21)
+    );
+    assert_eq!(
+        next_monthly_expiry(date(2025, 3, 22), &nyse),
+        date(2025, 4, 17)
+    );
+
+    assert_eq!(weekly_expiry(date(2024, 7, 1), &nyse), date(2024, 7, 5));
+    assert_eq!(weekly_expiry(date(2024, 7, 5), &nyse), date(2024, 7, 5));
+    assert_eq!(weekly_expiry(date(2024, 7, 6), &nyse), date(2024, 7, 12));
+    assert_eq!(weekly_expiry(date(2025, 4, 14), &nyse), date(2025, 4, 17));
+}
+
+#[test]
+fn cds_roll_dates_and_maturities() {
+    assert!(is_cds_date(date(2024, 3, 20)));
+    assert!(!is_cds_date(date(2024, 4, 20)));
+    assert_eq!(next_cds_date(date(2024, 3, 20)), date(2024, 6, 20));
+    assert_eq!(previous_cds_date(date(2024, 3, 20)), date(2023, 12, 20));
+    assert_eq!(previous_cds_date(date(2024, 5, 2)), date(2024, 3, 20));
+
+    // Maturities roll semi-annually on March 20 and September 20
+    let five_years = Tenor::Years(5);
+    assert_eq!(
+        cds_maturity(date(2016, 9, 19), five_years),
+        date(2021, 6, 20)
+    );
+    assert_eq!(
+        cds_maturity(date(2016, 9, 20), five_years),
+        date(2021, 12, 20)
+    );
+    assert_eq!(
+        cds_maturity(date(2016, 12, 25), five_years),
+        date(2021, 12, 20)
+    );
+    assert_eq!(
+        cds_maturity(date(2017, 3, 20), five_years),
+        date(2022, 6, 20)
+    );
+}
@@ -0,0 +1,169 @@
+use chrono::{Days, NaiveDate};
+use qox::core::error::CurveError;
+use qox::core::period::{DayCountConvention, DefaultPeriodCalculator};
+use qox::core::rate::{Compounding, Frequency, InterestRate};
+use qox::core::tenor::Tenor;
+use qox::evaluators::black_scholes::finite_difference::chain::ChainEvaluator;
+use qox::instruments::stock_option::{ExerciseStyle, StockOption};
+use qox::instruments::{OptionInstrument, OptionType};
+use qox::market::market_frame::OptionMarketFrame;
+use qox::market::rate_curve::{ContinuousRateCurve, InterpolatedRateCurve};
+use qox::market::vol_surface::FlatVolSurface;
+use qox::methods::analytic::black_scholes::black_scholes;
+use qox::methods::finite_difference::solver::FdmConfig;
+use qox::traits::market_view::MarketView;
+
+fn price_on(evaluation_date: NaiveDate, days_to_expiry: u64) -> f64 {
+    let expiry = (evaluation_date + Days::new(days_to_expiry))
+        .and_hms_opt(0, 0, 0)
+        .unwrap()
+        .and_utc();
+    let option = StockOption::new(100.0, expiry, OptionType::Call, ExerciseStyle::European);
+    let market = OptionMarketFrame::new(
+        evaluation_date,
+        100.0,
+        ContinuousRateCurve::new(0.05, DayCountConvention::Actual365Fixed),
+        FlatVolSurface::new(0.2),
+    );
+    option.evaluate(&market)
+}
+
+#[test]
+fn same_inputs_price_identically_on_any_day() {
+    let reference = price_on(NaiveDate::from_ymd_opt(2024, 1, 2).unwrap(), 365);
+    assert_eq!(
+        reference,
+        price_on(NaiveDate::from_ymd_opt(2024, 1, 2).unwrap(), 365)
+    );
+
+    // Historical revaluation and far-future dates see the same year to expiry
+    for evaluation_date in [
+        NaiveDate::from_ymd_opt(1999, 3, 15).unwrap(),
+        NaiveDate::from_ymd_opt(2019, 6, 3).unwrap(),
+        NaiveDate::from_ymd_opt(2041, 11, 29).unwrap(),
+    ] {
+        assert_eq!(price_on(evaluation_date, 365), reference);
+    }
+
+    let expected = black_scholes(100.0, 100.0, 1.0, 0.05, 0.2, true);
+    assert!((reference - expected).abs() < 1e-2);
+}
+
+#[test]
+fn time_to_expiry_is_measured_from_the_evaluation_date() {
+    let expiry = NaiveDate::from_ymd_opt(2020, 6, 19)
+        .unwrap()
+        .and_hms_opt(20, 0, 0)
+        .unwrap()
+        .and_utc();
+    let option = StockOption::new(100.0, expiry, OptionType::Put, ExerciseStyle::European);
+
+    let market = OptionMarketFrame::new(
+        NaiveDate::from_ymd_opt(2020, 3, 21).unwrap(),
+        100.0,
+        ContinuousRateCurve::new(0.0, DayCountConvention::Actual365Fixed),
+        FlatVolSurface::new(0.3),
+    );
+    // Measured to the second, up to the 20:00 expiry
+    let t: f64 = option.years_to_expiry(market.evaluation_time());
+    assert!((t - (90.0 + 20.0 / 24.0) / 365.0).abs() < 1e-12);
+
+    let later = market.with_evaluation_time(
+        NaiveDate::from_ymd_opt(2020, 5, 20)
+            .unwrap()
+            .and_hms_opt(14, 30, 0)
+            .unwrap()
+            .and_utc(),
+    );
+    assert_eq!(
+        later.evaluation_date(),
+        NaiveDate::from_ymd_opt(2020, 5, 20).unwrap()
+    );
+    let t: f64 = option.years_to_expiry(later.evaluation_time());
+    assert!((t - (30.0 + 5.5 / 24.0) / 365.0).abs() < 1e-12);
+}
+
+#[test]
+fn dated_curves_must_start_on_the_evaluation_date() {
+    let today = NaiveDate::from_ymd_opt(2024, 1, 2).unwrap();
+    let quote = InterestRate::new(
+        0.05,
+        DayCountConvention::Actual365Fixed,
+        Compounding::Continuous,
+        Frequency::Infinite,
+    );
+    let curve = InterpolatedRateCurve::new(
+        today,
+        vec![Tenor::Years(1), Tenor::Years(2)],
+        vec![quote, quote],
+        &DefaultPeriodCalculator,
+    )
+    .unwrap();
+
+    let market = OptionMarketFrame::new(today, 100.0, curve.clone(), FlatVolSurface::new(0.2));
+    assert!(market.check_reference_date().is_ok());
+
+    let later = market.with_evaluation_time(
+        (today + Days::new(30))
+            .and_hms_opt(0, 0, 0)
+            .unwrap()
+            .and_utc(),
+    );
+    assert!(matches!(
+        later.check_reference_date(),
+        Err(CurveError::ReferenceDateMismatch { curve, .. }) if curve == today
+    ));
+
+    // Undated curves are taken to start on the evaluation date
+    let flat = OptionMarketFrame::new(
+        today,
+        100.0,
+        ContinuousRateCurve::new(0.05, DayCountConvention::Actual365Fixed),
+        FlatVolSurface::new(0.2),
+    );
+    assert!(flat.check_reference_date().is_ok());
+}
+
+fn stale_curve_market()
+-> OptionMarketFrame<f64, InterpolatedRateCurve<'static, f64>, FlatVolSurface<f64>> {
+    let today = NaiveDate::from_ymd_opt(2024, 1, 2).unwrap();
+    let quote = InterestRate::new(
+        0.05,
+        DayCountConvention::Actual365Fixed,
+        Compounding::Continuous,
+        Frequency::Infinite,
+    );
+    let curve = InterpolatedRateCurve::new(
+        today.pred_opt().unwrap(),
+        vec![Tenor::Years(1), Tenor::Years(2)],
+        vec![quote, quote],
+        &DefaultPeriodCalculator,
+    )
+    .unwrap();
+    OptionMarketFrame::new(today, 100.0, curve, FlatVolSurface::new(0.2))
+}
+
+fn one_year_call() -> StockOption {
+    let expiry = NaiveDate::from_ymd_opt(2025, 1, 2)
+        .unwrap()
+        .and_hms_opt(0, 0, 0)
+        .unwrap()
+        .and_utc();
+    StockOption::new(100.0, expiry, OptionType::Call, ExerciseStyle::American)
+}
+
+#[test]
+#[should_panic(expected = "is not the market date")]
+fn pricing_rejects_a_curve_from_another_date() {
+    let _: f64 = one_year_call().evaluate(&stale_curve_market());
+}
+
+#[test]
+#[should_panic(expected = "is not the market date")]
+fn chain_pricing_rejects_a_curve_from_another_date() {
+    let _: Vec<f64> = ChainEvaluator::new(FdmConfig {
+        nodes: 100,
+        time_steps: 10,
+    })
+    .evaluate(&[one_year_call()], &stale_curve_market());
+}
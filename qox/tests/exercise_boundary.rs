@@ -0,0 +1,145 @@
+use chrono::{Days, NaiveDate};
+use qox::core::period::DayCountConvention;
+use qox::evaluators::black_scholes::finite_difference::VanillaPayoff;
+use qox::evaluators::black_scholes::finite_difference::chain::ChainEvaluator;
+use qox::instruments::stock_option::{ExerciseStyle, StockOption};
+use qox::instruments::{OptionInstrument, OptionType};
+use qox::market::market_frame::OptionMarketFrame;
+use qox::market::rate_curve::ContinuousRateCurve;
+use qox::market::vol_surface::FlatVolSurface;
+use qox::methods::complementarity::policy_iteration::PolicyIteration;
+use qox::methods::constraints::american::AmericanConstraint;
+use qox::methods::finite_difference::meshers::uniform::UniformMesher1d;
+use qox::methods::finite_difference::solver::{FdmConfig, Solver};
+use qox::methods::linear_operators::tridiagonal_operator::TridiagonalOperator;
+use qox::methods::step_policy::american_policy::AmericanPolicy;
+use qox::methods::time_stepping::butcher_jackiewicz2::ButcherJackiewicz2;
+use qox::methods::transforms::log::LogTransform;
+use qox::processes::FdmProcess;
+use qox::processes::black_scholes::BlackScholesProcess;
+use qox::traits::payoff::PayoffAsInitialConditions;
+
+fn evaluation_date() -> NaiveDate {
+    NaiveDate::from_ymd_opt(2024, 1, 2).unwrap()
+}
+
+fn market() -> OptionMarketFrame<f64, ContinuousRateCurve<'static, f64>, FlatVolSurface<f64>> {
+    OptionMarketFrame::new(
+        evaluation_date(),
+        100.0,
+        ContinuousRateCurve::new(0.05, DayCountConvention::Actual365Fixed),
+        FlatVolSurface::new(0.2),
+    )
+}
+
+fn one_year(option_type: OptionType, exercise_style: ExerciseStyle) -> StockOption {
+    StockOption::new(
+        100.0,
+        (evaluation_date() + Days::new(365))
+            .and_hms_opt(0, 0, 0)
+            .unwrap()
+            .and_utc(),
+        option_type,
+        exercise_style,
+    )
+}
+
+#[test]
+fn american_put_returns_price_and_boundary() {
+    let put = one_year(OptionType::Put, ExerciseStyle::American);
+    let (price, boundary): (f64, _) = put.evaluate_with_exercise_boundary(&market());
+    let boundary = boundary.expect("American exercise records a boundary");
+
+    // Binomial reference for S = K = 100, r = 5%, vol = 20%, one year
+    assert!((price - 6.0896).abs() < 1e-2);
+
+    assert_eq!(boundary.len(), 11);
+    let spots: Vec<f64> = boundary.spots.iter().map(|s| s.unwrap()).collect();
+
+    // The critical spot starts just below the strike and falls as expiry recedes
+    assert!(spots[0] < 100.0);
+    assert!(spots.windows(2).all(|w| w[1] < w[0]));
+    assert!((boundary.current().unwrap() - 80.9).abs() < 1.0);
+}
+
+#[test]
+fn american_call_without_dividends_is_never_exercised() {
+    let american = one_year(OptionType::Call, ExerciseStyle::American);
+    let european = one_year(OptionType::Call, ExerciseStyle::European);
+
+    let (price, boundary): (f64, _) = american.evaluate_with_exercise_boundary(&market());
+    let european_price: f64 = european.evaluate(&market());
+
+    assert!((price - european_price).abs() < 1e-3);
+    assert!(boundary.unwrap().spots.iter().all(Option::is_none));
+}
+
+#[test]
+fn european_options_have_no_boundary() {
+    let put = one_year(OptionType::Put, ExerciseStyle::European);
+    let (_, boundary): (f64, _) = put.evaluate_with_exercise_boundary(&market());
+
+    assert!(boundary.is_none());
+}
+
+#[test]
+fn reused_policy_records_only_the_latest_boundary() {
+    let config = FdmConfig {
+        nodes: 400,
+        time_steps: 11,
+    };
+    let solver = Solver { config };
+    let transform = LogTransform::new();
+    let mesher = UniformMesher1d::new(0.01_f64.ln(), 500.0_f64.ln(), config.nodes, transform);
+    let process =
+        BlackScholesProcess::new(0.05, 0.2, transform, DayCountConvention::Actual365Fixed);
+    let operator: TridiagonalOperator<f64> = process.build_operator(&mesher);
+    let payoff = PayoffAsInitialConditions::new(VanillaPayoff {
+        strike: 100.0,
+        option_type: OptionType::Put,
+    });
+    let policy = AmericanPolicy::new(
+        &operator,
+        PolicyIteration::new(1e-12, 100),
+        AmericanConstraint::new(payoff),
+    );
+    let dt = 1.0 / config.time_steps as f64;
+
+    solver.solve(ButcherJackiewicz2::new(), payoff, &mesher, dt, &policy);
+    let first = policy.exercise_boundary();
+    solver.solve(ButcherJackiewicz2::new(), payoff, &mesher, dt, &policy);
+    let second = policy.exercise_boundary();
+
+    assert_eq!(first.len(), config.time_steps);
+    assert_eq!(second.times, first.times);
+    assert_eq!(second.spots, first.spots);
+}
+
+#[test]
+fn chain_prices_american_options() {
+    let options: Vec<StockOption> = [90.0, 100.0, 110.0]
+        .into_iter()
+        .map(|strike| {
+            StockOption::new(
+                strike,
+                (evaluation_date() + Days::new(365))
+                    .and_hms_opt(0, 0, 0)
+                    .unwrap()
+                    .and_utc(),
+                OptionType::Put,
+                ExerciseStyle::American,
+            )
+        })
+        .collect();
+
+    let prices: Vec<f64> = ChainEvaluator::new(FdmConfig {
+        nodes: 1000,
+        time_steps: 11,
+    })
+    .evaluate(&options, &market());
+
+    for (option, price) in options.iter().zip(prices) {
+        let single: f64 = option.evaluate(&market());
+        assert!((price - single).abs() < 1e-2);
+    }
+}
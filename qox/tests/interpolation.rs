@@ -0,0 +1,206 @@
+use chrono::NaiveDate;
+use qox::core::curve_interpolation::{CurveInterpolation, InterpolationVariable};
+use qox::core::error::InterpolationError;
+use qox::core::period::{DayCountConvention, DefaultPeriodCalculator};
+use qox::core::rate::{Compounding, Frequency, InterestRate};
+use qox::core::tenor::Tenor;
+use qox::market::rate_curve::InterpolatedRateCurve;
+use qox::math::interpolate::{
+    CubicSplineInterpolator, FlatForwardInterpolator, InterpolationMethod, Interpolator1D,
+    MonotoneConvexInterpolator, MonotoneCubicInterpolator, SplineBoundary,
+};
+use qox::traits::rate_curve::RateCurve;
+
+#[test]
+fn clamped_spline_reproduces_a_cubic() {
+    let f = |x: f64| 1.0 - 2.0 * x + 0.5 * x * x - 0.1 * x * x * x;
+    let df = |x: f64| -2.0 + x - 0.3 * x * x;
+
+    let x = vec![0.0, 0.7, 1.5, 2.0, 3.2, 4.0];
+    let y = x.iter().map(|&x| f(x)).collect();
+    let spline = CubicSplineInterpolator::new(
+        x,
+        y,
+        SplineBoundary::Clamped {
+            start: df(0.0),
+            end: df(4.0),
+        },
+    )
+    .unwrap();
+
+    for v in [0.1, 0.9, 1.77, 2.5, 3.9] {
+        assert!((spline.interpolate(v) - f(v)).abs() < 1e-12, "at {}", v);
+    }
+}
+
+#[test]
+fn natural_spline_reproduces_a_line_and_hits_the_nodes() {
+    let x = vec![0.0, 1.0, 2.5, 4.0];
+    let line: Vec<f64> = x.iter().map(|&x| 3.0 - 0.5 * x).collect();
+    let spline = CubicSplineInterpolator::new(x.clone(), line, SplineBoundary::Natural).unwrap();
+    assert!((spline.interpolate(1.7) - (3.0 - 0.85)).abs() < 1e-12);
+
+    let y = vec![1.0, 4.0, 2.0, 5.0];
+    let spline =
+        CubicSplineInterpolator::new(x.clone(), y.clone(), SplineBoundary::Natural).unwrap();
+    for (x, y) in x.iter().zip(&y) {
+        assert!((spline.interpolate(*x) - y).abs() < 1e-12);
+    }
+}
+
+#[test]
+fn monotone_cubic_does_not_overshoot() {
+    // A step: a natural spline rings around it, Fritsch-Carlson must not
+    let x = vec![0.0, 1.0, 2.0, 3.0, 4.0];
+    let y = vec![0.0, 0.0, 1.0, 1.0, 1.0];
+    let monotone = MonotoneCubicInterpolator::new(x, y).unwrap();
+
+    let mut previous = monotone.interpolate(0.0);
+    for k in 1..=400 {
+        let value = monotone.interpolate(k as f64 * 0.01);
+        assert!(value >= previous - 1e-15);
+        assert!((0.0..=1.0).contains(&value));
+        previous = value;
+    }
+}
+
+#[test]
+fn zero_rate_schemes_preserve_discrete_forwards() {
+    let t = vec![0.5, 1.0, 2.0, 5.0, 10.0];
+    let r = vec![0.030, 0.032, 0.031, 0.036, 0.040];
+
+    let flat = FlatForwardInterpolator::new(t.clone(), r.clone()).unwrap();
+    let convex = MonotoneConvexInterpolator::new(t.clone(), r.clone()).unwrap();
+
+    for (ti, ri) in t.iter().zip(&r) {
+        assert!((flat.interpolate(*ti) - ri).abs() < 1e-14);
+        assert!((convex.interpolate(*ti) - ri).abs() < 1e-14);
+    }
+
+    // Flat forward: r(t) * t is linear within each interval
+    let rt = |s: f64| flat.interpolate(s) * s;
+    let mid = 0.5 * (rt(2.0) + rt(5.0));
+    assert!((rt(3.5) - mid).abs() < 1e-14);
+
+    // Monotone convex: the implied instantaneous forward has no jumps at the nodes
+    let forward = |s: f64| {
+        let h = 1e-6;
+        (convex.interpolate(s + h) * (s + h) - convex.interpolate(s - h) * (s - h)) / (2.0 * h)
+    };
+    for &node in &t[1..t.len() - 1] {
+        let left = forward(node - 1e-4);
+        let right = forward(node + 1e-4);
+        assert!(
+            (left - right).abs() < 1e-4,
+            "jump at {}: {} vs {}",
+            node,
+            left,
+            right
+        );
+    }
+}
+
+#[test]
+fn interpolated_curve_reprices_its_quotes_under_every_variable() {
+    let today = NaiveDate::from_ymd_opt(2025, 1, 15).unwrap();
+    let tenors = vec![
+        Tenor::Months(6),
+        Tenor::Years(1),
+        Tenor::Years(2),
+        Tenor::Years(5),
+        Tenor::Years(10),
+    ];
+    let rates: Vec<InterestRate<f64>> = [0.030, 0.032, 0.031, 0.036, 0.040]
+        .into_iter()
+        .map(|r| {
+            InterestRate::new(
+                r,
+                DayCountConvention::Actual365Fixed,
+                Compounding::Continuous,
+                Frequency::Infinite,
+            )
+        })
+        .collect();
+
+    let base = InterpolatedRateCurve::new(
+        today,
+        tenors.clone(),
+        rates.clone(),
+        &DefaultPeriodCalculator,
+    )
+    .unwrap();
+
+    let methods = [
+        InterpolationMethod::Linear,
+        InterpolationMethod::NaturalCubic,
+        InterpolationMethod::MonotoneCubic,
+    ];
+    let variables = [
+        InterpolationVariable::DiscountFactor,
+        InterpolationVariable::LogDiscount,
+        InterpolationVariable::InstantaneousForward,
+    ];
+
+    for method in methods {
+        for variable in variables {
+            let curve = base
+                .clone()
+                .with_interpolation(CurveInterpolation::new(variable, method).unwrap())
+                .unwrap();
+
+            for (tenor, rate) in tenors.iter().zip(&rates) {
+                let t = (tenor.advance(today) - today).num_days() as f64 / 365.0;
+                let expected = (-rate.value * t).exp();
+                assert!(
+                    (curve.discount_factor(t) - expected).abs() < 1e-12,
+                    "{:?} {:?} at {}",
+                    variable,
+                    method,
+                    t
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn zero_rate_only_methods_reject_other_variables() {
+    for method in [
+        InterpolationMethod::MonotoneConvex,
+        InterpolationMethod::FlatForward,
+    ] {
+        assert!(CurveInterpolation::new(InterpolationVariable::ZeroRate, method).is_ok());
+        for variable in [
+            InterpolationVariable::DiscountFactor,
+            InterpolationVariable::LogDiscount,
+            InterpolationVariable::InstantaneousForward,
+        ] {
+            assert!(matches!(
+                CurveInterpolation::new(variable, method),
+                Err(InterpolationError::RequiresZeroRates(_))
+            ));
+
+            // Bypassing the constructor is caught when the curve is built
+            let unchecked = CurveInterpolation { variable, method };
+            let today = NaiveDate::from_ymd_opt(2025, 1, 15).unwrap();
+            let rates = [0.03, 0.035]
+                .map(|r| {
+                    InterestRate::new(
+                        r,
+                        DayCountConvention::Actual365Fixed,
+                        Compounding::Continuous,
+                        Frequency::Infinite,
+                    )
+                })
+                .to_vec();
+            let curve = InterpolatedRateCurve::new(
+                today,
+                vec![Tenor::Years(1), Tenor::Years(2)],
+                rates,
+                &DefaultPeriodCalculator,
+            )
+            .unwrap();
+            assert!(curve.with_interpolation(unchecked).is_err());
+        }
+    }
+}
@@ -0,0 +1,269 @@
+use chrono::NaiveDate;
+use qox::core::curve_interpolation::CurveInterpolation;
+use qox::core::error::CurveError;
+use qox::core::period::{DayCountConvention, DefaultPeriodCalculator, Thirty360Subtype};
+use qox::core::rate::RateIndex;
+use qox::core::tenor::Tenor;
+use qox::core::yield_curve::{CurveInstrument, Deposit, ForwardRateAgreement, ParSwap, YieldCurve};
+use qox::instruments::swap::{FixedLeg, FloatingLeg, SwapSide, VanillaSwap};
+use qox::market::multi_curve::MultiCurveFrame;
+use qox::traits::market_view::RatesMarketView;
+use qox::traits::rate_curve::RateCurve;
+
+fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(y, m, d).unwrap()
+}
+
+const ACT360: DayCountConvention = DayCountConvention::Actual360;
+const ACT365: DayCountConvention = DayCountConvention::Actual365Fixed;
+const THIRTY360: DayCountConvention = DayCountConvention::Thirty360(Thirty360Subtype::US);
+
+fn today() -> NaiveDate {
+    date(2025, 1, 15)
+}
+
+fn ois_curve<'a>(swaps: &'a [ParSwap<'a, f64>]) -> YieldCurve<'a, f64> {
+    let instruments: Vec<&dyn CurveInstrument<f64>> = swaps
+        .iter()
+        .map(|s| s as &dyn CurveInstrument<f64>)
+        .collect();
+
+    YieldCurve::bootstrap(
+        today(),
+        &instruments,
+        CurveInterpolation::LOG_LINEAR_DISCOUNT,
+        ACT365,
+        &DefaultPeriodCalculator,
+    )
+    .unwrap()
+}
+
+fn ois_swaps<'a>() -> Vec<ParSwap<'a, f64>> {
+    [
+        (6, 0.0420),
+        (12, 0.0405),
+        (24, 0.0380),
+        (60, 0.0355),
+        (120, 0.0360),
+    ]
+    .into_iter()
+    .map(|(months, rate)| {
+        let maturity = Tenor::Months(months).advance(today());
+        ParSwap::new(today(), maturity, rate, Tenor::Years(1), ACT360)
+    })
+    .collect()
+}
+
+fn libor_swaps<'a>() -> Vec<ParSwap<'a, f64>> {
+    [(2, 0.0405), (5, 0.0382), (10, 0.0390)]
+        .into_iter()
+        .map(|(years, rate)| {
+            let maturity = Tenor::Years(years).advance(today());
+            ParSwap::new(today(), maturity, rate, Tenor::Months(6), THIRTY360)
+                .with_floating_leg(Tenor::Months(3), ACT360)
+        })
+        .collect()
+}
+
+#[test]
+fn projection_curve_reprices_swaps_discounted_on_ois() {
+    let ois_quotes = ois_swaps();
+    let ois = ois_curve(&ois_quotes);
+
+    let deposit = Deposit::new(today(), date(2025, 4, 15), 0.0445, ACT360);
+    let fra = ForwardRateAgreement::new(date(2025, 4, 15), date(2025, 7, 15), 0.0430, ACT360);
+    let swaps = libor_swaps();
+
+    let mut instruments: Vec<&dyn CurveInstrument<f64>> = vec![&deposit, &fra];
+    instruments.extend(swaps.iter().map(|s| s as &dyn CurveInstrument<f64>));
+
+    let libor = YieldCurve::bootstrap_projection(
+        today(),
+        &instruments,
+        &ois,
+        CurveInterpolation::LOG_LINEAR_DISCOUNT,
+        ACT365,
+        &DefaultPeriodCalculator,
+    )
+    .unwrap();
+
+    for instrument in &instruments {
+        let implied = instrument.implied_quote(&libor);
+        assert!((implied - instrument.quote()).abs() < 1e-10);
+    }
+
+    // Term rates carry a basis over OIS
+    assert!(libor.zero_rate(3.0) > ois.zero_rate(3.0));
+
+    let index = RateIndex::new("USD-3M", Tenor::Months(3), ACT360);
+    let market = MultiCurveFrame::new(today(), ACT365, &DefaultPeriodCalculator, &ois)
+        .with_projection(index.name, &libor);
+
+    for quote in &swaps {
+        let notional = 1_000_000.0;
+        let swap = VanillaSwap::new(
+            SwapSide::Payer,
+            FixedLeg::new(
+                quote.start,
+                quote.maturity,
+                Tenor::Months(6),
+                quote.rate,
+                notional,
+                THIRTY360,
+            ),
+            FloatingLeg::new(index, quote.start, quote.maturity, notional),
+        );
+
+        let npv = swap.npv(&market).unwrap();
+        let fair = swap.fair_rate(&market).unwrap();
+        assert!(npv.abs() < 1e-4, "npv {}", npv);
+        assert!(
+            (fair - quote.rate).abs() < 1e-12,
+            "{} vs {}",
+            fair,
+            quote.rate
+        );
+    }
+}
+
+#[test]
+fn receiver_mirrors_payer_and_spread_adds_an_annuity() {
+    let ois_quotes = ois_swaps();
+    let ois = ois_curve(&ois_quotes);
+    let index = RateIndex::new("USD-3M", Tenor::Months(3), ACT360);
+    let market = MultiCurveFrame::new(today(), ACT365, &DefaultPeriodCalculator, &ois)
+        .with_projection(index.name, &ois);
+
+    let maturity = Tenor::Years(5).advance(today());
+    let fixed = FixedLeg::new(today(), maturity, Tenor::Months(3), 0.04, 100.0, ACT360);
+    let floating = FloatingLeg::new(index, today(), maturity, 100.0);
+
+    let payer = VanillaSwap::new(SwapSide::Payer, fixed.clone(), floating.clone());
+    let receiver = VanillaSwap::new(SwapSide::Receiver, fixed.clone(), floating.clone());
+    let payer_npv = payer.npv(&market).unwrap();
+    assert!((payer_npv + receiver.npv(&market).unwrap()).abs() < 1e-12);
+
+    // Same schedules and day count: a spread is worth spread * annuity
+    let spread = 0.001;
+    let spreaded = VanillaSwap::new(SwapSide::Payer, fixed.clone(), floating.with_spread(spread));
+    let difference = spreaded.npv(&market).unwrap() - payer_npv;
+    assert!((difference - spread * fixed.annuity(&market).unwrap()).abs() < 1e-12);
+}
+
+#[test]
+fn missing_projection_curve_is_an_error() {
+    let ois_quotes = ois_swaps();
+    let ois = ois_curve(&ois_quotes);
+    let market: MultiCurveFrame<f64, _, &YieldCurve<f64>> =
+        MultiCurveFrame::new(today(), ACT365, &DefaultPeriodCalculator, &ois);
+
+    let index = RateIndex::new("EUR-6M", Tenor::Months(6), ACT360);
+    let maturity = Tenor::Years(2).advance(today());
+    let floating = FloatingLeg::new(index, today(), maturity, 1.0);
+
+    assert!(matches!(
+        floating.npv(&market),
+        Err(CurveError::MissingProjection(name)) if name == "EUR-6M"
+    ));
+}
+
+#[test]
+fn curves_must_start_on_the_market_reference_date() {
+    let ois_quotes = ois_swaps();
+    let ois = ois_curve(&ois_quotes);
+    let market = MultiCurveFrame::new(today(), ACT365, &DefaultPeriodCalculator, &ois)
+        .with_projection("USD-3M", &ois);
+    assert!(market.check_reference_dates().is_ok());
+
+    // A curve built yesterday reads every time a day short
+    let deposit = Deposit::new(today(), date(2025, 7, 15), 0.04, ACT360);
+    let stale = YieldCurve::bootstrap(
+        today().pred_opt().unwrap(),
+        &[&deposit as &dyn CurveInstrument<f64>],
+        CurveInterpolation::LOG_LINEAR_DISCOUNT,
+        ACT365,
+        &DefaultPeriodCalculator,
+    )
+    .unwrap();
+    let market = market.with_projection("USD-3M", &stale);
+    assert!(matches!(
+        market.check_reference_dates(),
+        Err(CurveError::ReferenceDateMismatch { curve, market })
+            if curve == today().pred_opt().unwrap() && market == today()
+    ));
+
+    // Pricing runs the same check
+    let index = RateIndex::new("USD-3M", Tenor::Months(3), ACT360);
+    let maturity = Tenor::Years(2).advance(today());
+    let swap = VanillaSwap::new(
+        SwapSide::Payer,
+        FixedLeg::new(today(), maturity, Tenor::Months(6), 0.04, 1.0, ACT360),
+        FloatingLeg::new(index, today(), maturity, 1.0),
+    );
+    assert!(matches!(
+        swap.npv(&market),
+        Err(CurveError::ReferenceDateMismatch { .. })
+    ));
+}
+
+#[test]
+fn projection_bootstrap_rejects_a_discount_curve_in_another_curve_time() {
+    let ois_quotes = ois_swaps();
+    let ois = ois_curve(&ois_quotes);
+    let deposit = Deposit::new(today(), date(2025, 4, 15), 0.0445, ACT360);
+    let instruments = [&deposit as &dyn CurveInstrument<f64>];
+
+    let projection = |reference_date, day_count| {
+        YieldCurve::bootstrap_projection(
+            reference_date,
+            &instruments,
+            &ois,
+            CurveInterpolation::LOG_LINEAR_DISCOUNT,
+            day_count,
+            &DefaultPeriodCalculator,
+        )
+    };
+
+    assert!(projection(today(), ACT365).is_ok());
+    assert!(matches!(
+        projection(today(), ACT360),
+        Err(CurveError::DayCountMismatch(maturity)) if maturity == date(2025, 4, 15)
+    ));
+    assert!(matches!(
+        projection(today().succ_opt().unwrap(), ACT365),
+        Err(CurveError::ReferenceDateMismatch { curve, .. }) if curve == today()
+    ));
+}
+
+#[test]
+fn seasoned_legs_leave_out_paid_coupons() {
+    let ois_quotes = ois_swaps();
+    let ois = ois_curve(&ois_quotes);
+    let index = RateIndex::new("USD-3M", Tenor::Months(3), ACT360);
+    let market = MultiCurveFrame::new(today(), ACT365, &DefaultPeriodCalculator, &ois)
+        .with_projection(index.name, &ois);
+
+    // A year into a three-year swap, with a coupon paid today
+    let start = date(2024, 1, 15);
+    let maturity = date(2027, 1, 15);
+    let seasoned_fixed = FixedLeg::new(start, maturity, Tenor::Months(3), 0.04, 1.0, ACT360);
+    let seasoned_floating = FloatingLeg::new(index, start, maturity, 1.0);
+    let fresh_fixed = FixedLeg::new(today(), maturity, Tenor::Months(3), 0.04, 1.0, ACT360);
+    let fresh_floating = FloatingLeg::new(index, today(), maturity, 1.0);
+
+    assert!(
+        (seasoned_fixed.annuity(&market).unwrap() - fresh_fixed.annuity(&market).unwrap()).abs()
+            < 1e-15
+    );
+    assert!(
+        (seasoned_floating.npv(&market).unwrap() - fresh_floating.npv(&market).unwrap()).abs()
+            < 1e-15
+    );
+
+    // Mid-period, the current coupon fixed before the market date
+    let accruing = FloatingLeg::new(index, date(2024, 12, 15), maturity, 1.0);
+    assert!(matches!(
+        accruing.npv(&market),
+        Err(CurveError::MissingFixing(from)) if from == date(2024, 12, 15)
+    ));
+}
@@ -0,0 +1,173 @@
+use chrono::{DateTime, Days, NaiveDate, Utc};
+use qox::core::period::DayCountConvention;
+use qox::evaluators::black_scholes::finite_difference::chain::ChainEvaluator;
+use qox::instruments::stock_option::{ExerciseStyle, StockOption};
+use qox::instruments::{OptionInstrument, OptionType};
+use qox::market::market_frame::OptionMarketFrame;
+use qox::market::rate_curve::ContinuousRateCurve;
+use qox::market::vol_surface::FlatVolSurface;
+use qox::methods::analytic::black_scholes::black_scholes;
+use qox::methods::finite_difference::solver::FdmConfig;
+
+fn evaluation_date() -> NaiveDate {
+    NaiveDate::from_ymd_opt(2024, 1, 2).unwrap()
+}
+
+fn expiry(days: u64) -> DateTime<Utc> {
+    (evaluation_date() + Days::new(days))
+        .and_hms_opt(16, 0, 0)
+        .unwrap()
+        .and_utc()
+}
+
+fn chain() -> Vec<StockOption> {
+    let near = expiry(91);
+    let far = expiry(365);
+
+    let mut options = Vec::new();
+    for expiry in [near, far] {
+        for strike in [80.0, 90.0, 100.0, 110.0, 120.0] {
+            for option_type in [OptionType::Call, OptionType::Put] {
+                options.push(StockOption::new(
+                    strike,
+                    expiry,
+                    option_type,
+                    ExerciseStyle::European,
+                ));
+            }
+        }
+    }
+    options
+}
+
+#[test]
+fn chain_matches_analytic_prices_in_input_order() {
+    let spot = 100.0;
+    let rate = 0.05;
+    let vol = 0.2;
+    let market = OptionMarketFrame::new(
+        evaluation_date(),
+        spot,
+        ContinuousRateCurve::new(rate, DayCountConvention::Actual365Fixed),
+        FlatVolSurface::new(vol),
+    );
+
+    let options = chain();
+    let evaluator = ChainEvaluator::new(FdmConfig {
+        nodes: 1000,
+        time_steps: 50,
+    });
+    let prices: Vec<f64> = evaluator.evaluate(&options, &market);
+
+    assert_eq!(prices.len(), options.len());
+    for (option, price) in options.iter().zip(prices) {
+        let t = <StockOption as OptionInstrument<f64, _>>::years_to_expiry(
+            *option,
+            market.evaluation_time,
+        );
+        let is_call = matches!(option.option_type, OptionType::Call);
+        let expected = black_scholes(spot, option.strike, t, rate, vol, is_call);
+
+        assert!(
+            (price - expected).abs() < 1e-2,
+            "strike {} {:?}: chain {} vs analytic {}",
+            option.strike,
+            option.option_type,
+            price,
+            expected
+        );
+    }
+}
+
+#[test]
+fn chain_agrees_with_single_option_evaluation() {
+    let market = OptionMarketFrame::new(
+        evaluation_date(),
+        95.0,
+        ContinuousRateCurve::new(0.03, DayCountConvention::Actual365Fixed),
+        FlatVolSurface::new(0.25),
+    );
+
+    let options = chain();
+    let evaluator = ChainEvaluator::new(FdmConfig {
+        nodes: 1000,
+        time_steps: 11,
+    });
+    let prices: Vec<f64> = evaluator.evaluate(&options, &market);
+
+    for (option, price) in options.iter().zip(prices) {
+        let single: f64 = option.evaluate(&market);
+        assert!(
+            (price - single).abs() < 1e-2,
+            "strike {}: chain {} vs single {}",
+            option.strike,
+            price,
+            single
+        );
+    }
+}
+
+#[test]
+fn empty_chain_prices_to_empty_vector() {
+    let market = OptionMarketFrame::new(
+        evaluation_date(),
+        100.0,
+        ContinuousRateCurve::new(0.05, DayCountConvention::Actual365Fixed),
+        FlatVolSurface::new(0.2),
+    );
+
+    let prices: Vec<f64> = ChainEvaluator::new(FdmConfig {
+        nodes: 100,
+        time_steps: 10,
+    })
+    .evaluate(&[], &market);
+
+    assert!(prices.is_empty());
+}
+
+#[test]
+fn american_options_in_a_chain_are_priced_not_rejected() {
+    let spot = 100.0;
+    let rate = 0.05;
+    let vol = 0.2;
+    let market = OptionMarketFrame::new(
+        evaluation_date(),
+        spot,
+        ContinuousRateCurve::new(rate, DayCountConvention::Actual365Fixed),
+        FlatVolSurface::new(vol),
+    );
+
+    let european = chain();
+    let american: Vec<StockOption> = european
+        .iter()
+        .map(|option| StockOption {
+            exercise_style: ExerciseStyle::American,
+            ..*option
+        })
+        .collect();
+    let evaluator = ChainEvaluator::new(FdmConfig {
+        nodes: 1000,
+        time_steps: 50,
+    });
+    let european_prices: Vec<f64> = evaluator.evaluate(&european, &market);
+    let american_prices: Vec<f64> = evaluator.evaluate(&american, &market);
+
+    for ((option, &am), &eu) in american.iter().zip(&american_prices).zip(&european_prices) {
+        let intrinsic = match option.option_type {
+            OptionType::Call => (spot - option.strike).max(0.0),
+            OptionType::Put => (option.strike - spot).max(0.0),
+        };
+        // Read off a log mesh, so exercised nodes interpolate a little under the payoff
+        assert!(
+            am > intrinsic - 1e-2,
+            "{:?}: {} below intrinsic",
+            option,
+            am
+        );
+        match option.option_type {
+            // Without dividends early exercise of a call is never optimal
+            OptionType::Call => assert!((am - eu).abs() < 1e-2, "{:?}: {} vs {}", option, am, eu),
+            OptionType::Put => assert!(am > eu, "{:?}: {} vs {}", option, am, eu),
+        }
+    }
+}
@@ -0,0 +1,164 @@
+use qox::core::period::DayCountConvention;
+use qox::evaluators::black_scholes::finite_difference::VanillaPayoff;
+use qox::instruments::OptionType;
+use qox::methods::analytic::black_scholes::black_scholes;
+use qox::methods::boundary_conditions::dirichlet::DirichletBoundary;
+use qox::methods::complementarity::ComplementaritySolver;
+use qox::methods::complementarity::projection::Projection;
+use qox::methods::complementarity::psor::Psor;
+use qox::methods::constraints::american::AmericanConstraint;
+use qox::methods::finite_difference::meshers::uniform::UniformMesher1d;
+use qox::methods::finite_difference::solver::{FdmConfig, Solver};
+use qox::methods::linear_operators::LinearOperator;
+use qox::methods::linear_operators::pentadiagonal_operator::PentadiagonalOperator;
+use qox::methods::linear_operators::tridiagonal_operator::TridiagonalOperator;
+use qox::methods::step_policy::linear_policy::LinearPolicy;
+use qox::methods::time_stepping::butcher_jackiewicz2::ButcherJackiewicz2;
+use qox::methods::time_stepping::input_vectors::InputVector;
+use qox::methods::transforms::log::LogTransform;
+use qox::processes::FdmProcess;
+use qox::processes::black_scholes::BlackScholesProcess;
+use qox::traits::payoff::{InitialConditions, PayoffAsInitialConditions};
+
+const SPOT: f64 = 100.0;
+const STRIKE: f64 = 100.0;
+const RATE: f64 = 0.05;
+const VOL: f64 = 0.2;
+const EXPIRY: f64 = 1.0;
+
+fn mesher(nodes: usize) -> UniformMesher1d<f64, LogTransform<f64>> {
+    // Symmetric around the strike so it sits on a node
+    UniformMesher1d::new(
+        (STRIKE / 5.0_f64).ln(),
+        (STRIKE * 5.0_f64).ln(),
+        nodes,
+        LogTransform::new(),
+    )
+}
+
+fn process<'a>()
+-> BlackScholesProcess<'a, f64, LogTransform<f64>, DirichletBoundary, DirichletBoundary> {
+    let bc = DirichletBoundary::DiscountedIntrinsic(OptionType::Call);
+    BlackScholesProcess::new(
+        RATE,
+        VOL,
+        LogTransform::new(),
+        DayCountConvention::Actual365Fixed,
+    )
+    .with_boundaries(bc, bc)
+}
+
+/// Black-Scholes call value with `SEASONED` years left, used as a smooth starting
+/// point so the payoff kink does not mask the spatial order.
+#[derive(Clone, Copy)]
+struct SeasonedCall;
+
+const SEASONED: f64 = 0.25;
+
+impl InitialConditions<f64> for SeasonedCall {
+    fn get_value(self, spot: f64) -> f64 {
+        black_scholes(spot, STRIKE, SEASONED, RATE, VOL, true)
+    }
+}
+
+fn price<L: LinearOperator<f64>>(nodes: usize, operator: &L) -> f64 {
+    let config = FdmConfig {
+        nodes,
+        time_steps: 400,
+    };
+    let solver = Solver { config };
+    let mesher = mesher(nodes);
+    let policy = LinearPolicy::new(operator);
+
+    let vector = solver.solve(
+        ButcherJackiewicz2::new(),
+        SeasonedCall,
+        &mesher,
+        (EXPIRY - SEASONED) / config.time_steps as f64,
+        &policy,
+    );
+    solver.interpolate(&mesher, vector.step_slice(0), SPOT)
+}
+
+#[test]
+fn banded_solve_inverts_apply() {
+    let nodes = 41;
+    let operator: PentadiagonalOperator<f64> = process().build_operator(&mesher(nodes));
+    let coeff = 0.01;
+
+    let x: Vec<f64> = (0..nodes).map(|i| (i as f64 * 0.3).sin() + 2.0).collect();
+    let mut lx = vec![0.0; nodes];
+    operator.apply_into(&x, &mut lx);
+
+    // The stage solve adds coeff * source back, so b = x - coeff * (L x + source)
+    let b: Vec<f64> = (0..nodes).map(|i| x[i] - coeff * lx[i]).collect();
+
+    let mut solved = vec![0.0; nodes];
+    let mut z = vec![0.0; nodes];
+    operator.setup_coeff(coeff);
+    operator.solve_inverse_into(&b, &mut solved, &mut z);
+
+    for (a, e) in solved.iter().zip(&x) {
+        assert!((a - e).abs() < 1e-10);
+    }
+}
+
+#[test]
+fn fourth_order_converges_faster_than_second_order() {
+    let expected = black_scholes(SPOT, STRIKE, EXPIRY, RATE, VOL, true);
+    let error = |nodes: usize, fourth_order: bool| {
+        let value = if fourth_order {
+            let op: PentadiagonalOperator<f64> = process().build_operator(&mesher(nodes));
+            price(nodes, &op)
+        } else {
+            let op: TridiagonalOperator<f64> = process().build_operator(&mesher(nodes));
+            price(nodes, &op)
+        };
+        (value - expected).abs()
+    };
+
+    // Halving h cuts the error by ~16 rather than ~4
+    assert!(error(81, true) / error(161, true) > 10.0);
+
+    // A quarter of the nodes is still more accurate than the second-order stencil
+    assert!(error(81, true) < error(321, false));
+}
+
+#[test]
+fn psor_and_projection_respect_the_obstacle() {
+    let nodes = 81;
+    let mesher = mesher(nodes);
+    let operator: PentadiagonalOperator<f64> = process().build_operator(&mesher);
+    let payoff = PayoffAsInitialConditions::new(VanillaPayoff {
+        strike: STRIKE,
+        option_type: OptionType::Put,
+    });
+    let constraint = AmericanConstraint::new(payoff);
+    let kappa = 0.05;
+
+    // One implicit step from the put payoff, pulled down so the obstacle binds
+    let rhs: Vec<f64> = (0..nodes)
+        .map(|i| (STRIKE - mesher.centers[i].exp()).max(0.0) * 0.9)
+        .collect();
+
+    let mut psor = rhs.clone();
+    Psor {
+        omega: 1.2,
+        tolerance: 1e-12,
+        max_iter: 10_000,
+    }
+    .solve(&operator, &rhs, kappa, &constraint, &mesher, &mut psor);
+
+    let mut projected = vec![0.0; nodes];
+    Projection::new().solve(&operator, &rhs, kappa, &constraint, &mesher, &mut projected);
+
+    for i in 0..nodes {
+        let g = (STRIKE - mesher.centers[i].exp()).max(0.0);
+        assert!(psor[i] >= g - 1e-12);
+        assert!(projected[i] >= g - 1e-12);
+    }
+
+    // Away from the exercise region both solve the same linear system
+    let i = nodes - 10;
+    assert!((psor[i] - projected[i]).abs() < 1e-6);
+}
@@ -1,6 +1,8 @@
 // tests/thirty360_us.rs
 
 use chrono::{Datelike, NaiveDate};
+use qox::core::calendars::cached::CachedCalendar;
+use qox::core::calendars::target::Target;
 use qox::core::period::{DefaultPeriodCalculator, PeriodCalculator};
 use qox::core::conventions::{DayCountConvention, Thirty360Subtype};
 use qox::traits::calendar::Calendar;
@@ -180,3 +182,127 @@ fn business252_basic() {
     let yf = calc.year_fraction(start, end, convention);
     assert_eq!(yf.0, 7.0/252.0);
 }
+
+#[test]
+fn business252_over_thirty_years_with_cached_calendar() {
+    let calc = DefaultPeriodCalculator;
+    let cached = CachedCalendar::new(Target);
+    let (start, end) = (date(2024, 1, 15), date(2054, 1, 15));
+
+    let naive = calc.year_fraction(start, end, DayCountConvention::Business252(&Target));
+    let fast = calc.year_fraction(start, end, DayCountConvention::Business252(&cached));
+    assert_eq!(fast.0, naive.0);
+    assert!((naive.0 - 30.0).abs() < 0.5);
+}
+
+#[test]
+fn act_act_isda_icma_afb_reference_cases() {
+    // ISDA, "EMU and market conventions: recent developments", Act/Act examples
+    let calc = DefaultPeriodCalculator;
+    let cases = [
+        // start, end, reference start, reference end, ISDA, ICMA, AFB
+        (date(2003, 11, 1), date(2004, 5, 1), date(2003, 11, 1), date(2004, 5, 1),
+            0.497724380567, 0.5, 0.497267759563),
+        // short first period
+        (date(1999, 2, 1), date(1999, 7, 1), date(1998, 7, 1), date(1999, 7, 1),
+            0.410958904110, 0.410958904110, 0.410958904110),
+        (date(1999, 7, 1), date(2000, 7, 1), date(1999, 7, 1), date(2000, 7, 1),
+            1.001377348600, 1.0, 1.0),
+        // long first period
+        (date(2002, 8, 15), date(2003, 7, 15), date(2003, 1, 15), date(2003, 7, 15),
+            0.915068493151, 0.915760869565, 0.915068493151),
+        (date(2003, 7, 15), date(2004, 1, 15), date(2003, 7, 15), date(2004, 1, 15),
+            0.504004790778, 0.5, 0.504109589041),
+        // short final period
+        (date(1999, 7, 30), date(2000, 1, 30), date(1999, 7, 30), date(2000, 1, 30),
+            0.503892506924, 0.5, 0.504109589041),
+        (date(2000, 1, 30), date(2000, 6, 30), date(2000, 1, 30), date(2000, 7, 30),
+            0.415300546448, 0.417582417582, 0.415300546448),
+    ];
+
+    for (start, end, ref_start, ref_end, isda, icma, afb) in cases {
+        let yf = calc.year_fraction(start, end, DayCountConvention::ActActISDA).0;
+        assert!((yf - isda).abs() < 1e-10, "ISDA {start} {end}: {yf}");
+
+        let yf = calc
+            .year_fraction_in_period(start, end, ref_start, ref_end, DayCountConvention::ActActICMA)
+            .0;
+        assert!((yf - icma).abs() < 1e-10, "ICMA {start} {end}: {yf}");
+
+        let yf = calc.year_fraction(start, end, DayCountConvention::ActActAFB).0;
+        assert!((yf - afb).abs() < 1e-10, "AFB {start} {end}: {yf}");
+    }
+
+    // A long final period spans whole notional periods after the reference period
+    let yf = calc.year_fraction_in_period(
+        date(2000, 1, 30),
+        date(2000, 12, 30),
+        date(2000, 1, 30),
+        date(2000, 7, 30),
+        DayCountConvention::ActActICMA,
+    );
+    assert!((yf.0 - (0.5 + 0.5 * 153.0 / 184.0)).abs() < 1e-12);
+
+    // Without a coupon period, or with one too short to have a frequency, ICMA is ISDA
+    for (start, end) in [
+        (date(2025, 1, 1), date(2025, 2, 15)),
+        (date(2025, 3, 3), date(2025, 3, 10)),
+        (date(2024, 12, 20), date(2025, 1, 2)),
+    ] {
+        let isda = calc.year_fraction(start, end, DayCountConvention::ActActISDA).0;
+        let yf = calc.year_fraction(start, end, DayCountConvention::ActActICMA).0;
+        assert_eq!(yf, isda);
+        if (end - start).num_days() < 15 {
+            let yf = calc
+                .year_fraction_in_period(start, end, start, end, DayCountConvention::ActActICMA)
+                .0;
+            assert_eq!(yf, isda);
+        }
+    }
+}
+
+#[test]
+fn act_365_leap_nl365_and_one_one() {
+    let calc = DefaultPeriodCalculator;
+
+    // Semi-annual: the leap year of the period end decides
+    let yf = calc.year_fraction_in_period(
+        date(2003, 11, 1),
+        date(2004, 5, 1),
+        date(2003, 11, 1),
+        date(2004, 5, 1),
+        DayCountConvention::Actual365Leap,
+    );
+    assert!((yf.0 - 182.0 / 366.0).abs() < 1e-12);
+
+    // Annual: only a February 29 inside the period counts
+    let annual = |start, end| {
+        calc.year_fraction(start, end, DayCountConvention::Actual365Leap).0
+    };
+    assert!((annual(date(2003, 7, 1), date(2004, 7, 1)) - 1.0).abs() < 1e-12);
+    assert!((annual(date(2004, 3, 1), date(2005, 3, 1)) - 1.0).abs() < 1e-12);
+    assert!((annual(date(2004, 7, 1), date(2005, 7, 1)) - 1.0).abs() < 1e-12);
+
+    let nl = DayCountConvention::NL365;
+    assert_eq!(calc.days_between(date(2004, 1, 1), date(2005, 1, 1), nl).0, 365);
+    assert_eq!(calc.days_between(date(2004, 2, 28), date(2004, 3, 1), nl).0, 1);
+    assert_eq!(calc.days_between(date(2004, 3, 1), date(2004, 2, 28), nl).0, -1);
+    assert!((calc.year_fraction(date(2003, 6, 1), date(2004, 6, 1), nl).0 - 1.0).abs() < 1e-12);
+
+    let one = DayCountConvention::OneOne;
+    assert_eq!(calc.year_fraction(date(2024, 1, 1), date(2024, 2, 1), one).0, 1.0);
+    assert_eq!(calc.year_fraction(date(2024, 1, 1), date(2026, 1, 1), one).0, 1.0);
+}
+
+#[test]
+fn thirty_e_plus_360_rolls_the_31st_forward() {
+    let calc = DefaultPeriodCalculator;
+    let convention = DayCountConvention::Thirty360(Thirty360Subtype::EuropeanPlus);
+
+    // d2 = 31 becomes the 1st of April: 30*(4-1) + (1-30) = 61
+    assert_eq!(calc.days_between(date(2024, 1, 30), date(2024, 3, 31), convention).0, 61);
+    // d1 = 31 still rolls back to 30: 30*(2-1) + (28-30) = 28
+    assert_eq!(calc.days_between(date(2024, 1, 31), date(2024, 2, 28), convention).0, 28);
+    // December 31 rolls into the next year: 360 + 30*(1-12) + (1-30) = 1
+    assert_eq!(calc.days_between(date(2024, 12, 30), date(2024, 12, 31), convention).0, 1);
+}
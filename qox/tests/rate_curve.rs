@@ -0,0 +1,384 @@
+use chrono::NaiveDate;
+use qox::core::curve_interpolation::CurveInterpolation;
+use qox::core::period::{DayCountConvention, DefaultPeriodCalculator};
+use qox::core::rate::{Compounding, Discountable, Frequency, InterestRate};
+use qox::core::tenor::Tenor;
+use qox::core::yield_curve::{CurveInstrument, Deposit, ParSwap, YieldCurve};
+use qox::market::rate_curve::{ContinuousRateCurve, FlatRateCurve, InterpolatedRateCurve};
+use qox::traits::rate_curve::RateCurve;
+
+fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(y, m, d).unwrap()
+}
+
+#[test]
+fn forward_rates_on_a_flat_continuous_curve() {
+    let r: f64 = 0.04;
+    let curve = ContinuousRateCurve::new(r, DayCountConvention::Actual365Fixed);
+
+    assert!((curve.instantaneous_forward(2.0) - r).abs() < 1e-15);
+
+    let simple = curve.forward_rate(1.0, 1.5, Compounding::Simple, Frequency::Once);
+    assert!((simple - ((r * 0.5).exp() - 1.0) / 0.5).abs() < 1e-12);
+
+    let annual = curve.forward_rate(2.0, 5.0, Compounding::Compounded, Frequency::Annual);
+    assert!((annual - (r.exp() - 1.0)).abs() < 1e-12);
+
+    let continuous = curve.forward_rate(0.25, 3.0, Compounding::Continuous, Frequency::Infinite);
+    assert!((continuous - r).abs() < 1e-12);
+}
+
+#[test]
+fn default_instantaneous_forward_matches_the_compounded_rate() {
+    // Annually compounded 5% is a flat 4.879% continuous forward
+    let curve = FlatRateCurve::new(InterestRate::new(
+        0.05,
+        DayCountConvention::Actual365Fixed,
+        Compounding::Compounded,
+        Frequency::Annual,
+    ));
+
+    let expected = 1.05_f64.ln();
+    for t in [0.0, 0.5, 3.0] {
+        assert!((curve.instantaneous_forward(t) - expected).abs() < 1e-8);
+    }
+}
+
+#[test]
+fn date_forward_accrues_under_the_requested_day_count() {
+    let r: f64 = 0.03;
+    let today = date(2025, 1, 15);
+    let quote = InterestRate::new(
+        r,
+        DayCountConvention::Actual365Fixed,
+        Compounding::Continuous,
+        Frequency::Infinite,
+    );
+    let curve = InterpolatedRateCurve::new(
+        today,
+        vec![Tenor::Years(1), Tenor::Years(2)],
+        vec![quote, quote],
+        &DefaultPeriodCalculator,
+    )
+    .unwrap();
+    let start = date(2025, 4, 15);
+    let end = date(2025, 7, 15);
+
+    let forward = curve.forward_rate_between(
+        start,
+        end,
+        DayCountConvention::Actual360,
+        Compounding::Simple,
+        Frequency::Once,
+        &DefaultPeriodCalculator,
+    );
+
+    let days = (end - start).num_days() as f64;
+    let tau = days / 360.0;
+    assert!((forward.value - ((r * days / 365.0).exp() - 1.0) / tau).abs() < 1e-12);
+    assert!(matches!(forward.convention, DayCountConvention::Actual360));
+}
+
+#[test]
+#[should_panic(expected = "reference date")]
+fn date_forward_needs_a_dated_curve() {
+    let curve = ContinuousRateCurve::new(0.03_f64, DayCountConvention::Actual360);
+    curve.forward_rate_between(
+        date(2025, 4, 15),
+        date(2025, 7, 15),
+        DayCountConvention::Actual360,
+        Compounding::Simple,
+        Frequency::Once,
+        &DefaultPeriodCalculator,
+    );
+}
+
+#[test]
+fn date_forward_takes_curve_time_from_the_curve_day_count() {
+    let today = date(2025, 1, 15);
+    let quote = |value| {
+        InterestRate::new(
+            value,
+            DayCountConvention::Actual365Fixed,
+            Compounding::Continuous,
+            Frequency::Infinite,
+        )
+    };
+    let curve = InterpolatedRateCurve::new(
+        today,
+        vec![Tenor::Years(1), Tenor::Years(5), Tenor::Years(10)],
+        vec![quote(0.04), quote(0.05), quote(0.052)],
+        &DefaultPeriodCalculator,
+    )
+    .unwrap();
+
+    // Act/360 accrual on an Act/365F curve
+    let (start, end) = (date(2030, 1, 15), date(2030, 7, 15));
+    let forward = curve.forward_rate_between(
+        start,
+        end,
+        DayCountConvention::Actual360,
+        Compounding::Simple,
+        Frequency::Once,
+        &DefaultPeriodCalculator,
+    );
+
+    let tau = (end - start).num_days() as f64 / 360.0;
+    let growth = curve.discount_factor_at(start) / curve.discount_factor_at(end);
+    let expected = (growth - 1.0) / tau;
+    assert!(
+        (forward.value - expected).abs() < 1e-12,
+        "{} vs {}",
+        forward.value,
+        expected
+    );
+
+    // Measuring curve time on Act/360 as well would overstate the forward
+    let act360_time = |d: NaiveDate| (d - today).num_days() as f64 / 360.0;
+    let mismatched =
+        (curve.discount_factor(act360_time(start)) / curve.discount_factor(act360_time(end)) - 1.0)
+            / tau;
+    assert!(mismatched - forward.value > 5e-4);
+}
+
+#[test]
+fn par_rate_reprices_a_bootstrapped_swap() {
+    let today = date(2025, 1, 15);
+    let act365 = DayCountConvention::Actual365Fixed;
+
+    let deposit = Deposit::new(today, date(2025, 7, 15), 0.031, act365);
+    let swaps: Vec<ParSwap<f64>> = [(2, 0.033), (5, 0.036), (10, 0.038)]
+        .into_iter()
+        .map(|(years, rate)| {
+            ParSwap::new(
+                today,
+                Tenor::Years(years).advance(today),
+                rate,
+                Tenor::Months(6),
+                act365,
+            )
+        })
+        .collect();
+
+    let mut instruments: Vec<&dyn CurveInstrument<f64>> = vec![&deposit];
+    instruments.extend(swaps.iter().map(|s| s as &dyn CurveInstrument<f64>));
+
+    let curve = YieldCurve::bootstrap(
+        today,
+        &instruments,
+        CurveInterpolation::LOG_LINEAR_DISCOUNT,
+        act365,
+        &DefaultPeriodCalculator,
+    )
+    .unwrap();
+
+    for swap in &swaps {
+        let mut schedule = vec![swap.start];
+        schedule.extend_from_slice(swap.payment_dates());
+
+        let par = curve.par_rate_for_schedule(&schedule, act365, &DefaultPeriodCalculator);
+        assert!((par - swap.rate).abs() < 1e-12, "{} vs {}", par, swap.rate);
+    }
+
+    // Piecewise flat forwards: the instantaneous forward equals the simple-to-continuous
+    // forward over any sub-interval of a pillar interval
+    let f = curve.instantaneous_forward(3.0);
+    let continuous = curve.forward_rate(2.5, 3.5, Compounding::Continuous, Frequency::Infinite);
+    assert!((f - continuous).abs() < 1e-8);
+}
+
+#[test]
+fn date_queries_restate_quotes_from_act365_curve_time() {
+    let today = date(2025, 1, 15);
+    let r = 0.05;
+    let rate = |value| {
+        InterestRate::new(
+            value,
+            DayCountConvention::Actual360,
+            Compounding::Continuous,
+            Frequency::Infinite,
+        )
+    };
+
+    let curve = InterpolatedRateCurve::new(
+        today,
+        vec![Tenor::Months(6), Tenor::Years(2)],
+        vec![rate(r), rate(r)],
+        &DefaultPeriodCalculator,
+    )
+    .unwrap();
+
+    let target = date(2026, 3, 2);
+    let days = (target - today).num_days() as f64;
+    assert!((curve.time(target) - days / 365.0).abs() < 1e-15);
+    assert!((curve.discount_factor_at(target) - (-r * days / 360.0).exp()).abs() < 1e-15);
+
+    // The same discount factor restated on Act/365F
+    let restated = curve.zero_rate_at(
+        target,
+        DayCountConvention::Actual365Fixed,
+        Compounding::Continuous,
+        Frequency::Infinite,
+    );
+    assert!((restated.value - r * 365.0 / 360.0).abs() < 1e-14);
+    assert!(matches!(
+        restated.convention,
+        DayCountConvention::Actual365Fixed
+    ));
+}
+
+#[test]
+fn mixed_convention_quotes_share_one_discount_curve() {
+    let today = date(2025, 1, 15);
+    let tenors = vec![Tenor::Months(3), Tenor::Years(1), Tenor::Years(5)];
+    let rates = vec![
+        InterestRate::new(
+            0.045,
+            DayCountConvention::Actual360,
+            Compounding::Simple,
+            Frequency::Once,
+        ),
+        InterestRate::new(
+            0.042,
+            DayCountConvention::Actual365Fixed,
+            Compounding::Compounded,
+            Frequency::SemiAnnual,
+        ),
+        InterestRate::new(
+            0.039,
+            DayCountConvention::Actual360,
+            Compounding::Continuous,
+            Frequency::Infinite,
+        ),
+    ];
+
+    let curve = InterpolatedRateCurve::new(
+        today,
+        tenors.clone(),
+        rates.clone(),
+        &DefaultPeriodCalculator,
+    )
+    .unwrap();
+    assert!(matches!(
+        curve.day_count(),
+        DayCountConvention::Actual365Fixed
+    ));
+
+    for (tenor, rate) in tenors.iter().zip(&rates) {
+        let end = tenor.advance(today);
+        let days = (end - today).num_days() as f64;
+        let accrual = match rate.convention {
+            DayCountConvention::Actual360 => days / 360.0,
+            _ => days / 365.0,
+        };
+        let expected = rate.discount_factor(accrual);
+        assert!((curve.discount_factor_at(end) - expected).abs() < 1e-14);
+
+        // The internal zero is continuous on Act/365F, and converts back to the quote
+        let t = days / 365.0;
+        assert!((curve.zero_rate(t) + expected.ln() / t).abs() < 1e-14);
+        let quoted = curve.zero_rate_at(end, rate.convention, rate.compounding, rate.frequency);
+        assert!((quoted.value - rate.value).abs() < 1e-12);
+    }
+}
+
+#[test]
+fn equivalent_rates_compound_to_the_same_amount() {
+    let simple = InterestRate::new(
+        0.05,
+        DayCountConvention::Actual360,
+        Compounding::Simple,
+        Frequency::Once,
+    );
+    let start = date(2025, 1, 15);
+    let end = date(2025, 10, 15);
+    let days = (end - start).num_days() as f64;
+
+    let growth = simple.compound_factor_between(start, end, &DefaultPeriodCalculator);
+    assert!((growth - (1.0 + 0.05 * days / 360.0)).abs() < 1e-15);
+    let df = simple.discount_factor_between(start, end, &DefaultPeriodCalculator);
+    assert!((df * growth - 1.0).abs() < 1e-15);
+
+    // Money-market simple on Act/360 to annually compounded on Act/365F
+    let annual = simple.equivalent_rate_between(
+        start,
+        end,
+        DayCountConvention::Actual365Fixed,
+        Compounding::Compounded,
+        Frequency::Annual,
+        &DefaultPeriodCalculator,
+    );
+    assert!(matches!(
+        annual.convention,
+        DayCountConvention::Actual365Fixed
+    ));
+    assert!((annual.compound_factor(days / 365.0) - growth).abs() < 1e-14);
+
+    // And back again over a year fraction
+    let continuous = annual.equivalent_rate(2.0, Compounding::Continuous, Frequency::Infinite);
+    assert!((continuous.value - annual.value.ln_1p()).abs() < 1e-14);
+}
+
+#[test]
+fn key_rate_rho_matches_bump_and_reprice() {
+    let today = date(2025, 1, 15);
+    let tenors = vec![
+        Tenor::Years(1),
+        Tenor::Years(2),
+        Tenor::Years(5),
+        Tenor::Years(10),
+    ];
+    let quotes = [0.030, 0.032, 0.035, 0.037];
+    let build = |bumped: Option<usize>| {
+        let rates = quotes
+            .iter()
+            .enumerate()
+            .map(|(i, &r)| {
+                let r = if bumped == Some(i) { r + 1e-6 } else { r };
+                InterestRate::new(
+                    r,
+                    DayCountConvention::Actual365Fixed,
+                    Compounding::Compounded,
+                    Frequency::Annual,
+                )
+            })
+            .collect();
+        InterpolatedRateCurve::new(today, tenors.clone(), rates, &DefaultPeriodCalculator)
+            .unwrap()
+            .with_interpolation(CurveInterpolation::LOG_LINEAR_DISCOUNT)
+            .unwrap()
+    };
+
+    // Annual 4% coupon bond to seven years
+    fn bond<T: qox::types::Real>(curve: &impl RateCurve<T>) -> T {
+        (1..=7).fold(T::zero(), |pv, year| {
+            let coupon = if year == 7 { 1.04 } else { 0.04 };
+            pv + T::from_f64(coupon) * curve.discount_factor(T::from_f64(year as f64))
+        })
+    }
+
+    let curve = build(None);
+    let rho = curve.key_rate_rho::<4, _>(bond).unwrap();
+    assert_eq!(rho.len(), 4);
+    assert!(matches!(rho[3].0, Tenor::Years(10)));
+
+    let base = bond(&curve);
+    for (i, (_, sensitivity)) in rho.iter().enumerate() {
+        let bumped = (bond(&build(Some(i))) - base) / 1e-6;
+        assert!(
+            (sensitivity - bumped).abs() < 1e-4,
+            "node {}: {} vs {}",
+            i,
+            sensitivity,
+            bumped
+        );
+    }
+
+    assert!(matches!(
+        curve.seed_nodes::<3>(),
+        Err(qox::core::error::CurveError::SeedMismatch {
+            nodes: 4,
+            variables: 3
+        })
+    ));
+}
@@ -0,0 +1,248 @@
+use chrono::NaiveDate;
+use qox::core::calendars::target::Target;
+use qox::core::conventions::BusinessDayConvention;
+use qox::core::error::ScheduleError;
+use qox::core::rate::Frequency;
+use qox::core::schedule::{DateGeneration, Schedule, Stub};
+use qox::core::tenor::Tenor;
+
+fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(y, m, d).unwrap()
+}
+
+#[test]
+fn backward_schedule_adjusts_to_business_days() {
+    let schedule = Schedule::builder(date(2024, 3, 15), date(2026, 3, 15), &Target)
+        .with_frequency(Frequency::SemiAnnual)
+        .build()
+        .unwrap();
+
+    assert_eq!(
+        schedule.unadjusted_dates(),
+        [
+            date(2024, 3, 15),
+            date(2024, 9, 15),
+            date(2025, 3, 15),
+            date(2025, 9, 15),
+            date(2026, 3, 15)
+        ]
+    );
+    assert_eq!(
+        schedule.dates(),
+        [
+            date(2024, 3, 15),
+            date(2024, 9, 16),
+            date(2025, 3, 17),
+            date(2025, 9, 15),
+            date(2026, 3, 16)
+        ]
+    );
+    assert_eq!(schedule.payment_dates(), &schedule.dates()[1..]);
+    assert!((0..4).all(|i| schedule.is_regular(i)));
+}
+
+#[test]
+fn stubs_follow_the_generation_direction() {
+    let (effective, termination) = (date(2024, 1, 15), date(2025, 3, 15));
+    let build = |rule, stub| {
+        Schedule::builder(effective, termination, &Target)
+            .with_tenor(Tenor::Months(6))
+            .with_rule(rule)
+            .with_stub(stub)
+            .with_convention(BusinessDayConvention::Unadjusted)
+            .build()
+            .unwrap()
+    };
+
+    let short_back = build(DateGeneration::Forward, Stub::Short);
+    assert_eq!(
+        short_back.dates(),
+        [effective, date(2024, 7, 15), date(2025, 1, 15), termination]
+    );
+    assert!(short_back.is_regular(1) && !short_back.is_regular(2));
+
+    let long_back = build(DateGeneration::Forward, Stub::Long);
+    assert_eq!(
+        long_back.dates(),
+        [effective, date(2024, 7, 15), termination]
+    );
+    assert!(long_back.is_regular(0) && !long_back.is_regular(1));
+
+    let short_front = build(DateGeneration::Backward, Stub::Short);
+    assert_eq!(
+        short_front.dates(),
+        [effective, date(2024, 3, 15), date(2024, 9, 15), termination]
+    );
+    assert!(!short_front.is_regular(0) && short_front.is_regular(1));
+
+    let long_front = build(DateGeneration::Backward, Stub::Long);
+    assert_eq!(
+        long_front.dates(),
+        [effective, date(2024, 9, 15), termination]
+    );
+    assert!(!long_front.is_regular(0) && long_front.is_regular(1));
+
+    // Explicit stub dates
+    let explicit = Schedule::builder(effective, termination, &Target)
+        .with_tenor(Tenor::Months(6))
+        .with_rule(DateGeneration::Forward)
+        .with_first_date(date(2024, 2, 15))
+        .with_next_to_last_date(date(2025, 2, 15))
+        .with_convention(BusinessDayConvention::Unadjusted)
+        .build()
+        .unwrap();
+    assert_eq!(
+        explicit.dates(),
+        [
+            effective,
+            date(2024, 2, 15),
+            date(2024, 8, 15),
+            date(2025, 2, 15),
+            termination
+        ]
+    );
+    let regular: Vec<bool> = (0..4).map(|i| explicit.is_regular(i)).collect();
+    assert_eq!(regular, [false, true, true, false]);
+}
+
+#[test]
+fn end_of_month_schedule_stays_on_month_ends() {
+    let build = |convention| {
+        Schedule::builder(date(2024, 2, 29), date(2025, 2, 28), &Target)
+            .with_tenor(Tenor::Months(3))
+            .with_rule(DateGeneration::Forward)
+            .with_end_of_month(true)
+            .with_convention(convention)
+            .build()
+            .unwrap()
+    };
+
+    let unadjusted = build(BusinessDayConvention::Unadjusted);
+    assert_eq!(
+        unadjusted.dates(),
+        [
+            date(2024, 2, 29),
+            date(2024, 5, 31),
+            date(2024, 8, 31),
+            date(2024, 11, 30),
+            date(2025, 2, 28)
+        ]
+    );
+
+    let adjusted = build(BusinessDayConvention::Following);
+    assert_eq!(
+        adjusted.dates(),
+        [
+            date(2024, 2, 29),
+            date(2024, 5, 31),
+            date(2024, 8, 30),
+            date(2024, 11, 29),
+            date(2025, 2, 28)
+        ]
+    );
+    assert!((0..4).all(|i| adjusted.is_regular(i)));
+}
+
+#[test]
+fn imm_third_wednesday_and_cds_rules() {
+    let imm = Schedule::builder(date(2024, 1, 10), date(2025, 1, 10), &Target)
+        .with_tenor(Tenor::Months(3))
+        .with_rule(DateGeneration::Imm)
+        .build()
+        .unwrap();
+    assert_eq!(
+        imm.dates(),
+        [
+            date(2024, 3, 20),
+            date(2024, 6, 19),
+            date(2024, 9, 18),
+            date(2024, 12, 18),
+            date(2025, 3, 19)
+        ]
+    );
+
+    let third_wednesday = Schedule::builder(date(2024, 1, 5), date(2024, 4, 5), &Target)
+        .with_tenor(Tenor::Months(1))
+        .with_rule(DateGeneration::ThirdWednesday)
+        .build()
+        .unwrap();
+    assert_eq!(
+        third_wednesday.dates(),
+        [
+            date(2024, 1, 5),
+            date(2024, 2, 21),
+            date(2024, 3, 20),
+            date(2024, 4, 5)
+        ]
+    );
+
+    let cds = Schedule::builder(date(2024, 5, 2), date(2029, 6, 20), &Target)
+        .with_tenor(Tenor::Months(3))
+        .with_rule(DateGeneration::Cds)
+        .with_convention(BusinessDayConvention::Following)
+        .with_termination_convention(BusinessDayConvention::Unadjusted)
+        .build()
+        .unwrap();
+    assert_eq!(cds.dates().len(), 22);
+    assert_eq!(cds.dates()[0], date(2024, 3, 20));
+    assert_eq!(cds.unadjusted_dates()[2], date(2024, 9, 20));
+    // 2025-12-20 is a Saturday
+    assert_eq!(cds.dates()[7], date(2025, 12, 22));
+    assert_eq!(cds.dates()[21], date(2029, 6, 20));
+}
+
+#[test]
+fn payment_dates_and_single_periods() {
+    let schedule = Schedule::builder(date(2024, 1, 31), date(2024, 7, 31), &Target)
+        .with_tenor(Tenor::Months(2))
+        .with_convention(BusinessDayConvention::Unadjusted)
+        .with_payment_convention(BusinessDayConvention::Following)
+        .build()
+        .unwrap();
+    // Easter Sunday 2024 pays after Easter Monday
+    assert_eq!(schedule.dates()[1], date(2024, 3, 31));
+    assert_eq!(schedule.payment_dates()[0], date(2024, 4, 2));
+
+    let zero = Schedule::builder(date(2024, 1, 31), date(2029, 1, 31), &Target)
+        .with_frequency(Frequency::Once)
+        .build()
+        .unwrap();
+    assert_eq!(zero.periods().count(), 1);
+    assert_eq!(zero.payment_dates(), [date(2029, 1, 31)]);
+}
+
+#[test]
+fn invalid_schedules_are_rejected() {
+    let inverted = Schedule::builder(date(2025, 1, 1), date(2024, 1, 1), &Target).build();
+    assert!(matches!(inverted, Err(ScheduleError::InvalidDates { .. })));
+
+    let stub = Schedule::builder(date(2024, 1, 1), date(2025, 1, 1), &Target)
+        .with_tenor(Tenor::Months(3))
+        .with_first_date(date(2025, 6, 1))
+        .build();
+    assert!(matches!(stub, Err(ScheduleError::InvalidStubDate(_))));
+
+    for tenor in [Tenor::Months(0), Tenor::Days(-7), Tenor::Years(-1)] {
+        let stuck = Schedule::builder(date(2024, 1, 1), date(2025, 1, 1), &Target)
+            .with_tenor(tenor)
+            .build();
+        assert!(matches!(stuck, Err(ScheduleError::InvalidTenor { .. })));
+    }
+
+    // Monthly dates would snap onto the same IMM date three times
+    for rule in [DateGeneration::Imm, DateGeneration::Cds] {
+        let monthly = Schedule::builder(date(2024, 1, 1), date(2025, 1, 1), &Target)
+            .with_tenor(Tenor::Months(1))
+            .with_rule(rule)
+            .build();
+        assert!(matches!(
+            monthly,
+            Err(ScheduleError::InvalidTenor { rule: r, .. }) if r == rule
+        ));
+    }
+    let weekly = Schedule::builder(date(2024, 1, 1), date(2025, 1, 1), &Target)
+        .with_tenor(Tenor::Weeks(1))
+        .with_rule(DateGeneration::ThirdWednesday)
+        .build();
+    assert!(weekly.is_err());
+}
@@ -0,0 +1,227 @@
+use qox::instruments::OptionType;
+use qox::math::special::non_central_chi_squared_cdf;
+use qox::methods::finite_difference::meshers::uniform::UniformMesher1d;
+use qox::methods::finite_difference::solver::{FdmConfig, Solver};
+use qox::methods::linear_operators::tridiagonal_operator::TridiagonalOperator;
+use qox::methods::step_policy::StepPolicy;
+use qox::methods::step_policy::bermudan_policy::BermudanPolicy;
+use qox::methods::step_policy::linear_policy::LinearPolicy;
+use qox::methods::time_stepping::butcher_jackiewicz2::ButcherJackiewicz2;
+use qox::methods::time_stepping::input_vectors::InputVector;
+use qox::methods::transforms::identity::IdentityTransform;
+use qox::processes::FdmProcess;
+use qox::processes::cir::CirProcess;
+use qox::processes::hull_white::HullWhiteProcess;
+use qox::processes::vasicek::VasicekProcess;
+use qox::traits::payoff::InitialConditions;
+use qox::traits::rate_curve::RateCurve;
+use qox::types::Real;
+
+type Mesher = UniformMesher1d<f64, IdentityTransform<f64>>;
+
+#[derive(Clone, Copy)]
+struct UnitPayoff;
+
+impl InitialConditions<f64> for UnitPayoff {
+    fn get_value(self, _rate: f64) -> f64 {
+        1.0
+    }
+}
+
+/// Call on the bond maturing `tail` years after the option expiry.
+#[derive(Clone, Copy)]
+struct CirBondCall {
+    process: CirProcess<f64>,
+    strike: f64,
+    tail: f64,
+}
+
+impl InitialConditions<f64> for CirBondCall {
+    fn get_value(self, rate: f64) -> f64 {
+        (self.process.zero_coupon_bond(0.0, self.tail, rate) - self.strike).max(0.0)
+    }
+}
+
+/// Put on the Vasicek bond maturing at `maturity`, exercised at `expiry` with the
+/// rate read as `r + shift`.
+#[derive(Clone, Copy)]
+struct VasicekBondPut {
+    process: VasicekProcess<f64>,
+    strike: f64,
+    expiry: f64,
+    maturity: f64,
+    shift: f64,
+}
+
+impl VasicekBondPut {
+    fn value(self, t: f64, rate: f64) -> f64 {
+        (self.strike - self.process.zero_coupon_bond(t, self.maturity, rate)).max(0.0)
+    }
+}
+
+impl InitialConditions<f64> for VasicekBondPut {
+    fn get_value(self, rate: f64) -> f64 {
+        self.value(self.expiry, rate + self.shift)
+    }
+}
+
+/// Rolls `payoff` back `expiry` years on a uniform rate mesh and reads it off at `x`.
+fn rollback<P, IC>(process: &P, payoff: IC, lo: f64, hi: f64, expiry: f64, x: f64) -> f64
+where
+    P: FdmProcess<f64, TridiagonalOperator<f64>, Mesher, IdentityTransform<f64>>,
+    IC: InitialConditions<f64> + Copy,
+{
+    let mesher = UniformMesher1d::new(lo, hi, 801, process.transform());
+    let operator = process.build_operator(&mesher);
+    rollback_with(&mesher, &LinearPolicy::new(&operator), payoff, expiry, x)
+}
+
+fn rollback_with<SP, IC>(mesher: &Mesher, policy: &SP, payoff: IC, expiry: f64, x: f64) -> f64
+where
+    SP: StepPolicy<f64, Mesher, TridiagonalOperator<f64>>,
+    IC: InitialConditions<f64> + Copy,
+{
+    let config = FdmConfig {
+        nodes: mesher.centers.len(),
+        time_steps: 200,
+    };
+    let solver = Solver { config };
+
+    let vector = solver.solve(
+        ButcherJackiewicz2::new(),
+        payoff,
+        mesher,
+        expiry / config.time_steps as f64,
+        policy,
+    );
+    let values = vector.step_slice(0);
+
+    let centers = &mesher.centers;
+    let i = centers.iter().rposition(|&c| c <= x).unwrap();
+    let w = (x - centers[i]) / (centers[i + 1] - centers[i]);
+    values[i] + w * (values[i + 1] - values[i])
+}
+
+#[test]
+fn non_central_chi_squared_matches_the_one_degree_closed_form() {
+    // With one degree of freedom the variable is (Z + sqrt(lambda))^2
+    for (x, lambda) in [(0.3, 0.0), (2.0, 1.5), (9.0, 4.0), (30.0, 20.0)] {
+        let root = f64::sqrt(x);
+        let shift = f64::sqrt(lambda);
+        let expected = (root - shift).norm_cdf() - (-root - shift).norm_cdf();
+        let cdf = non_central_chi_squared_cdf(x, 1.0, lambda);
+        assert!((cdf - expected).abs() < 1e-10, "{} {}: {}", x, lambda, cdf);
+    }
+
+    // Two degrees, central: exponential with mean two
+    assert!((non_central_chi_squared_cdf(3.0, 2.0, 0.0) - (1.0 - (-1.5_f64).exp())).abs() < 1e-14);
+}
+
+#[test]
+fn hull_white_fitted_to_a_vasicek_curve_reproduces_vasicek() {
+    let vasicek = VasicekProcess::new(0.15, 0.045, 0.012, 0.03);
+    let hull_white = HullWhiteProcess::new(0.15, 0.012, vasicek);
+
+    for (t, maturity, r) in [(0.0, 5.0, 0.03), (1.0, 3.0, 0.05), (2.5, 10.0, 0.01)] {
+        let expected = vasicek.zero_coupon_bond(t, maturity, r);
+        let fitted = hull_white.zero_coupon_bond(t, maturity, r);
+        assert!(
+            (fitted - expected).abs() < 1e-14,
+            "{} vs {}",
+            fitted,
+            expected
+        );
+    }
+
+    for option_type in [OptionType::Call, OptionType::Put] {
+        let expected = vasicek.bond_option(option_type, 0.85, 2.0, 6.0);
+        let fitted = hull_white.bond_option(option_type, 0.85, 2.0, 6.0);
+        assert!((fitted - expected).abs() < 1e-14);
+    }
+
+    // Put-call parity on the bond
+    let call = vasicek.bond_option(OptionType::Call, 0.85, 2.0, 6.0);
+    let put = vasicek.bond_option(OptionType::Put, 0.85, 2.0, 6.0);
+    let forward = vasicek.discount_factor(6.0) - 0.85 * vasicek.discount_factor(2.0);
+    assert!((call - put - forward).abs() < 1e-14);
+}
+
+#[test]
+fn finite_differences_reprice_zero_coupon_bonds() {
+    let maturity = 5.0;
+
+    let vasicek = VasicekProcess::new(0.3, 0.05, 0.015, 0.03);
+    let fd = rollback(&vasicek, UnitPayoff, -0.2, 0.3, maturity, vasicek.r0);
+    assert!((fd - vasicek.discount_factor(maturity)).abs() < 1e-5);
+
+    let cir = CirProcess::new(0.4, 0.05, 0.1, 0.03);
+    assert!(cir.feller_condition());
+    let fd = rollback(&cir, UnitPayoff, 0.0, 0.4, maturity, cir.r0);
+    assert!((fd - cir.discount_factor(maturity)).abs() < 1e-5);
+
+    // Hull-White rolls back in x = r - alpha(t), starting from x = 0
+    let hull_white = HullWhiteProcess::new(0.3, 0.015, cir);
+    let fd = rollback(&hull_white, UnitPayoff, -0.2, 0.2, maturity, 0.0);
+    let price = fd * hull_white.shift_discount(0.0, maturity);
+    assert!((price - cir.discount_factor(maturity)).abs() < 1e-5);
+}
+
+#[test]
+fn cir_bond_option_matches_finite_differences() {
+    let cir = CirProcess::new(0.4, 0.05, 0.1, 0.03);
+    let (expiry, maturity) = (1.0, 4.0);
+    let strike = cir.zero_coupon_bond(0.0, maturity - expiry, 0.04);
+
+    let payoff = CirBondCall {
+        process: cir,
+        strike,
+        tail: maturity - expiry,
+    };
+    let fd = rollback(&cir, payoff, 0.0, 0.4, expiry, cir.r0);
+    let call = cir.bond_option(OptionType::Call, strike, expiry, maturity);
+    assert!((call - fd).abs() < 1e-5, "{} vs {}", call, fd);
+
+    let put = cir.bond_option(OptionType::Put, strike, expiry, maturity);
+    assert!(put > 0.0 && put < call + strike);
+}
+
+#[test]
+fn hull_white_early_exercise_rescales_the_exercise_value() {
+    let vasicek = VasicekProcess::new(0.15, 0.045, 0.012, 0.03);
+    let hull_white = HullWhiteProcess::new(0.15, 0.012, vasicek);
+    let (expiry, maturity) = (2.0, 5.0);
+    let put = VasicekBondPut {
+        process: vasicek,
+        strike: 0.9,
+        expiry,
+        maturity,
+        shift: 0.0,
+    };
+    // Yearly exercise, as times to expiry
+    let exercise_times = vec![0.5, 1.0, 1.5];
+
+    // Vasicek discounts at the full rate, so the exercise value needs no rescaling
+    let mesher: Mesher = UniformMesher1d::new(-0.2, 0.3, 801, IdentityTransform::new());
+    let operator = vasicek.build_operator(&mesher);
+    let policy = BermudanPolicy::new(&operator, exercise_times.clone(), |tau, r| {
+        put.value(expiry - tau, r)
+    });
+    let expected = rollback_with(&mesher, &policy, put, expiry, vasicek.r0);
+    let european = rollback(&vasicek, put, -0.2, 0.3, expiry, vasicek.r0);
+    assert!(expected > european + 1e-4, "{} vs {}", expected, european);
+
+    // The same option on the x mesh of the fitted Hull-White model
+    let shifted = VasicekBondPut {
+        shift: hull_white.alpha(expiry),
+        ..put
+    };
+    let mesher: Mesher = UniformMesher1d::new(-0.25, 0.25, 801, IdentityTransform::new());
+    let operator = hull_white.build_operator(&mesher);
+    let policy = BermudanPolicy::new(&operator, exercise_times, |tau, x| {
+        let t = expiry - tau;
+        hull_white.exercise_value(t, expiry, x, |r| put.value(t, r))
+    });
+    let fd = rollback_with(&mesher, &policy, shifted, expiry, 0.0);
+    let price = fd * hull_white.shift_discount(0.0, expiry);
+    assert!((price - expected).abs() < 1e-5, "{} vs {}", price, expected);
+}
@@ -0,0 +1,132 @@
+use chrono::NaiveDate;
+use qox::core::period::{DayCountConvention, DefaultPeriodCalculator};
+use qox::core::rate::{Compounding, Frequency, InterestRate};
+use qox::core::tenor::Tenor;
+use qox::market::rate_curve::{ContinuousRateCurve, FlatRateCurve, InterpolatedRateCurve};
+use qox::market::spreaded_curve::{ForwardSpreadedCurve, ImpliedCurve, Spread, ZeroSpreadedCurve};
+use qox::traits::rate_curve::RateCurve;
+use qox::types::dual_array::DualArray;
+
+const ACT365: DayCountConvention = DayCountConvention::Actual365Fixed;
+
+#[test]
+fn parallel_spread_shifts_zero_rates_and_forwards_alike() {
+    let base = ContinuousRateCurve::new(0.03, ACT365);
+    let zero = ZeroSpreadedCurve::new(base, Spread::parallel(0.0125));
+    let forward = ForwardSpreadedCurve::new(base, Spread::parallel(0.0125));
+
+    for t in [0.5, 2.0, 7.0] {
+        assert!((zero.zero_rate(t) - 0.0425).abs() < 1e-15);
+        assert!((zero.discount_factor(t) - (-0.0425 * t).exp()).abs() < 1e-15);
+        assert!((forward.discount_factor(t) - zero.discount_factor(t)).abs() < 1e-15);
+        assert!((forward.instantaneous_forward(t) - 0.0425).abs() < 1e-15);
+    }
+}
+
+#[test]
+fn spreaded_zero_rates_are_continuous_over_a_compounded_base() {
+    // 5% annually compounded base, so the spreads act on a 4.879% continuous rate
+    let base = FlatRateCurve::new(InterestRate::new(
+        0.05,
+        ACT365,
+        Compounding::Compounded,
+        Frequency::Annual,
+    ));
+    let zero = ZeroSpreadedCurve::new(&base, Spread::parallel(0.01));
+    let forward = ForwardSpreadedCurve::new(&base, Spread::parallel(0.01));
+
+    let expected = 1.05_f64.ln() + 0.01;
+    for t in [0.0, 0.5, 2.0, 10.0] {
+        assert!((zero.zero_rate(t) - expected).abs() < 1e-8, "{}", t);
+        assert!((forward.zero_rate(t) - expected).abs() < 1e-8, "{}", t);
+    }
+    for t in [0.5, 2.0, 10.0] {
+        let implied = -zero.discount_factor(t).ln() / t;
+        assert!((zero.zero_rate(t) - implied).abs() < 1e-15);
+    }
+}
+
+#[test]
+fn forward_spread_term_structure_integrates_piecewise_linearly() {
+    let base = ContinuousRateCurve::new(0.02, ACT365);
+    let spread = Spread::term(vec![1.0, 3.0], vec![0.01, 0.03]).unwrap();
+    let curve = ForwardSpreadedCurve::new(&base, spread.clone());
+
+    // Flat 1% to year one, then a ramp to 3%, then flat
+    assert!((spread.integral(0.5) - 0.005).abs() < 1e-15);
+    assert!((spread.integral(2.0) - (0.01 + 0.015)).abs() < 1e-15);
+    assert!((spread.integral(5.0) - (0.01 + 0.04 + 0.06)).abs() < 1e-15);
+
+    let df = curve.discount_factor(2.0);
+    assert!((df - (-0.02 * 2.0 - 0.025_f64).exp()).abs() < 1e-15);
+    assert!((curve.instantaneous_forward(2.0) - 0.04).abs() < 1e-15);
+
+    assert!(Spread::term(vec![2.0, 1.0], vec![0.0, 0.0]).is_err());
+}
+
+#[test]
+fn implied_curve_discounts_with_base_forwards() {
+    let base = ZeroSpreadedCurve::new(
+        ContinuousRateCurve::new(0.03, ACT365),
+        Spread::term(vec![0.0, 10.0], vec![0.0, 0.01]).unwrap(),
+    );
+    let implied = ImpliedCurve::new(&base, 2.0);
+
+    for t in [0.25, 1.0, 5.0] {
+        let expected = base.discount_factor(2.0 + t) / base.discount_factor(2.0);
+        assert!((implied.discount_factor(t) - expected).abs() < 1e-15);
+        assert!((implied.zero_rate(t) + expected.ln() / t).abs() < 1e-14);
+    }
+}
+
+#[test]
+fn implied_curve_at_a_date_moves_the_reference_date() {
+    let today = NaiveDate::from_ymd_opt(2025, 1, 15).unwrap();
+    let later = NaiveDate::from_ymd_opt(2027, 1, 15).unwrap();
+    let quote =
+        |value| InterestRate::new(value, ACT365, Compounding::Continuous, Frequency::Infinite);
+    let base = InterpolatedRateCurve::new(
+        today,
+        vec![Tenor::Years(1), Tenor::Years(5), Tenor::Years(10)],
+        vec![quote(0.03), quote(0.035), quote(0.04)],
+        &DefaultPeriodCalculator,
+    )
+    .unwrap();
+
+    let implied = ImpliedCurve::at_date(&base, later).unwrap();
+    assert_eq!(RateCurve::reference_date(&implied), Some(later));
+    assert_eq!(implied.start(), base.time(later));
+    assert_eq!(implied.curve_time(later), Some(0.0));
+
+    let end = NaiveDate::from_ymd_opt(2030, 1, 15).unwrap();
+    let t = implied.curve_time(end).unwrap();
+    let expected = base.discount_factor_at(end) / base.discount_factor_at(later);
+    assert!((implied.discount_factor(t) - expected).abs() < 1e-15);
+
+    // A curve time need not fall on a date, and an undated base has no dates at all
+    assert_eq!(
+        RateCurve::reference_date(&ImpliedCurve::new(&base, 2.0)),
+        None
+    );
+    assert!(ImpliedCurve::at_date(ContinuousRateCurve::new(0.03, ACT365), later).is_none());
+}
+
+#[test]
+fn dual_spreads_carry_spread_sensitivities() {
+    type D = DualArray<2>;
+    let base = ContinuousRateCurve::new(D::from(0.03), ACT365);
+    let spread = Spread::term(
+        vec![D::from(1.0), D::from(5.0)],
+        vec![D::var(0.01, 0), D::var(0.02, 1)],
+    )
+    .unwrap();
+    let curve = ZeroSpreadedCurve::new(base, spread);
+
+    // At t = 3 the spread is the midpoint of the two nodes
+    let t = 3.0;
+    let df = curve.discount_factor(D::from(t));
+    assert!((df.val - (-(0.03 + 0.015) * t).exp()).abs() < 1e-15);
+    for i in 0..2 {
+        assert!((df.grad[i] + 0.5 * t * df.val).abs() < 1e-14);
+    }
+}
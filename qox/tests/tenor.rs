@@ -0,0 +1,142 @@
+use std::collections::HashSet;
+
+use chrono::NaiveDate;
+use qox::core::error::TenorError;
+use qox::core::rate::Frequency;
+use qox::core::tenor::Tenor;
+
+fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(y, m, d).unwrap()
+}
+
+fn tenor(s: &str) -> Tenor {
+    s.parse().unwrap()
+}
+
+#[test]
+fn parses_and_displays_canonically() {
+    for (text, expected, display) in [
+        ("3M", Tenor::Months(3), "3M"),
+        ("1y6m", Tenor::Months(18), "1Y6M"),
+        ("12M", Tenor::Years(1), "1Y"),
+        ("2W", Tenor::Weeks(2), "2W"),
+        ("14D", Tenor::Weeks(2), "2W"),
+        ("1W3D", Tenor::Days(10), "10D"),
+        (" -18M ", Tenor::Months(-18), "-1Y6M"),
+        ("ON", Tenor::Days(1), "1D"),
+        ("TN", Tenor::Days(2), "2D"),
+        ("sn", Tenor::Days(3), "3D"),
+        ("0D", Tenor::Years(0), "0D"),
+    ] {
+        let parsed = tenor(text);
+        assert_eq!(parsed, expected, "{}", text);
+        assert_eq!(parsed.to_string(), display, "{}", text);
+        assert_eq!(tenor(&parsed.to_string()), parsed);
+    }
+
+    assert!(matches!("".parse::<Tenor>(), Err(TenorError::Empty)));
+    for bad in ["3", "M", "3X", "1Y2D", "1.5Y", "3M-"] {
+        assert!(
+            matches!(bad.parse::<Tenor>(), Err(TenorError::Invalid(_))),
+            "{}",
+            bad
+        );
+    }
+}
+
+#[test]
+fn arithmetic_and_normalization() {
+    assert_eq!(tenor("1Y") + tenor("6M"), Tenor::Months(18));
+    assert_eq!(tenor("1Y") - tenor("3M"), Tenor::Months(9));
+    assert_eq!(tenor("1W") + tenor("2D"), Tenor::Days(9));
+    assert!(matches!(tenor("1W") + tenor("1W"), Tenor::Weeks(2)));
+    assert!(matches!(tenor("2Y") - tenor("1Y"), Tenor::Years(1)));
+    assert_eq!(tenor("3M") * 4, Tenor::Years(1));
+    assert_eq!(-tenor("2W"), Tenor::Days(-14));
+    assert_eq!(tenor("0D") + tenor("3M"), Tenor::Months(3));
+    assert!(tenor("1M").checked_add(tenor("1D")).is_none());
+    assert!(Tenor::Months(i32::MAX).checked_add(tenor("1M")).is_none());
+
+    let set: HashSet<Tenor> = ["12M", "1Y", "2W", "14D", "1Y6M", "18M"]
+        .into_iter()
+        .map(tenor)
+        .collect();
+    assert_eq!(set.len(), 3);
+}
+
+#[test]
+fn ordering_by_approximate_length() {
+    let mut pillars: Vec<Tenor> = ["10Y", "ON", "6M", "1W", "18M", "1M", "2Y"]
+        .into_iter()
+        .map(tenor)
+        .collect();
+    pillars.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    let sorted: Vec<String> = pillars.iter().map(|t| t.to_string()).collect();
+    assert_eq!(sorted, ["1D", "1W", "1M", "6M", "1Y6M", "2Y", "10Y"]);
+
+    assert!(tenor("1M") > tenor("30D"));
+    assert!(tenor("1M") < tenor("31D"));
+    assert!(tenor("1Y") > tenor("52W"));
+    // 16 months is 487 days on average: too close to call
+    assert_eq!(tenor("16M").partial_cmp(&tenor("487D")), None);
+}
+
+#[test]
+fn frequency_conversion() {
+    assert!(matches!(
+        tenor("6M").frequency(),
+        Some(Frequency::SemiAnnual)
+    ));
+    assert!(matches!(tenor("12M").frequency(), Some(Frequency::Annual)));
+    assert!(matches!(
+        tenor("3M").frequency(),
+        Some(Frequency::Quarterly)
+    ));
+    assert!(matches!(tenor("0D").frequency(), Some(Frequency::Once)));
+    assert!(tenor("2M").frequency().is_none());
+    assert!(tenor("1W").frequency().is_none());
+
+    for frequency in [Frequency::Annual, Frequency::Quarterly, Frequency::Monthly] {
+        let period = frequency.tenor().unwrap();
+        assert_eq!(period.frequency().unwrap() as i32, frequency as i32);
+    }
+}
+
+#[test]
+fn negative_tenors_advance_backwards() {
+    assert_eq!(
+        Tenor::Months(-1).advance(date(2024, 3, 31)),
+        date(2024, 2, 29)
+    );
+    assert_eq!(
+        Tenor::Years(-1).advance(date(2024, 2, 29)),
+        date(2023, 2, 28)
+    );
+    assert_eq!(tenor("-1Y6M").advance(date(2024, 7, 15)), date(2023, 1, 15));
+    assert_eq!(
+        Tenor::Weeks(-2).advance(date(2024, 1, 10)),
+        date(2023, 12, 27)
+    );
+}
+
+#[test]
+fn lengths_past_the_count_range_compare_without_overflow() {
+    // Parsed lengths in days or months must fit an i32
+    for big in ["200000000Y", "400000000W", "99999999999D", "178956970Y12M"] {
+        assert!(
+            matches!(big.parse::<Tenor>(), Err(TenorError::Invalid(_))),
+            "{}",
+            big
+        );
+    }
+    assert_eq!(tenor("178956970Y"), Tenor::Months(2_147_483_640));
+
+    let years = Tenor::Years(200_000_000);
+    assert_eq!(years, Tenor::Years(200_000_000));
+    assert_ne!(years, Tenor::Months(i32::MAX));
+    assert!(Tenor::Months(i32::MAX) < years);
+    assert_eq!(Tenor::Weeks(i32::MAX), Tenor::Weeks(i32::MAX));
+
+    let set: HashSet<Tenor> = [years, Tenor::Weeks(i32::MIN)].into_iter().collect();
+    assert_eq!(set.len(), 2);
+}
@@ -0,0 +1,219 @@
+use chrono::NaiveDate;
+use qox::core::curve_interpolation::{CurveInterpolation, InterpolationVariable};
+use qox::core::error::CurveError;
+use qox::core::period::{DayCountConvention, DefaultPeriodCalculator};
+use qox::core::tenor::Tenor;
+use qox::core::yield_curve::{
+    CurveInstrument, Deposit, ForwardRateAgreement, ParSwap, RateFuture, YieldCurve,
+};
+use qox::math::interpolate::InterpolationMethod;
+use qox::traits::rate_curve::RateCurve;
+
+fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(y, m, d).unwrap()
+}
+
+struct Quotes<'a> {
+    deposits: Vec<Deposit<'a, f64>>,
+    fras: Vec<ForwardRateAgreement<'a, f64>>,
+    futures: Vec<RateFuture<'a, f64>>,
+    swaps: Vec<ParSwap<'a, f64>>,
+}
+
+impl<'a> Quotes<'a> {
+    fn instruments(&self) -> Vec<&dyn CurveInstrument<'a, f64>> {
+        let mut out: Vec<&dyn CurveInstrument<'a, f64>> = Vec::new();
+        out.extend(
+            self.deposits
+                .iter()
+                .map(|i| i as &dyn CurveInstrument<'a, f64>),
+        );
+        out.extend(self.fras.iter().map(|i| i as &dyn CurveInstrument<'a, f64>));
+        out.extend(
+            self.futures
+                .iter()
+                .map(|i| i as &dyn CurveInstrument<'a, f64>),
+        );
+        out.extend(
+            self.swaps
+                .iter()
+                .map(|i| i as &dyn CurveInstrument<'a, f64>),
+        );
+        out
+    }
+}
+
+fn quotes<'a>() -> Quotes<'a> {
+    let today = date(2025, 1, 15);
+    let act360 = DayCountConvention::Actual360;
+    let thirty360 = DayCountConvention::Thirty360(qox::core::conventions::Thirty360Subtype::US);
+
+    Quotes {
+        deposits: vec![
+            Deposit::new(today, date(2025, 1, 16), 0.0430, act360),
+            Deposit::new(today, date(2025, 2, 15), 0.0432, act360),
+            Deposit::new(today, date(2025, 4, 15), 0.0435, act360),
+        ],
+        fras: vec![ForwardRateAgreement::new(
+            date(2025, 4, 15),
+            date(2025, 7, 15),
+            0.0428,
+            act360,
+        )],
+        futures: vec![
+            RateFuture::new(date(2025, 7, 15), date(2025, 10, 15), 95.80, act360),
+            RateFuture::new(date(2025, 10, 15), date(2026, 1, 15), 95.95, act360)
+                .with_convexity_adjustment(0.0002),
+        ],
+        swaps: [(2, 0.0395), (3, 0.0380), (5, 0.0372), (10, 0.0385)]
+            .into_iter()
+            .map(|(years, rate)| {
+                ParSwap::new(
+                    today,
+                    Tenor::Years(years).advance(today),
+                    rate,
+                    Tenor::Months(6),
+                    thirty360,
+                )
+            })
+            .collect(),
+    }
+}
+
+#[test]
+fn bootstrapped_curve_reprices_every_instrument() {
+    let quotes = quotes();
+    let instruments = quotes.instruments();
+
+    for interpolation in [
+        CurveInterpolation::LINEAR_ZERO,
+        CurveInterpolation::LOG_LINEAR_DISCOUNT,
+        CurveInterpolation::LINEAR_DISCOUNT,
+        CurveInterpolation::new(
+            InterpolationVariable::ZeroRate,
+            InterpolationMethod::NaturalCubic,
+        )
+        .unwrap(),
+        CurveInterpolation::new(
+            InterpolationVariable::ZeroRate,
+            InterpolationMethod::MonotoneConvex,
+        )
+        .unwrap(),
+        CurveInterpolation::new(
+            InterpolationVariable::ZeroRate,
+            InterpolationMethod::FlatForward,
+        )
+        .unwrap(),
+        CurveInterpolation::new(
+            InterpolationVariable::LogDiscount,
+            InterpolationMethod::MonotoneCubic,
+        )
+        .unwrap(),
+        CurveInterpolation::new(
+            InterpolationVariable::InstantaneousForward,
+            InterpolationMethod::Linear,
+        )
+        .unwrap(),
+    ] {
+        let curve = YieldCurve::bootstrap(
+            date(2025, 1, 15),
+            &instruments,
+            interpolation,
+            DayCountConvention::Actual365Fixed,
+            &DefaultPeriodCalculator,
+        )
+        .unwrap();
+
+        for instrument in &instruments {
+            let implied = instrument.implied_quote(&curve);
+            assert!(
+                (implied - instrument.quote()).abs() < 1e-10,
+                "{:?} {}: implied {} vs quote {}",
+                interpolation,
+                instrument.maturity_date(),
+                implied,
+                instrument.quote()
+            );
+        }
+
+        assert_eq!(curve.pillars().len(), instruments.len() + 1);
+        assert!((curve.discount_factor(0.0) - 1.0).abs() < 1e-12);
+    }
+}
+
+#[test]
+fn flat_quotes_give_a_flat_curve() {
+    let today = date(2025, 1, 15);
+    let act365 = DayCountConvention::Actual365Fixed;
+    let r: f64 = 0.03;
+
+    // Simple rates and annual swap rates consistent with a flat 3% continuous curve
+    let deposit_end = date(2025, 7, 15);
+    let tau = (deposit_end - today).num_days() as f64 / 365.0;
+    let deposit = Deposit::new(today, deposit_end, ((r * tau).exp() - 1.0) / tau, act365);
+
+    let swaps: Vec<ParSwap<f64>> = (1..=5)
+        .map(|years| {
+            let maturity = Tenor::Years(years).advance(today);
+            let mut annuity = 0.0;
+            let mut prev = today;
+            for k in 1..=years {
+                let pay = Tenor::Years(k).advance(today);
+                let t = (pay - today).num_days() as f64 / 365.0;
+                annuity += (pay - prev).num_days() as f64 / 365.0 * (-r * t).exp();
+                prev = pay;
+            }
+            let t_n = (maturity - today).num_days() as f64 / 365.0;
+            let par = (1.0 - (-r * t_n).exp()) / annuity;
+            ParSwap::new(today, maturity, par, Tenor::Years(1), act365)
+        })
+        .collect();
+
+    let mut instruments: Vec<&dyn CurveInstrument<f64>> = vec![&deposit];
+    instruments.extend(swaps.iter().map(|s| s as &dyn CurveInstrument<f64>));
+
+    let curve = YieldCurve::bootstrap(
+        today,
+        &instruments,
+        CurveInterpolation::LOG_LINEAR_DISCOUNT,
+        act365,
+        &DefaultPeriodCalculator,
+    )
+    .unwrap();
+
+    for t in [0.25, 0.5, 1.0, 2.7, 4.0, 5.0] {
+        assert!(
+            (curve.zero_rate(t) - r).abs() < 1e-10,
+            "zero rate at {} is {}",
+            t,
+            curve.zero_rate(t)
+        );
+    }
+}
+
+#[test]
+fn bootstrap_rejects_bad_instrument_sets() {
+    let today = date(2025, 1, 15);
+    let act360 = DayCountConvention::Actual360;
+
+    let empty: Vec<&dyn CurveInstrument<f64>> = Vec::new();
+    let result = YieldCurve::bootstrap(
+        today,
+        &empty,
+        CurveInterpolation::LINEAR_ZERO,
+        act360,
+        &DefaultPeriodCalculator,
+    );
+    assert!(matches!(result, Err(CurveError::NoInstruments)));
+
+    let a = Deposit::new(today, date(2025, 4, 15), 0.04, act360);
+    let b = ForwardRateAgreement::new(date(2025, 1, 15), date(2025, 4, 15), 0.041, act360);
+    let result = YieldCurve::bootstrap(
+        today,
+        &[&a as &dyn CurveInstrument<f64>, &b],
+        CurveInterpolation::LINEAR_ZERO,
+        act360,
+        &DefaultPeriodCalculator,
+    );
+    assert!(matches!(result, Err(CurveError::DuplicatePillar(_))));
+}
@@ -1,9 +1,10 @@
 import qox
-from datetime import datetime, timezone, timedelta
+from datetime import date, datetime, timezone
 
-expiry = datetime.now(timezone.utc) + timedelta(days=365)
+evaluation_date = date(2024, 1, 2)
+expiry = datetime(2025, 1, 1, tzinfo=timezone.utc)
 stock_option = qox.StockOption(100.0, expiry, "call")
-market_frame = qox.OptionMarketFrame(95.0, qox.RateCurve.continuous(0.05), qox.VolSurface.flat(0.2))
+market_frame = qox.OptionMarketFrame(evaluation_date, 95.0, qox.RateCurve.continuous(0.05), qox.VolSurface.flat(0.2))
 price = stock_option.evaluate(market_frame)
 
 print(f"Price: {price}")
\ No newline at end of file